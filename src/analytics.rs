@@ -0,0 +1,240 @@
+use crate::pokemon::{Pokemon, PokemonType, StatKind};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+// Valor numérico que se puede analizar: una estadística base o el total
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Metric {
+    Stat(StatKind),
+    Total,
+}
+
+impl Metric {
+    pub const ALL: [Metric; 7] = [
+        Metric::Stat(StatKind::Hp),
+        Metric::Stat(StatKind::Attack),
+        Metric::Stat(StatKind::Defense),
+        Metric::Stat(StatKind::SpecialAttack),
+        Metric::Stat(StatKind::SpecialDefense),
+        Metric::Stat(StatKind::Speed),
+        Metric::Total,
+    ];
+
+    pub fn value(&self, pokemon: &Pokemon) -> u16 {
+        match self {
+            Metric::Stat(kind) => pokemon.stats.get(*kind) as u16,
+            Metric::Total => pokemon.total_stats(),
+        }
+    }
+
+    // Clave del texto traducido en los catálogos de i18n
+    pub fn key(&self) -> &'static str {
+        match self {
+            Metric::Stat(kind) => kind.key(),
+            Metric::Total => "stat.total",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Summary {
+    pub count: usize,
+    pub min: u16,
+    pub max: u16,
+    pub mean: f32,
+    pub median: f32,
+    pub p25: f32,
+    pub p75: f32,
+    pub p90: f32,
+}
+
+// Resumen estadístico de una lista de valores; None si está vacía
+pub fn summarize(values: &[u16]) -> Option<Summary> {
+    if values.is_empty() {
+        return None;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+
+    let sum: f32 = sorted.iter().map(|&v| v as f32).sum();
+    Some(Summary {
+        count: sorted.len(),
+        min: sorted[0],
+        max: sorted[sorted.len() - 1],
+        mean: sum / sorted.len() as f32,
+        median: percentile(&sorted, 50.0),
+        p25: percentile(&sorted, 25.0),
+        p75: percentile(&sorted, 75.0),
+        p90: percentile(&sorted, 90.0),
+    })
+}
+
+// Percentil con interpolación lineal sobre valores ya ordenados
+pub fn percentile(sorted: &[u16], p: f32) -> f32 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = (p / 100.0).clamp(0.0, 1.0) * (sorted.len() - 1) as f32;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    let fraction = rank - lower as f32;
+    sorted[lower] as f32 + (sorted[upper] as f32 - sorted[lower] as f32) * fraction
+}
+
+pub fn metric_values(pokemons: &[&Pokemon], metric: Metric) -> Vec<u16> {
+    pokemons.iter().map(|p| metric.value(p)).collect()
+}
+
+pub fn summary_by_type(pokemons: &[&Pokemon], metric: Metric) -> BTreeMap<PokemonType, Summary> {
+    let mut values: BTreeMap<PokemonType, Vec<u16>> = BTreeMap::new();
+    for pokemon in pokemons {
+        for pokemon_type in &pokemon.types {
+            values.entry(*pokemon_type).or_default().push(metric.value(pokemon));
+        }
+    }
+    values
+        .into_iter()
+        .filter_map(|(t, v)| summarize(&v).map(|s| (t, s)))
+        .collect()
+}
+
+pub fn summary_by_generation(pokemons: &[&Pokemon], metric: Metric) -> BTreeMap<u8, Summary> {
+    let mut values: BTreeMap<u8, Vec<u16>> = BTreeMap::new();
+    for pokemon in pokemons {
+        values.entry(pokemon.generation).or_default().push(metric.value(pokemon));
+    }
+    values
+        .into_iter()
+        .filter_map(|(g, v)| summarize(&v).map(|s| (g, s)))
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Histogram {
+    pub min: u16,
+    pub bin_width: u16,
+    pub counts: Vec<usize>,
+}
+
+impl Histogram {
+    // Rango [desde, hasta) de una barra
+    pub fn bin_range(&self, index: usize) -> (u16, u16) {
+        let start = self.min + self.bin_width * index as u16;
+        (start, start + self.bin_width)
+    }
+}
+
+pub fn histogram(values: &[u16], bins: usize) -> Histogram {
+    let bins = bins.max(1);
+    let min = values.iter().copied().min().unwrap_or(0);
+    let max = values.iter().copied().max().unwrap_or(0);
+    let bin_width = ((max - min) as usize / bins + 1) as u16;
+
+    let mut counts = vec![0; bins];
+    for &value in values {
+        let index = ((value - min) / bin_width) as usize;
+        counts[index.min(bins - 1)] += 1;
+    }
+    Histogram { min, bin_width, counts }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct TypeDistribution {
+    // Pokémon que tienen el tipo en cualquiera de sus dos ranuras
+    pub by_type: BTreeMap<PokemonType, usize>,
+    // Combinaciones de tipos; los monotipo se guardan como (tipo, tipo)
+    pub pairs: BTreeMap<(PokemonType, PokemonType), usize>,
+}
+
+impl TypeDistribution {
+    // La combinación es simétrica: (Fuego, Volador) == (Volador, Fuego)
+    pub fn pair_count(&self, a: PokemonType, b: PokemonType) -> usize {
+        let key = if a <= b { (a, b) } else { (b, a) };
+        self.pairs.get(&key).copied().unwrap_or(0)
+    }
+}
+
+pub fn type_distribution(pokemons: &[&Pokemon]) -> TypeDistribution {
+    let mut distribution = TypeDistribution::default();
+    for pokemon in pokemons {
+        for pokemon_type in &pokemon.types {
+            *distribution.by_type.entry(*pokemon_type).or_default() += 1;
+        }
+        if let Some(&first) = pokemon.types.first() {
+            let second = pokemon.types.get(1).copied().unwrap_or(first);
+            let key = if first <= second { (first, second) } else { (second, first) };
+            *distribution.pairs.entry(key).or_default() += 1;
+        }
+    }
+    distribution
+}
+
+// Los n Pokémon con el valor más alto (en empate, el de menor número primero)
+pub fn top_n<'a>(pokemons: &[&'a Pokemon], metric: Metric, n: usize) -> Vec<&'a Pokemon> {
+    let mut sorted = pokemons.to_vec();
+    sorted.sort_by(|a, b| metric.value(b).cmp(&metric.value(a)).then(a.id.cmp(&b.id)));
+    sorted.truncate(n);
+    sorted
+}
+
+// Ámbito sobre el que se calcula un ranking
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RankScope {
+    All,
+    Type(PokemonType),
+    Generation(u8),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Ranking {
+    // Posición 1 = valor más alto; los empates comparten posición
+    pub rank: usize,
+    pub total: usize,
+    // Porcentaje de Pokémon por debajo (los empates cuentan la mitad)
+    pub percentile: f32,
+}
+
+// Valores ordenados por métrica y ámbito, calculados una sola vez al cargar la base de datos
+#[derive(Debug, Clone, Default)]
+pub struct StatRankings {
+    sorted: HashMap<(RankScope, Metric), Vec<u16>>,
+}
+
+impl StatRankings {
+    pub fn build(pokemons: &[&Pokemon]) -> Self {
+        let mut sorted: HashMap<(RankScope, Metric), Vec<u16>> = HashMap::new();
+        for pokemon in pokemons {
+            for metric in Metric::ALL {
+                let value = metric.value(pokemon);
+                for scope in Self::scopes_of(pokemon) {
+                    sorted.entry((scope, metric)).or_default().push(value);
+                }
+            }
+        }
+        for values in sorted.values_mut() {
+            values.sort_unstable();
+        }
+        Self { sorted }
+    }
+
+    // Ámbitos a los que pertenece un Pokémon: global, cada uno de sus tipos y su generación
+    pub fn scopes_of(pokemon: &Pokemon) -> Vec<RankScope> {
+        let mut scopes = vec![RankScope::All];
+        scopes.extend(pokemon.types.iter().map(|&t| RankScope::Type(t)));
+        scopes.push(RankScope::Generation(pokemon.generation));
+        scopes
+    }
+
+    pub fn rank(&self, pokemon: &Pokemon, metric: Metric, scope: RankScope) -> Option<Ranking> {
+        let values = self.sorted.get(&(scope, metric))?;
+        let value = metric.value(pokemon);
+        let below = values.partition_point(|&v| v < value);
+        let below_or_equal = values.partition_point(|&v| v <= value);
+        let equal = below_or_equal - below;
+        Some(Ranking {
+            rank: values.len() - below_or_equal + 1,
+            total: values.len(),
+            percentile: (below as f32 + equal as f32 / 2.0) / values.len() as f32 * 100.0,
+        })
+    }
+}
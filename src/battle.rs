@@ -0,0 +1,503 @@
+use crate::database::Database;
+use crate::i18n::I18n;
+use crate::pokemon::{calculate_stat, Pokemon, PokemonType, StatKind};
+use crate::rng::Rng;
+use crate::team::TeamMember;
+
+// Si nadie gana en este número de turnos la batalla acaba en empate
+pub const MAX_TURNS: u32 = 300;
+// Probabilidad de golpe crítico: 1 entre 24
+const CRITICAL_ODDS: usize = 24;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoveCategory {
+    Physical,
+    Special,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Move {
+    pub name: &'static str,
+    pub move_type: PokemonType,
+    pub category: MoveCategory,
+    pub power: u16,
+    // Porcentaje de acierto
+    pub accuracy: u8,
+}
+
+const fn mv(name: &'static str, move_type: PokemonType, category: MoveCategory, power: u16, accuracy: u8) -> Move {
+    Move { name, move_type, category, power, accuracy }
+}
+
+// Tabla reducida de movimientos de daño. El primero de cada tipo y categoría es el que se
+// asigna por defecto a los Pokémon sin movimientos conocidos.
+const MOVES: [Move; 50] = {
+    use MoveCategory::*;
+    use PokemonType::*;
+    [
+        mv("Body Slam", Normal, Physical, 85, 100),
+        mv("Hyper Voice", Normal, Special, 90, 100),
+        mv("Double-Edge", Normal, Physical, 120, 100),
+        mv("Fire Punch", Fire, Physical, 75, 100),
+        mv("Flamethrower", Fire, Special, 90, 100),
+        mv("Flare Blitz", Fire, Physical, 120, 100),
+        mv("Fire Blast", Fire, Special, 110, 85),
+        mv("Waterfall", Water, Physical, 80, 100),
+        mv("Surf", Water, Special, 90, 100),
+        mv("Hydro Pump", Water, Special, 110, 80),
+        mv("Thunder Punch", Electric, Physical, 75, 100),
+        mv("Thunderbolt", Electric, Special, 90, 100),
+        mv("Thunder", Electric, Special, 110, 70),
+        mv("Seed Bomb", Grass, Physical, 80, 100),
+        mv("Energy Ball", Grass, Special, 90, 100),
+        mv("Leaf Blade", Grass, Physical, 90, 100),
+        mv("Giga Drain", Grass, Special, 75, 100),
+        mv("Ice Punch", Ice, Physical, 75, 100),
+        mv("Ice Beam", Ice, Special, 90, 100),
+        mv("Blizzard", Ice, Special, 110, 70),
+        mv("Brick Break", Fighting, Physical, 75, 100),
+        mv("Aura Sphere", Fighting, Special, 80, 100),
+        mv("Close Combat", Fighting, Physical, 120, 100),
+        mv("Focus Blast", Fighting, Special, 120, 70),
+        mv("Poison Jab", Poison, Physical, 80, 100),
+        mv("Sludge Bomb", Poison, Special, 90, 100),
+        mv("Earthquake", Ground, Physical, 100, 100),
+        mv("Earth Power", Ground, Special, 90, 100),
+        mv("Drill Peck", Flying, Physical, 80, 100),
+        mv("Air Slash", Flying, Special, 75, 95),
+        mv("Brave Bird", Flying, Physical, 120, 100),
+        mv("Hurricane", Flying, Special, 110, 70),
+        mv("Zen Headbutt", Psychic, Physical, 80, 90),
+        mv("Psychic", Psychic, Special, 90, 100),
+        mv("X-Scissor", Bug, Physical, 80, 100),
+        mv("Bug Buzz", Bug, Special, 90, 100),
+        mv("Rock Slide", Rock, Physical, 75, 90),
+        mv("Power Gem", Rock, Special, 80, 100),
+        mv("Stone Edge", Rock, Physical, 100, 80),
+        mv("Shadow Claw", Ghost, Physical, 70, 100),
+        mv("Shadow Ball", Ghost, Special, 80, 100),
+        mv("Dragon Claw", Dragon, Physical, 80, 100),
+        mv("Dragon Pulse", Dragon, Special, 85, 100),
+        mv("Outrage", Dragon, Physical, 120, 100),
+        mv("Crunch", Dark, Physical, 80, 100),
+        mv("Dark Pulse", Dark, Special, 80, 100),
+        mv("Iron Head", Steel, Physical, 80, 100),
+        mv("Flash Cannon", Steel, Special, 80, 100),
+        mv("Play Rough", Fairy, Physical, 90, 90),
+        mv("Moonblast", Fairy, Special, 95, 100),
+    ]
+};
+
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+pub fn find_move(name: &str) -> Option<Move> {
+    let wanted = normalize(name);
+    MOVES.iter().find(|m| normalize(m.name) == wanted).copied()
+}
+
+// Movimientos de un miembro: los de su set que estén en la tabla o, si no hay ninguno,
+// un ataque STAB por tipo en la categoría de su mejor estadística ofensiva
+pub fn moves_for(member: &TeamMember, pokemon: &Pokemon) -> Vec<Move> {
+    let known: Vec<Move> = member.set.moves.iter().filter_map(|name| find_move(name)).collect();
+    if !known.is_empty() {
+        return known;
+    }
+
+    let category = if pokemon.stats.attack >= pokemon.stats.special_attack {
+        MoveCategory::Physical
+    } else {
+        MoveCategory::Special
+    };
+    let mut types = pokemon.types.clone();
+    if types.len() < 2 && !types.contains(&PokemonType::Normal) {
+        types.push(PokemonType::Normal);
+    }
+    types
+        .iter()
+        .filter_map(|&t| MOVES.iter().find(|m| m.move_type == t && m.category == category))
+        .copied()
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Player,
+    Opponent,
+}
+
+impl Side {
+    pub fn other(&self) -> Side {
+        match self {
+            Side::Player => Side::Opponent,
+            Side::Opponent => Side::Player,
+        }
+    }
+
+    fn index(&self) -> usize {
+        match self {
+            Side::Player => 0,
+            Side::Opponent => 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BattleEvent {
+    Turn(u32),
+    SwitchIn { side: Side, name: String, hp: u16, max_hp: u16 },
+    UseMove { side: Side, name: String, move_name: &'static str },
+    Miss { side: Side, name: String },
+    // side es el bando del Pokémon que recibe el golpe
+    Damage { side: Side, name: String, amount: u16, hp: u16, max_hp: u16, effectiveness: f32, critical: bool },
+    Faint { side: Side, name: String },
+    Victory(Side),
+    Draw,
+}
+
+impl BattleEvent {
+    // Texto del registro en el idioma elegido; team_names son los nombres de los dos equipos
+    pub fn text(&self, team_names: [&str; 2], i18n: &I18n) -> String {
+        match self {
+            BattleEvent::Turn(turn) => i18n.tr_args("battle.event.turn", &[("turn", turn)]),
+            BattleEvent::SwitchIn { name, .. } => i18n.tr_args("battle.event.switch_in", &[("name", name)]),
+            BattleEvent::UseMove { name, move_name, .. } => {
+                i18n.tr_args("battle.event.use_move", &[("name", name), ("move", move_name)])
+            }
+            BattleEvent::Miss { name, .. } => i18n.tr_args("battle.event.miss", &[("name", name)]),
+            BattleEvent::Damage { name, amount, effectiveness, critical, .. } => {
+                let mut text = i18n.tr_args("battle.event.damage", &[("name", name), ("amount", amount)]);
+                if *critical {
+                    text.push(' ');
+                    text.push_str(i18n.tr("battle.event.critical"));
+                }
+                let key = if *effectiveness == 0.0 {
+                    Some("battle.event.no_effect")
+                } else if *effectiveness > 1.0 {
+                    Some("battle.event.super_effective")
+                } else if *effectiveness < 1.0 {
+                    Some("battle.event.not_very_effective")
+                } else {
+                    None
+                };
+                if let Some(key) = key {
+                    text.push(' ');
+                    text.push_str(i18n.tr(key));
+                }
+                text
+            }
+            BattleEvent::Faint { name, .. } => i18n.tr_args("battle.event.faint", &[("name", name)]),
+            BattleEvent::Victory(side) => {
+                let team = match side {
+                    Side::Player => team_names[0],
+                    Side::Opponent => team_names[1],
+                };
+                i18n.tr_args("battle.event.victory", &[("team", &team)])
+            }
+            BattleEvent::Draw => i18n.tr("battle.event.draw").to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Combatant {
+    name: String,
+    types: Vec<PokemonType>,
+    level: u8,
+    stats: [u16; 6],
+    hp: u16,
+    moves: Vec<Move>,
+}
+
+impl Combatant {
+    fn new(member: &TeamMember, pokemon: &Pokemon) -> Self {
+        let set = &member.set;
+        let mut stats = [0; 6];
+        for (i, kind) in StatKind::ALL.into_iter().enumerate() {
+            stats[i] = calculate_stat(
+                kind,
+                pokemon.stats.get(kind),
+                set.ivs.get(kind),
+                set.evs.get(kind),
+                set.level,
+                set.nature,
+            );
+        }
+        Self {
+            name: set.nickname.clone().unwrap_or_else(|| pokemon.display_name()),
+            types: pokemon.types.clone(),
+            level: set.level,
+            stats,
+            hp: stats[0],
+            moves: moves_for(member, pokemon),
+        }
+    }
+
+    fn stat(&self, kind: StatKind) -> u16 {
+        self.stats[StatKind::ALL.iter().position(|&k| k == kind).unwrap_or(0)]
+    }
+
+    fn max_hp(&self) -> u16 {
+        self.stats[0]
+    }
+
+    fn offense_defense(&self, target: &Combatant, category: MoveCategory) -> (u16, u16) {
+        match category {
+            MoveCategory::Physical => (self.stat(StatKind::Attack), target.stat(StatKind::Defense)),
+            MoveCategory::Special => (self.stat(StatKind::SpecialAttack), target.stat(StatKind::SpecialDefense)),
+        }
+    }
+
+    // La IA elige siempre el movimiento con más daño esperado contra el rival
+    fn choose_move(&self, target: &Combatant) -> Option<Move> {
+        self.moves
+            .iter()
+            .map(|&m| {
+                let (attack, defense) = self.offense_defense(target, m.category);
+                let expected = m.power as f32
+                    * m.accuracy as f32
+                    * stab(self, m)
+                    * m.move_type.effectiveness_against(&target.types)
+                    * attack as f32
+                    / defense.max(1) as f32;
+                (m, expected)
+            })
+            .fold(None, |best: Option<(Move, f32)>, (m, expected)| match best {
+                Some((_, best_expected)) if best_expected >= expected => best,
+                _ => Some((m, expected)),
+            })
+            .map(|(m, _)| m)
+    }
+}
+
+fn stab(user: &Combatant, m: Move) -> f32 {
+    if user.types.contains(&m.move_type) {
+        1.5
+    } else {
+        1.0
+    }
+}
+
+struct Team {
+    members: Vec<Combatant>,
+    active: usize,
+}
+
+impl Team {
+    fn active(&self) -> &Combatant {
+        &self.members[self.active]
+    }
+
+    fn next_alive(&self) -> Option<usize> {
+        self.members.iter().position(|c| c.hp > 0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BattleResult {
+    pub events: Vec<BattleEvent>,
+    pub winner: Option<Side>,
+    pub turns: u32,
+}
+
+fn build_team(members: &[TeamMember], database: &Database) -> Team {
+    let members = members
+        .iter()
+        .filter_map(|member| {
+            let pokemon = database.get_pokemon_form(member.id, member.form.as_deref())?;
+            Some(Combatant::new(member, pokemon))
+        })
+        .collect();
+    Team { members, active: 0 }
+}
+
+// Fórmula de daño de los juegos con STAB, efectividad, crítico y factor aleatorio 85-100%
+fn damage(user: &Combatant, target: &Combatant, m: Move, critical: bool, rng: &mut Rng) -> (u16, f32) {
+    let effectiveness = m.move_type.effectiveness_against(&target.types);
+    if effectiveness == 0.0 {
+        return (0, 0.0);
+    }
+    let (attack, defense) = user.offense_defense(target, m.category);
+    let base = (2 * user.level as u32 / 5 + 2) * m.power as u32 * attack as u32 / defense.max(1) as u32 / 50 + 2;
+    let random = (85 + rng.below(16)) as f32 / 100.0;
+    let critical = if critical { 1.5 } else { 1.0 };
+    let total = base as f32 * stab(user, m) * effectiveness * critical * random;
+    ((total as u16).max(1), effectiveness)
+}
+
+pub fn simulate(player: &[TeamMember], opponent: &[TeamMember], database: &Database, seed: u64) -> BattleResult {
+    let mut rng = Rng::new(seed);
+    let mut teams = [build_team(player, database), build_team(opponent, database)];
+    let mut events = Vec::new();
+
+    for side in [Side::Player, Side::Opponent] {
+        let team = &teams[side.index()];
+        if let Some(first) = team.members.first() {
+            events.push(BattleEvent::SwitchIn { side, name: first.name.clone(), hp: first.hp, max_hp: first.max_hp() });
+        }
+    }
+
+    let mut turn = 0;
+    let winner = loop {
+        let alive = [teams[0].next_alive().is_some(), teams[1].next_alive().is_some()];
+        match alive {
+            [false, false] => break None,
+            [true, false] => break Some(Side::Player),
+            [false, true] => break Some(Side::Opponent),
+            _ => {}
+        }
+        if turn >= MAX_TURNS {
+            break None;
+        }
+        turn += 1;
+        events.push(BattleEvent::Turn(turn));
+
+        // Orden por velocidad; en empate decide una moneda
+        let player_speed = teams[0].active().stat(StatKind::Speed);
+        let opponent_speed = teams[1].active().stat(StatKind::Speed);
+        let first = if player_speed > opponent_speed || (player_speed == opponent_speed && rng.below(2) == 0) {
+            Side::Player
+        } else {
+            Side::Opponent
+        };
+
+        for attacker_side in [first, first.other()] {
+            let target_side = attacker_side.other();
+            let attacker = teams[attacker_side.index()].active().clone();
+            // Un Pokémon debilitado antes de actuar pierde su turno
+            if attacker.hp == 0 || teams[target_side.index()].active().hp == 0 {
+                continue;
+            }
+            let Some(chosen) = attacker.choose_move(teams[target_side.index()].active()) else {
+                continue;
+            };
+            events.push(BattleEvent::UseMove { side: attacker_side, name: attacker.name.clone(), move_name: chosen.name });
+
+            if rng.below(100) >= chosen.accuracy as usize {
+                events.push(BattleEvent::Miss { side: attacker_side, name: attacker.name.clone() });
+                continue;
+            }
+            let critical = rng.below(CRITICAL_ODDS) == 0;
+            let target_team = &mut teams[target_side.index()];
+            let target_index = target_team.active;
+            let (amount, effectiveness) = damage(&attacker, &target_team.members[target_index], chosen, critical, &mut rng);
+            let target = &mut target_team.members[target_index];
+            // El registro muestra los PS que se pierden de verdad, no el daño bruto
+            let amount = amount.min(target.hp);
+            target.hp -= amount;
+            events.push(BattleEvent::Damage {
+                side: target_side,
+                name: target.name.clone(),
+                amount,
+                hp: target.hp,
+                max_hp: target.max_hp(),
+                effectiveness,
+                critical: critical && amount > 0,
+            });
+            if target.hp == 0 {
+                events.push(BattleEvent::Faint { side: target_side, name: target.name.clone() });
+            }
+        }
+
+        // Al final del turno entra el siguiente Pokémon de cada equipo que haya perdido el suyo
+        for side in [Side::Player, Side::Opponent] {
+            let team = &mut teams[side.index()];
+            if team.active().hp == 0 {
+                if let Some(next) = team.next_alive() {
+                    team.active = next;
+                    let member = team.active();
+                    events.push(BattleEvent::SwitchIn { side, name: member.name.clone(), hp: member.hp, max_hp: member.max_hp() });
+                }
+            }
+        }
+    };
+
+    events.push(match winner {
+        Some(side) => BattleEvent::Victory(side),
+        None => BattleEvent::Draw,
+    });
+    BattleResult { events, winner, turns: turn }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BatchResult {
+    pub runs: usize,
+    pub player_wins: usize,
+    pub opponent_wins: usize,
+    pub draws: usize,
+    pub average_turns: f32,
+}
+
+impl BatchResult {
+    pub fn win_rate(&self, side: Side) -> f32 {
+        if self.runs == 0 {
+            return 0.0;
+        }
+        let wins = match side {
+            Side::Player => self.player_wins,
+            Side::Opponent => self.opponent_wins,
+        };
+        wins as f32 / self.runs as f32 * 100.0
+    }
+}
+
+// Sin interfaz: N batallas con semillas derivadas de la semilla inicial
+pub fn run_batch(
+    player: &[TeamMember],
+    opponent: &[TeamMember],
+    database: &Database,
+    runs: usize,
+    seed: u64,
+) -> BatchResult {
+    let mut seeds = Rng::new(seed);
+    let mut result = BatchResult { runs, ..BatchResult::default() };
+    let mut total_turns = 0u64;
+    for _ in 0..runs {
+        let battle = simulate(player, opponent, database, seeds.next_u64());
+        total_turns += battle.turns as u64;
+        match battle.winner {
+            Some(Side::Player) => result.player_wins += 1,
+            Some(Side::Opponent) => result.opponent_wins += 1,
+            None => result.draws += 1,
+        }
+    }
+    if runs > 0 {
+        result.average_turns = total_turns as f32 / runs as f32;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn database() -> Database {
+        let mut database = Database::new();
+        database.load_from_csv().expect("CSV");
+        database
+    }
+
+    fn team(database: &Database, names: &[&str]) -> Vec<TeamMember> {
+        names.iter().map(|name| TeamMember::from_pokemon(database.find(name).expect(name))).collect()
+    }
+
+    #[test]
+    fn same_seed_gives_the_same_battle() {
+        let database = database();
+        let player = team(&database, &["Charizard", "Pikachu", "Snorlax"]);
+        let opponent = team(&database, &["Blastoise", "Gengar", "Dragonite"]);
+        let first = simulate(&player, &opponent, &database, 42);
+        let second = simulate(&player, &opponent, &database, 42);
+        assert!(first.events.len() > 2);
+        assert_eq!(first, second);
+        assert_eq!(first.events.last(), Some(&first.winner.map_or(BattleEvent::Draw, BattleEvent::Victory)));
+        assert_eq!(
+            run_batch(&player, &opponent, &database, 20, 7),
+            run_batch(&player, &opponent, &database, 20, 7)
+        );
+    }
+}
@@ -0,0 +1,308 @@
+use crate::i18n::{I18n, LocalizedNames};
+use crate::pokemon::{Pokemon, PokemonType, StatKind};
+use crate::sprites;
+use base64::Engine;
+use std::error::Error;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Mismas medidas que el panel de detalles de la interfaz
+const WIDTH: f32 = 400.0;
+const MARGIN: f32 = 16.0;
+const SPRITE_SIZE: f32 = 96.0;
+const STAT_ROW: f32 = 28.0;
+const BAR_X: f32 = 112.0;
+const BAR_WIDTH: f32 = 230.0;
+const FONT: &str = "Segoe UI, Helvetica, Arial, sans-serif";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum CardFormat {
+    Svg,
+    Html,
+}
+
+impl CardFormat {
+    pub const ALL: [CardFormat; 2] = [CardFormat::Svg, CardFormat::Html];
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            CardFormat::Svg => "svg",
+            CardFormat::Html => "html",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            CardFormat::Svg => "SVG",
+            CardFormat::Html => "HTML",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CardOptions {
+    pub format: CardFormat,
+    // Debilidades, resistencias e inmunidades
+    pub defense: bool,
+    // Sprite incrustado en la tarjeta (si existe en la carpeta de sprites)
+    pub sprite: bool,
+    pub sprites_dir: PathBuf,
+}
+
+impl Default for CardOptions {
+    fn default() -> Self {
+        Self {
+            format: CardFormat::Svg,
+            defense: true,
+            sprite: true,
+            sprites_dir: sprites::sprites_dir(),
+        }
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+// Aproximación del ancho del texto, suficiente para colocar las etiquetas
+fn text_width(text: &str, size: f32) -> f32 {
+    text.chars().count() as f32 * size * 0.6
+}
+
+fn rgb((r, g, b): (u8, u8, u8)) -> String {
+    format!("rgb({},{},{})", r, g, b)
+}
+
+fn text(svg: &mut String, x: f32, y: f32, size: f32, color: (u8, u8, u8), bold: bool, content: &str) {
+    let weight = if bold { " font-weight=\"bold\"" } else { "" };
+    let _ = writeln!(
+        svg,
+        "  <text x=\"{:.1}\" y=\"{:.1}\" font-size=\"{:.1}\" fill=\"{}\"{}>{}</text>",
+        x, y, size, rgb(color), weight, escape(content)
+    );
+}
+
+// Etiqueta de tipo con su color; devuelve el ancho ocupado
+fn type_badge(svg: &mut String, x: f32, y: f32, height: f32, pokemon_type: PokemonType, label: &str) -> f32 {
+    let size = height * 0.55;
+    let width = text_width(label, size) + height * 0.7;
+    let _ = writeln!(
+        svg,
+        "  <rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{:.1}\" rx=\"{:.1}\" fill=\"{}\"/>",
+        x, y, width, height, height / 2.0, rgb(pokemon_type.rgb())
+    );
+    let _ = writeln!(
+        svg,
+        "  <text x=\"{:.1}\" y=\"{:.1}\" font-size=\"{:.1}\" fill=\"white\" font-weight=\"bold\" text-anchor=\"middle\">{}</text>",
+        x + width / 2.0, y + height * 0.72, size, escape(label)
+    );
+    width
+}
+
+fn sprite_image(path: &Path) -> Option<String> {
+    let bytes = fs::read(path).ok()?;
+    let mime = match path.extension()?.to_str()? {
+        "gif" => "image/gif",
+        _ => "image/png",
+    };
+    Some(format!("data:{};base64,{}", mime, base64::engine::general_purpose::STANDARD.encode(bytes)))
+}
+
+// Tarjeta en SVG con lo mismo que el panel de detalles: sprite, nombre, número, tipos y estadísticas
+pub fn render_svg(pokemon: &Pokemon, names: &LocalizedNames, i18n: &I18n, options: &CardOptions) -> String {
+    let mut body = String::new();
+    let mut header_x = MARGIN;
+
+    if options.sprite {
+        match sprites::find_sprite(&options.sprites_dir, pokemon, false).and_then(|path| sprite_image(&path)) {
+            Some(data) => {
+                let _ = writeln!(
+                    body,
+                    "  <image x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" href=\"{}\" style=\"image-rendering:pixelated\"/>",
+                    MARGIN, MARGIN, SPRITE_SIZE, SPRITE_SIZE, data
+                );
+            }
+            None => {
+                let _ = writeln!(
+                    body,
+                    "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" rx=\"{:.1}\" fill=\"rgb(225,235,245)\" stroke=\"rgb(180,200,220)\"/>",
+                    MARGIN + 1.0, MARGIN + 1.0, SPRITE_SIZE - 2.0, SPRITE_SIZE - 2.0, SPRITE_SIZE * 0.2
+                );
+                let _ = writeln!(
+                    body,
+                    "  <text x=\"{}\" y=\"{}\" font-size=\"{}\" fill=\"rgb(140,160,180)\" text-anchor=\"middle\">?</text>",
+                    MARGIN + SPRITE_SIZE / 2.0, MARGIN + SPRITE_SIZE * 0.67, SPRITE_SIZE * 0.5
+                );
+            }
+        }
+        header_x += SPRITE_SIZE + MARGIN;
+    }
+
+    // Nombre (más pequeño si no cabe), número y tipos
+    let name = i18n.pokemon_name(pokemon, names);
+    let available = WIDTH - header_x - MARGIN;
+    let name_size = (available / (name.chars().count() as f32 * 0.6)).clamp(14.0, 28.0);
+    text(&mut body, header_x, 48.0, name_size, (40, 110, 80), true, &name);
+    text(&mut body, header_x, 74.0, 18.0, (60, 130, 100), false, &format!("#{:03}", pokemon.id));
+    let mut badge_x = header_x;
+    for &pokemon_type in &pokemon.types {
+        badge_x += type_badge(&mut body, badge_x, 86.0, 24.0, pokemon_type, &i18n.type_name(pokemon_type, names)) + 6.0;
+    }
+
+    // Información básica
+    let mut y = MARGIN + SPRITE_SIZE + 28.0;
+    let mut info = i18n.tr_args("details.generation", &[("number", &pokemon.generation)]);
+    if let Some(height) = pokemon.formatted_height() {
+        info += &format!("  ·  {}", i18n.tr_args("details.height", &[("value", &format!("{:.1}", height))]));
+    }
+    if let Some(weight) = pokemon.formatted_weight() {
+        info += &format!("  ·  {}", i18n.tr_args("details.weight", &[("value", &format!("{:.1}", weight))]));
+    }
+    text(&mut body, MARGIN, y, 14.0, (60, 80, 100), false, &info);
+    if pokemon.legendary {
+        let legendary = i18n.tr("details.legendary");
+        text(&mut body, WIDTH - MARGIN - text_width(legendary, 14.0), y, 14.0, (200, 150, 30), true, legendary);
+    }
+
+    // Barras de estadísticas con el mismo degradado de color que la interfaz
+    y += 28.0;
+    text(&mut body, MARGIN, y, 15.0, (40, 80, 120), true, i18n.tr("details.stats"));
+    y += 10.0;
+    for kind in StatKind::ALL {
+        let value = pokemon.stats.get(kind);
+        let progress = value as f32 / 255.0;
+        text(&mut body, MARGIN, y + 15.0, 14.0, (40, 80, 120), false, i18n.stat_name(kind));
+        let _ = writeln!(
+            body,
+            "  <rect x=\"{}\" y=\"{:.1}\" width=\"{}\" height=\"20\" rx=\"4\" fill=\"rgb(220,230,240)\" stroke=\"rgb(180,200,220)\"/>",
+            BAR_X, y, BAR_WIDTH
+        );
+        let bar_width = (BAR_WIDTH - 4.0) * progress;
+        if bar_width > 0.0 {
+            let color = (
+                (200.0 - progress * 100.0) as u8,
+                (180.0 + progress * 75.0) as u8,
+                (140.0 + progress * 40.0) as u8,
+            );
+            let _ = writeln!(
+                body,
+                "  <rect x=\"{}\" y=\"{:.1}\" width=\"{:.1}\" height=\"16\" rx=\"3\" fill=\"{}\"/>",
+                BAR_X + 2.0, y + 2.0, bar_width, rgb(color)
+            );
+            let _ = writeln!(
+                body,
+                "  <line x1=\"{}\" y1=\"{:.1}\" x2=\"{:.1}\" y2=\"{:.1}\" stroke=\"rgba(255,255,255,0.7)\"/>",
+                BAR_X + 3.0, y + 3.0, BAR_X + 1.0 + bar_width, y + 3.0
+            );
+        }
+        text(&mut body, BAR_X + BAR_WIDTH + 10.0, y + 15.0, 14.0, (40, 80, 120), false, &value.to_string());
+        y += STAT_ROW;
+    }
+    let total = format!("{}: {}", i18n.tr("stat.total"), pokemon.total_stats());
+    text(&mut body, MARGIN, y + 14.0, 15.0, (40, 80, 120), true, &total);
+    y += 24.0;
+
+    // Perfil defensivo: tipos agrupados por multiplicador
+    if options.defense {
+        y += 16.0;
+        text(&mut body, MARGIN, y, 15.0, (40, 80, 120), true, i18n.tr("card.defense"));
+        y += 8.0;
+        for (multiplier, label) in [(4.0, "×4"), (2.0, "×2"), (0.5, "×½"), (0.25, "×¼"), (0.0, "×0")] {
+            let types: Vec<PokemonType> = PokemonType::ALL
+                .into_iter()
+                .filter(|t| t.effectiveness_against(&pokemon.types) == multiplier)
+                .collect();
+            if types.is_empty() {
+                continue;
+            }
+            text(&mut body, MARGIN, y + 14.0, 14.0, (40, 80, 120), true, label);
+            let mut x = MARGIN + 36.0;
+            for pokemon_type in types {
+                let label = i18n.type_name(pokemon_type, names);
+                let width = text_width(&label, 18.0 * 0.55) + 18.0 * 0.7;
+                if x + width > WIDTH - MARGIN {
+                    x = MARGIN + 36.0;
+                    y += 22.0;
+                }
+                x += type_badge(&mut body, x, y + 1.0, 18.0, pokemon_type, &label) + 4.0;
+            }
+            y += 24.0;
+        }
+    }
+
+    let height = y + MARGIN;
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" font-family=\"{font}\">\n",
+        w = WIDTH, h = height, font = FONT
+    );
+    let _ = writeln!(
+        svg,
+        "  <rect x=\"0.5\" y=\"0.5\" width=\"{}\" height=\"{}\" rx=\"8\" fill=\"rgb(240,248,255)\" stroke=\"rgb(180,200,220)\"/>",
+        WIDTH - 1.0, height - 1.0
+    );
+    svg += &body;
+    svg += "</svg>\n";
+    svg
+}
+
+// Documento HTML con la tarjeta SVG incrustada, listo para imprimir
+pub fn render_html(cards: &[String], title: &str) -> String {
+    let mut html = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>\n\
+         body {{ margin: 16px; }}\n\
+         .card {{ display: inline-block; margin: 8px; page-break-inside: avoid; break-inside: avoid; }}\n\
+         </style>\n</head>\n<body>\n",
+        escape(title)
+    );
+    for card in cards {
+        let _ = write!(html, "<div class=\"card\">\n{}</div>\n", card);
+    }
+    html += "</body>\n</html>\n";
+    html
+}
+
+pub fn render(pokemon: &Pokemon, names: &LocalizedNames, i18n: &I18n, options: &CardOptions) -> String {
+    let svg = render_svg(pokemon, names, i18n, options);
+    match options.format {
+        CardFormat::Svg => svg,
+        CardFormat::Html => render_html(&[svg], &i18n.pokemon_name(pokemon, names)),
+    }
+}
+
+// "6-mega-charizard-x.svg"
+pub fn file_name(pokemon: &Pokemon, format: CardFormat) -> String {
+    format!("{}.{}", sprites::sprite_stem(pokemon), format.extension())
+}
+
+pub const SHEET_FILE: &str = "index.html";
+
+// Una tarjeta por Pokémon en la carpeta indicada; en HTML además una hoja con todas
+// (index.html) para imprimirlas de una vez. Devuelve cuántas tarjetas se escribieron.
+pub fn write_cards(
+    dir: &Path,
+    pokemons: &[&Pokemon],
+    names: &LocalizedNames,
+    i18n: &I18n,
+    options: &CardOptions,
+) -> Result<usize, Box<dyn Error>> {
+    fs::create_dir_all(dir)?;
+    let mut sheet = Vec::new();
+    for pokemon in pokemons {
+        let svg = render_svg(pokemon, names, i18n, options);
+        let card = match options.format {
+            CardFormat::Svg => svg,
+            CardFormat::Html => {
+                let html = render_html(std::slice::from_ref(&svg), &i18n.pokemon_name(pokemon, names));
+                sheet.push(svg);
+                html
+            }
+        };
+        fs::write(dir.join(file_name(pokemon, options.format)), card)?;
+    }
+    if options.format == CardFormat::Html {
+        fs::write(dir.join(SHEET_FILE), render_html(&sheet, "Pokédex"))?;
+    }
+    Ok(pokemons.len())
+}
@@ -0,0 +1,481 @@
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use pokedex::analytics::Metric;
+use pokedex::battle::{self, Side};
+use pokedex::card::{self, CardFormat, CardOptions};
+use pokedex::export::{self, Column, ExportFormat};
+use pokedex::flashcards::{self, CardTemplate, DeckFormat};
+use pokedex::i18n::{I18n, Language};
+use pokedex::rng::Rng;
+use pokedex::showdown;
+use pokedex::team::{Team, TeamMember};
+use pokedex::{Database, Pokemon, PokemonFilter, PokemonType, StatKind};
+use std::error::Error;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+// Sin subcomando se abre la interfaz gráfica
+#[derive(Parser)]
+#[command(name = "pokedex", version, about = "Pokédex en Rust: interfaz gráfica y consultas desde la terminal")]
+pub struct Cli {
+    #[arg(long, global = true, help = "Salida en JSON en lugar de tabla")]
+    pub json: bool,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    #[command(about = "Muestra la ficha de un Pokémon por nombre o número")]
+    Show {
+        query: String,
+    },
+    #[command(about = "Busca Pokémon por nombre (en cualquier idioma cargado)")]
+    Search {
+        query: String,
+    },
+    #[command(about = "Lista los Pokémon con filtros y orden")]
+    List {
+        #[command(flatten)]
+        query: QueryArgs,
+    },
+    #[command(about = "Comprueba la coherencia de los datos")]
+    Validate,
+    #[cfg(feature = "server")]
+    #[command(about = "Sirve la base de datos como API JSON por HTTP")]
+    Serve {
+        #[arg(long, default_value = "127.0.0.1")]
+        host: String,
+        #[arg(long, default_value_t = 8080)]
+        port: u16,
+    },
+    #[command(about = "Atiende peticiones JSON-RPC 2.0 por la entrada estándar (una por línea)")]
+    Rpc,
+    #[cfg(feature = "tui")]
+    #[command(about = "Abre la interfaz de texto para la terminal")]
+    Tui,
+    #[cfg(feature = "graphql")]
+    #[command(about = "Imprime el esquema GraphQL en SDL")]
+    GraphqlSchema {
+        #[arg(long, short, help = "Archivo de salida; por defecto la salida estándar")]
+        output: Option<PathBuf>,
+    },
+    #[command(about = "Genera la tarjeta imprimible (SVG o HTML) de un Pokémon")]
+    Card {
+        query: String,
+        #[arg(long, help = "Forma concreta (p. ej. \"Mega Charizard X\")")]
+        form: Option<String>,
+        #[command(flatten)]
+        card: CardArgs,
+        #[arg(long, short, help = "Archivo de salida; por defecto la salida estándar")]
+        output: Option<PathBuf>,
+    },
+    #[command(about = "Genera una tarjeta por cada Pokémon de una consulta")]
+    Cards {
+        #[command(flatten)]
+        card: CardArgs,
+        #[arg(long, default_value = "cards", help = "Carpeta donde se escriben las tarjetas")]
+        out_dir: PathBuf,
+        #[command(flatten)]
+        query: QueryArgs,
+    },
+    #[command(about = "Exporta un mazo de tarjetas de estudio importable en Anki")]
+    Deck {
+        #[arg(long, value_enum, value_delimiter = ',', default_value = "name-types", help = "Plantillas, separadas por comas")]
+        template: Vec<CardTemplate>,
+        #[arg(long, value_enum, help = "Por defecto se deduce de la extensión de --output, o TSV")]
+        format: Option<DeckFormat>,
+        #[arg(long, short, help = "Archivo del mazo")]
+        output: PathBuf,
+        #[command(flatten)]
+        query: QueryArgs,
+    },
+    #[command(about = "Simula combates entre dos equipos y muestra los porcentajes de victoria")]
+    Battle {
+        #[arg(help = "Equipo 1: archivo en formato Showdown o especies separadas por comas")]
+        player: String,
+        #[arg(help = "Equipo 2: archivo en formato Showdown o especies separadas por comas")]
+        opponent: String,
+        #[arg(long, default_value_t = 100, help = "Número de combates")]
+        runs: usize,
+        #[arg(long, help = "Semilla inicial; por defecto una aleatoria")]
+        seed: Option<u64>,
+        #[arg(long, help = "Muestra también el registro del primer combate")]
+        log: bool,
+        #[arg(long, default_value = "es", value_parser = parse_language, help = "Idioma del registro (es, en)")]
+        lang: Language,
+    },
+    #[command(about = "Exporta la base de datos o el resultado de una consulta")]
+    Export {
+        #[arg(long, value_enum, help = "Por defecto se deduce de la extensión de --output, o JSON")]
+        format: Option<ExportFormat>,
+        #[arg(long, short, help = "Archivo de salida; por defecto la salida estándar")]
+        output: Option<PathBuf>,
+        #[arg(long, value_enum, value_delimiter = ',', help = "Columnas a exportar, separadas por comas; por defecto todas")]
+        columns: Vec<Column>,
+        #[command(flatten)]
+        query: QueryArgs,
+    },
+}
+
+#[derive(Args)]
+pub struct CardArgs {
+    #[arg(long, value_enum, default_value = "svg")]
+    format: CardFormat,
+    #[arg(long, help = "Sin el perfil defensivo")]
+    no_defense: bool,
+    #[arg(long, help = "Sin el sprite")]
+    no_sprite: bool,
+}
+
+impl CardArgs {
+    fn options(&self) -> CardOptions {
+        CardOptions {
+            format: self.format,
+            defense: !self.no_defense,
+            sprite: !self.no_sprite,
+            ..CardOptions::default()
+        }
+    }
+}
+
+// Filtros y orden comunes a `list`, `cards`, `deck` y `export`
+#[derive(Args)]
+pub struct QueryArgs {
+    #[arg(long, help = "Texto de búsqueda por nombre")]
+    search: Option<String>,
+    #[arg(long, value_enum, default_value = "id")]
+    sort: SortKey,
+    #[arg(long = "type", value_parser = parse_type)]
+    pokemon_type: Option<PokemonType>,
+    #[arg(long)]
+    generation: Option<u8>,
+    #[arg(long, help = "Invierte el orden")]
+    reverse: bool,
+    #[arg(long, help = "Incluye las formas (megas, formas regionales...)")]
+    forms: bool,
+    #[arg(long)]
+    limit: Option<usize>,
+}
+
+impl QueryArgs {
+    fn select<'a>(&self, database: &'a Database) -> Vec<&'a Pokemon> {
+        let filter = PokemonFilter {
+            search: self.search.clone().unwrap_or_default(),
+            pokemon_type: self.pokemon_type,
+            generation: self.generation,
+            ..PokemonFilter::default()
+        };
+        let mut pokemons = if self.forms { database.filter_entries(&filter) } else { database.query(&filter) };
+        match self.sort.metric() {
+            // Las estadísticas se ordenan de mayor a menor
+            Some(metric) => pokemons.sort_by_key(|p| std::cmp::Reverse(metric.value(p))),
+            None if self.sort == SortKey::Name => pokemons.sort_by_key(|p| p.display_name()),
+            None => {}
+        }
+        if self.reverse {
+            pokemons.reverse();
+        }
+        if let Some(limit) = self.limit {
+            pokemons.truncate(limit);
+        }
+        pokemons
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SortKey {
+    Id,
+    Name,
+    Hp,
+    Attack,
+    Defense,
+    SpAtk,
+    SpDef,
+    Speed,
+    Total,
+}
+
+impl SortKey {
+    fn metric(&self) -> Option<Metric> {
+        match self {
+            SortKey::Id | SortKey::Name => None,
+            SortKey::Hp => Some(Metric::Stat(StatKind::Hp)),
+            SortKey::Attack => Some(Metric::Stat(StatKind::Attack)),
+            SortKey::Defense => Some(Metric::Stat(StatKind::Defense)),
+            SortKey::SpAtk => Some(Metric::Stat(StatKind::SpecialAttack)),
+            SortKey::SpDef => Some(Metric::Stat(StatKind::SpecialDefense)),
+            SortKey::Speed => Some(Metric::Stat(StatKind::Speed)),
+            SortKey::Total => Some(Metric::Total),
+        }
+    }
+}
+
+fn parse_language(code: &str) -> Result<Language, String> {
+    Language::ALL
+        .into_iter()
+        .find(|language| language.code().eq_ignore_ascii_case(code))
+        .ok_or_else(|| format!("idioma desconocido: {}", code))
+}
+
+fn parse_type(name: &str) -> Result<PokemonType, String> {
+    PokemonType::from_name(name).ok_or_else(|| format!("tipo desconocido: {}", name))
+}
+
+pub fn run(command: Command, database: &Database, json: bool, out: &mut dyn Write) -> Result<ExitCode, Box<dyn Error>> {
+    match command {
+        Command::Show { query } => {
+            let Some(pokemon) = database.find(&query) else {
+                eprintln!("No se encontró ningún Pokémon con «{}»", query);
+                return Ok(ExitCode::FAILURE);
+            };
+            if json {
+                writeln!(out, "{}", serde_json::to_string_pretty(pokemon)?)?;
+            } else {
+                print_details(out, pokemon)?;
+            }
+        }
+        Command::Search { query } => {
+            let filter = PokemonFilter { search: query, ..PokemonFilter::default() };
+            print_list(out, &database.filter_entries(&filter), json)?;
+        }
+        Command::List { query } => print_list(out, &query.select(database), json)?,
+        Command::Validate => {
+            let issues = database.validate();
+            if json {
+                let messages: Vec<String> = issues.iter().map(ToString::to_string).collect();
+                writeln!(out, "{}", serde_json::to_string_pretty(&messages)?)?;
+            } else if issues.is_empty() {
+                writeln!(out, "Datos correctos: {} entradas", database.get_all_pokemon().len())?;
+            } else {
+                for issue in &issues {
+                    writeln!(out, "{}", issue)?;
+                }
+                writeln!(out, "{} problemas encontrados", issues.len())?;
+            }
+            if !issues.is_empty() {
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        #[cfg(feature = "server")]
+        Command::Serve { host, port } => {
+            let server = pokedex::server::ApiServer::bind(&format!("{}:{}", host, port)).map_err(|e| e.to_string())?;
+            if let Some(address) = server.local_addr() {
+                eprintln!("Sirviendo la API en http://{}", address);
+            }
+            server.serve(database);
+        }
+        Command::Rpc => pokedex::rpc::serve(database, &mut io::stdin().lock(), out)?,
+        #[cfg(feature = "tui")]
+        Command::Tui => pokedex::tui::run(database)?,
+        #[cfg(feature = "graphql")]
+        Command::GraphqlSchema { output } => {
+            let sdl = pokedex::graphql::sdl();
+            match output {
+                Some(path) => fs::write(path, sdl)?,
+                None => write!(out, "{}", sdl)?,
+            }
+        }
+        Command::Card { query, form, card, output } => {
+            let Some(found) = database.find(&query) else {
+                eprintln!("No se encontró ningún Pokémon con «{}»", query);
+                return Ok(ExitCode::FAILURE);
+            };
+            let pokemon = match form {
+                Some(form) => match database
+                    .get_forms(found.id)
+                    .into_iter()
+                    .find(|p| p.form.as_deref().is_some_and(|f| f.eq_ignore_ascii_case(&form)))
+                {
+                    Some(pokemon) => pokemon,
+                    None => {
+                        eprintln!("{} no tiene la forma «{}»", found.name, form);
+                        return Ok(ExitCode::FAILURE);
+                    }
+                },
+                None => found,
+            };
+            let i18n = I18n::new(Language::Spanish);
+            let text = card::render(pokemon, database.names(), &i18n, &card.options());
+            match output {
+                Some(path) => fs::write(path, text)?,
+                None => write!(out, "{}", text)?,
+            }
+        }
+        Command::Cards { card, out_dir, query } => {
+            let i18n = I18n::new(Language::Spanish);
+            let count = card::write_cards(&out_dir, &query.select(database), database.names(), &i18n, &card.options())?;
+            eprintln!("{} tarjetas escritas en {}", count, out_dir.display());
+        }
+        Command::Deck { template, format, output, query } => {
+            let format = format
+                .or_else(|| DeckFormat::ALL.into_iter().find(|f| output.extension().is_some_and(|e| e == f.extension())))
+                .unwrap_or(DeckFormat::Tsv);
+            let i18n = I18n::new(Language::Spanish);
+            let summary = flashcards::write_deck(&output, &query.select(database), &template, format, database.names(), &i18n)?;
+            eprintln!("{} tarjetas escritas en {}", summary.cards, output.display());
+            if let Some(media_dir) = summary.media_dir {
+                eprintln!("{} sprites copiados a {} (cópialos a collection.media de Anki)", summary.media, media_dir.display());
+            }
+        }
+        Command::Battle { player, opponent, runs, seed, log, lang } => {
+            let player = load_team(&player, database)?;
+            let opponent = load_team(&opponent, database)?;
+            let seed = seed.unwrap_or_else(Rng::random_seed);
+            let batch = battle::run_batch(player.members(), opponent.members(), database, runs, seed);
+            // El primer combate del lote usa la primera semilla derivada
+            let log: Vec<String> = if log {
+                let i18n = I18n::new(lang);
+                let first = battle::simulate(player.members(), opponent.members(), database, Rng::new(seed).next_u64());
+                first.events.iter().map(|event| event.text([player.name(), opponent.name()], &i18n)).collect()
+            } else {
+                Vec::new()
+            };
+            if json {
+                let mut summary = serde_json::json!({
+                    "seed": seed,
+                    "runs": batch.runs,
+                    "player_wins": batch.player_wins,
+                    "opponent_wins": batch.opponent_wins,
+                    "draws": batch.draws,
+                    "player_win_rate": batch.win_rate(Side::Player),
+                    "opponent_win_rate": batch.win_rate(Side::Opponent),
+                    "average_turns": batch.average_turns,
+                });
+                if !log.is_empty() {
+                    summary["log"] = serde_json::json!(log);
+                }
+                writeln!(out, "{}", serde_json::to_string_pretty(&summary)?)?;
+            } else {
+                for line in &log {
+                    writeln!(out, "{}", line)?;
+                }
+                let percent = |count: usize| format!("{:.1}%", count as f32 / batch.runs.max(1) as f32 * 100.0);
+                let rows = vec![
+                    vec!["Equipo 1".to_string(), batch.player_wins.to_string(), percent(batch.player_wins)],
+                    vec!["Equipo 2".to_string(), batch.opponent_wins.to_string(), percent(batch.opponent_wins)],
+                    vec!["Empates".to_string(), batch.draws.to_string(), percent(batch.draws)],
+                ];
+                print_table(out, &["", "Combates", "%"], &rows)?;
+                writeln!(out, "{} combates, {:.1} turnos de media, semilla {}", batch.runs, batch.average_turns, seed)?;
+            }
+        }
+        Command::Export { format, output, columns, query } => {
+            let format = format
+                .or_else(|| output.as_deref().and_then(ExportFormat::from_path))
+                .unwrap_or(ExportFormat::Json);
+            let text = export::export(&query.select(database), &columns, format)?;
+            match output {
+                Some(path) => fs::write(path, text)?,
+                None => write!(out, "{}", text)?,
+            }
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+// Si el argumento es un archivo se importa como Showdown; si no, son especies separadas por comas
+fn load_team(arg: &str, database: &Database) -> Result<Team, Box<dyn Error>> {
+    let path = Path::new(arg);
+    let members = if path.is_file() {
+        let import = showdown::parse_team(&fs::read_to_string(path)?, database);
+        let i18n = I18n::new(Language::Spanish);
+        for diagnostic in &import.diagnostics {
+            let text = i18n.tr_args(diagnostic.key(), &[("line", &diagnostic.line), ("value", &diagnostic.value())]);
+            eprintln!("{}: {}", path.display(), text);
+        }
+        import.members
+    } else {
+        arg.split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(|name| {
+                database
+                    .find(name)
+                    .map(TeamMember::from_pokemon)
+                    .ok_or_else(|| format!("No se encontró ningún Pokémon con «{}»", name))
+            })
+            .collect::<Result<Vec<_>, _>>()?
+    };
+    if members.is_empty() {
+        return Err(format!("el equipo «{}» está vacío", arg).into());
+    }
+    Ok(Team::with_members(arg, members)?)
+}
+
+fn type_list(pokemon: &Pokemon) -> String {
+    pokemon.types.iter().map(ToString::to_string).collect::<Vec<_>>().join("/")
+}
+
+fn print_details(out: &mut dyn Write, pokemon: &Pokemon) -> io::Result<()> {
+    let i18n = I18n::new(Language::Spanish);
+    let mut rows = vec![
+        vec!["Número".to_string(), format!("#{:03}", pokemon.id)],
+        vec!["Nombre".to_string(), pokemon.display_name()],
+        vec!["Tipos".to_string(), type_list(pokemon)],
+        vec!["Generación".to_string(), pokemon.generation.to_string()],
+        vec!["Legendario".to_string(), if pokemon.legendary { "sí" } else { "no" }.to_string()],
+    ];
+    for kind in StatKind::ALL {
+        rows.push(vec![i18n.stat_name(kind).to_string(), pokemon.stats.get(kind).to_string()]);
+    }
+    rows.push(vec!["Total".to_string(), pokemon.total_stats().to_string()]);
+    print_table(out, &[], &rows)
+}
+
+fn print_list(out: &mut dyn Write, pokemons: &[&Pokemon], json: bool) -> Result<(), Box<dyn Error>> {
+    if json {
+        writeln!(out, "{}", serde_json::to_string_pretty(pokemons)?)?;
+        return Ok(());
+    }
+    let rows: Vec<Vec<String>> = pokemons
+        .iter()
+        .map(|p| {
+            let mut row = vec![format!("#{:03}", p.id), p.display_name(), type_list(p)];
+            row.extend(StatKind::ALL.iter().map(|&kind| p.stats.get(kind).to_string()));
+            row.push(p.total_stats().to_string());
+            row
+        })
+        .collect();
+    print_table(out, &["#", "Nombre", "Tipos", "PS", "Ata", "Def", "AtEsp", "DefEsp", "Vel", "Total"], &rows)?;
+    Ok(())
+}
+
+// Tabla alineada con columnas separadas por dos espacios; los números a la derecha
+fn print_table(out: &mut dyn Write, headers: &[&str], rows: &[Vec<String>]) -> io::Result<()> {
+    let columns = headers.len().max(rows.iter().map(Vec::len).max().unwrap_or(0));
+    let mut widths = vec![0; columns];
+    for (i, header) in headers.iter().enumerate() {
+        widths[i] = header.chars().count();
+    }
+    for row in rows {
+        for (i, cell) in row.iter().enumerate() {
+            widths[i] = widths[i].max(cell.chars().count());
+        }
+    }
+
+    let format_row = |cells: Vec<&str>| {
+        let line: Vec<String> = cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| {
+                if cell.parse::<u32>().is_ok() {
+                    format!("{:>width$}", cell, width = widths[i])
+                } else {
+                    format!("{:<width$}", cell, width = widths[i])
+                }
+            })
+            .collect();
+        line.join("  ").trim_end().to_string()
+    };
+    if !headers.is_empty() {
+        writeln!(out, "{}", format_row(headers.to_vec()))?;
+        writeln!(out, "{}", widths.iter().map(|&w| "-".repeat(w)).collect::<Vec<_>>().join("  "))?;
+    }
+    for row in rows {
+        writeln!(out, "{}", format_row(row.iter().map(String::as_str).collect()))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,315 @@
+use crate::database::Database;
+use crate::pokemon::Pokemon;
+use crate::storage;
+use crate::team::{Team, TeamMember, MAX_TEAM_SIZE};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+const COLLECTIONS_FILE: &str = "collections.json";
+// Versión del formato del archivo; al cambiarla hay que añadir su paso en migrate()
+pub const CURRENT_VERSION: u32 = 1;
+pub const BOX_SIZE: usize = 30;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CollectionKind {
+    Team,
+    Box,
+    List,
+}
+
+impl CollectionKind {
+    pub const ALL: [CollectionKind; 3] = [CollectionKind::Team, CollectionKind::Box, CollectionKind::List];
+
+    // Clave del texto traducido en los catálogos de i18n
+    pub fn key(&self) -> &'static str {
+        match self {
+            CollectionKind::Team => "collections.kind.team",
+            CollectionKind::Box => "collections.kind.box",
+            CollectionKind::List => "collections.kind.list",
+        }
+    }
+
+    // Máximo de Pokémon; las listas no tienen límite
+    pub fn capacity(&self) -> Option<usize> {
+        match self {
+            CollectionKind::Team => Some(MAX_TEAM_SIZE),
+            CollectionKind::Box => Some(BOX_SIZE),
+            CollectionKind::List => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CollectionError {
+    Full(usize),
+    // Se esperaba un equipo y la colección es de otro tipo
+    NotATeam(CollectionKind),
+    UnsupportedVersion(u32),
+}
+
+impl fmt::Display for CollectionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CollectionError::Full(capacity) => write!(f, "la colección ya tiene {} Pokémon", capacity),
+            CollectionError::NotATeam(kind) => write!(f, "la colección es de tipo {:?}, no un equipo", kind),
+            CollectionError::UnsupportedVersion(version) => write!(
+                f,
+                "versión de archivo {} no soportada (la más reciente es {})",
+                version, CURRENT_VERSION
+            ),
+        }
+    }
+}
+
+impl Error for CollectionError {}
+
+// Equipo, caja o lista personalizada con nombre
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Collection {
+    pub name: String,
+    pub kind: CollectionKind,
+    #[serde(default)]
+    pub members: Vec<TeamMember>,
+}
+
+impl Collection {
+    pub fn new(name: impl Into<String>, kind: CollectionKind) -> Self {
+        Self {
+            name: name.into(),
+            kind,
+            members: Vec::new(),
+        }
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.kind.capacity().is_some_and(|capacity| self.members.len() >= capacity)
+    }
+
+    pub fn add(&mut self, member: TeamMember) -> Result<(), CollectionError> {
+        if let Some(capacity) = self.kind.capacity().filter(|_| self.is_full()) {
+            return Err(CollectionError::Full(capacity));
+        }
+        self.members.push(member);
+        Ok(())
+    }
+
+    pub fn remove(&mut self, index: usize) -> Option<TeamMember> {
+        (index < self.members.len()).then(|| self.members.remove(index))
+    }
+
+    pub fn contains(&self, id: u16) -> bool {
+        self.members.iter().any(|member| member.id == id)
+    }
+
+    // Especies de la colección, para el filtro de la lista
+    pub fn species_ids(&self) -> BTreeSet<u16> {
+        self.members.iter().map(|member| member.id).collect()
+    }
+
+    // Pokémon de la base de datos para cada miembro (se omiten los que no existen)
+    pub fn resolve<'a>(&self, database: &'a Database) -> Vec<&'a Pokemon> {
+        self.members
+            .iter()
+            .filter_map(|member| database.get_pokemon_form(member.id, member.form.as_deref()))
+            .collect()
+    }
+}
+
+// Contenido del archivo (versión 1, la primera):
+// { "version": 1, "collections": [{ "name": "...", "kind": "Team" | "Box" | "List", "members": [TeamMember...] }] }
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct CollectionsFile {
+    version: u32,
+    collections: Vec<Collection>,
+}
+
+// Lleva un JSON de una versión anterior a la actual, paso a paso; por ahora solo existe la 1
+fn migrate(value: Value) -> Result<Vec<Collection>, Box<dyn Error>> {
+    let version = value.get("version").and_then(Value::as_u64).unwrap_or(0) as u32;
+    if !(1..=CURRENT_VERSION).contains(&version) {
+        return Err(Box::new(CollectionError::UnsupportedVersion(version)));
+    }
+    let file: CollectionsFile = serde_json::from_value(value)?;
+    Ok(file.collections)
+}
+
+fn read_file(path: &Path) -> Result<Option<Vec<Collection>>, Box<dyn Error>> {
+    match storage::load_json::<Value>(path)? {
+        Some(value) => Ok(Some(migrate(value)?)),
+        None => Ok(None),
+    }
+}
+
+fn write_file(path: &Path, collections: &[Collection]) -> Result<(), Box<dyn Error>> {
+    let file = CollectionsFile {
+        version: CURRENT_VERSION,
+        collections: collections.to_vec(),
+    };
+    storage::save_json(path, &file)
+}
+
+// Todas las colecciones del usuario, guardadas en la carpeta de datos
+#[derive(Debug, Default)]
+pub struct CollectionStore {
+    collections: Vec<Collection>,
+    path: Option<PathBuf>,
+}
+
+impl CollectionStore {
+    pub fn path() -> Option<PathBuf> {
+        storage::data_file(COLLECTIONS_FILE)
+    }
+
+    // Si el archivo no existe se empieza sin colecciones; si está dañado se avisa y no se sobrescribe
+    pub fn load() -> Self {
+        let Some(path) = Self::path() else {
+            return Self::default();
+        };
+        match read_file(&path) {
+            Ok(collections) => Self {
+                collections: collections.unwrap_or_default(),
+                path: Some(path),
+            },
+            Err(e) => {
+                eprintln!("Error al leer {}: {}", path.display(), e);
+                Self::default()
+            }
+        }
+    }
+
+    pub fn save(&self) {
+        let Some(path) = &self.path else {
+            return;
+        };
+        if let Err(e) = write_file(path, &self.collections) {
+            eprintln!("No se pudo guardar {}: {}", path.display(), e);
+        }
+    }
+
+    pub fn all(&self) -> &[Collection] {
+        &self.collections
+    }
+
+    pub fn get(&self, index: usize) -> Option<&Collection> {
+        self.collections.get(index)
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut Collection> {
+        self.collections.get_mut(index)
+    }
+
+    // Copia de la colección como equipo, si lo es
+    pub fn team(&self, index: usize) -> Option<Team> {
+        self.collections.get(index).cloned().and_then(|c| Team::try_from(c).ok())
+    }
+
+    // Índices de las colecciones de un tipo, en orden
+    pub fn indices_of(&self, kind: CollectionKind) -> Vec<usize> {
+        (0..self.collections.len())
+            .filter(|&i| self.collections[i].kind == kind)
+            .collect()
+    }
+
+    pub fn create(&mut self, name: &str, kind: CollectionKind) -> usize {
+        let name = self.unique_name(name);
+        self.collections.push(Collection::new(name, kind));
+        self.collections.len() - 1
+    }
+
+    pub fn rename(&mut self, index: usize, name: &str) {
+        let name = name.trim();
+        if name.is_empty() || self.collections.get(index).is_some_and(|c| c.name == name) {
+            return;
+        }
+        let name = self.unique_name(name);
+        if let Some(collection) = self.collections.get_mut(index) {
+            collection.name = name;
+        }
+    }
+
+    pub fn duplicate(&mut self, index: usize) -> Option<usize> {
+        let mut copy = self.collections.get(index)?.clone();
+        copy.name = self.unique_name(&copy.name);
+        self.collections.insert(index + 1, copy);
+        Some(index + 1)
+    }
+
+    pub fn delete(&mut self, index: usize) -> Option<Collection> {
+        (index < self.collections.len()).then(|| self.collections.remove(index))
+    }
+
+    // Evita nombres repetidos añadiendo " (2)", " (3)"...
+    fn unique_name(&self, name: &str) -> String {
+        let taken = |candidate: &str| self.collections.iter().any(|c| c.name == candidate);
+        if !taken(name) {
+            return name.to_string();
+        }
+        (2..)
+            .map(|n| format!("{} ({})", name, n))
+            .find(|candidate| !taken(candidate))
+            .unwrap_or_else(|| name.to_string())
+    }
+
+    // Exporta una colección a un archivo independiente con el mismo formato versionado
+    pub fn export(&self, index: usize, path: &Path) -> Result<(), Box<dyn Error>> {
+        let collection = self.collections.get(index).ok_or("colección inexistente")?;
+        write_file(path, std::slice::from_ref(collection))
+    }
+
+    // Añade las colecciones de un archivo exportado; devuelve cuántas se importaron
+    pub fn import(&mut self, path: &Path) -> Result<usize, Box<dyn Error>> {
+        let imported = read_file(path)?.ok_or_else(|| format!("no existe {}", path.display()))?;
+        let count = imported.len();
+        for mut collection in imported {
+            collection.name = self.unique_name(&collection.name);
+            self.collections.push(collection);
+        }
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VERSION_1: &str = r#"{
+        "version": 1,
+        "collections": [
+            { "name": "Principal", "kind": "Team", "members": [{ "id": 6, "form": "Mega Charizard X" }, { "id": 25 }] },
+            { "name": "Caja 1", "kind": "Box" }
+        ]
+    }"#;
+
+    #[test]
+    fn reads_version_1() {
+        let collections = migrate(serde_json::from_str(VERSION_1).unwrap()).expect("versión 1");
+        assert_eq!(collections.len(), 2);
+        assert_eq!(collections[0].kind, CollectionKind::Team);
+        assert_eq!(collections[0].members[0].form.as_deref(), Some("Mega Charizard X"));
+        assert_eq!(collections[0].members[1].set, Default::default());
+        assert_eq!(collections[1].kind, CollectionKind::Box);
+        assert!(collections[1].members.is_empty());
+    }
+
+    #[test]
+    fn rejects_unknown_versions() {
+        for text in [r#"{ "version": 2, "collections": [] }"#, r#"{ "collections": [] }"#, "[]"] {
+            let error = migrate(serde_json::from_str(text).unwrap()).unwrap_err();
+            assert!(error.downcast_ref::<CollectionError>().is_some(), "{}", text);
+        }
+    }
+
+    #[test]
+    fn written_files_read_back() {
+        let mut team = Collection::new("Equipo", CollectionKind::Team);
+        team.members.push(TeamMember { id: 150, form: None, set: Default::default() });
+        let file = CollectionsFile { version: CURRENT_VERSION, collections: vec![team.clone()] };
+        let value = serde_json::to_value(&file).unwrap();
+        assert_eq!(migrate(value).expect("archivo actual"), vec![team]);
+    }
+
+}
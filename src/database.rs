@@ -1,16 +1,39 @@
+use std::collections::BTreeSet;
 use std::error::Error;
-use crate::pokemon::{Pokemon, PokemonType, Stats};
-use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+use crate::analytics::StatRankings;
+use crate::i18n::LocalizedNames;
+use crate::pokemon::{Pokemon, PokemonType, StatKind, Stats};
+use crate::showdown;
 
+#[derive(Clone, Default)]
 pub struct Database {
-    pokemon_map: HashMap<String, Pokemon>,
+    // Todas las entradas (especies y formas) en el orden del CSV
+    pokemon: Vec<Pokemon>,
+    // Pares (especie, especie a la que evoluciona) ordenados por la primera
+    evolutions: Vec<(u16, u16)>,
+    names: LocalizedNames,
+    rankings: StatRankings,
 }
 
 impl Database {
     pub fn new() -> Self {
-        Self {
-            pokemon_map: HashMap::new(),
-        }
+        Self::default()
+    }
+
+    // Carga nombres de especies y tipos en otros idiomas
+    pub fn load_localized_names(&mut self, path: &Path) -> Result<(), Box<dyn Error>> {
+        self.names = LocalizedNames::load(path)?;
+        Ok(())
+    }
+
+    pub fn names(&self) -> &LocalizedNames {
+        &self.names
+    }
+
+    pub fn rankings(&self) -> &StatRankings {
+        &self.rankings
     }
 
     pub fn load_from_csv(&mut self) -> Result<(), Box<dyn Error>> {
@@ -25,69 +48,268 @@ impl Database {
             
             // Parsear los tipos
             let mut types = Vec::new();
-            if let Some(type1) = self.parse_type(&record[2]) {
+            if let Some(type1) = PokemonType::from_name(&record[2]) {
                 types.push(type1);
             }
-            if record.get(3).map_or(false, |t| !t.is_empty()) {
-                if let Some(type2) = self.parse_type(&record[3]) {
+            if record.get(3).is_some_and(|t| !t.is_empty()) {
+                if let Some(type2) = PokemonType::from_name(&record[3]) {
                     types.push(type2);
                 }
             }
 
+            // Columnas: #,Name,Type 1,Type 2,Total,HP,Attack,Defense,Sp. Atk,Sp. Def,Speed,Generation,Legendary
+            let (name, form) = split_form(&record[1]);
             let pokemon = Pokemon {
                 id: record[0].parse()?,
-                name: record[1].to_string(),
+                name,
+                form,
                 types,
-                height: record[4].parse()?,
-                weight: record[5].parse()?,
+                height: None,
+                weight: None,
                 stats: Stats {
-                    hp: record[6].parse()?,
-                    attack: record[7].parse()?,
-                    defense: record[8].parse()?,
-                    special_attack: record[9].parse()?,
-                    special_defense: record[10].parse()?,
-                    speed: record[11].parse()?,
+                    hp: record[5].parse()?,
+                    attack: record[6].parse()?,
+                    defense: record[7].parse()?,
+                    special_attack: record[8].parse()?,
+                    special_defense: record[9].parse()?,
+                    speed: record[10].parse()?,
                 },
+                generation: record[11].parse()?,
+                legendary: record[12].eq_ignore_ascii_case("true"),
             };
 
-            self.pokemon_map.insert(pokemon.name.to_lowercase(), pokemon);
+            self.pokemon.push(pokemon);
+        }
+
+        // Ordenar por número manteniendo las formas detrás de su especie
+        self.pokemon.sort_by_key(|p| p.id);
+
+        // Evoluciones entre especies (columnas: From,To)
+        let evolutions_content = include_str!("../assets/evolutions.csv");
+        let mut rdr = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_reader(evolutions_content.as_bytes());
+        for result in rdr.records() {
+            let record = result?;
+            self.evolutions.push((record[0].parse()?, record[1].parse()?));
         }
+        self.evolutions.sort_unstable();
 
-        println!("Cargados {} Pokémon desde CSV", self.pokemon_map.len());
+        // Rankings de cada estadística precalculados para el panel de detalles
+        self.rankings = StatRankings::build(&self.get_all_pokemon());
         Ok(())
     }
 
-    fn parse_type(&self, type_str: &str) -> Option<PokemonType> {
-        match type_str.trim().to_lowercase().as_str() {
-            "normal" => Some(PokemonType::Normal),
-            "fire" => Some(PokemonType::Fire),
-            "water" => Some(PokemonType::Water),
-            "electric" => Some(PokemonType::Electric),
-            "grass" => Some(PokemonType::Grass),
-            "ice" => Some(PokemonType::Ice),
-            "fighting" => Some(PokemonType::Fighting),
-            "poison" => Some(PokemonType::Poison),
-            "ground" => Some(PokemonType::Ground),
-            "flying" => Some(PokemonType::Flying),
-            "psychic" => Some(PokemonType::Psychic),
-            "bug" => Some(PokemonType::Bug),
-            "rock" => Some(PokemonType::Rock),
-            "ghost" => Some(PokemonType::Ghost),
-            "dragon" => Some(PokemonType::Dragon),
-            "dark" => Some(PokemonType::Dark),
-            "steel" => Some(PokemonType::Steel),
-            "fairy" => Some(PokemonType::Fairy),
-            _ => None,
+    // Devuelve la forma base (la primera del CSV) de la especie
+    pub fn get_pokemon(&self, id: u16) -> Option<&Pokemon> {
+        self.pokemon.iter().find(|p| p.id == id)
+    }
+
+    pub fn get_pokemon_form(&self, id: u16, form: Option<&str>) -> Option<&Pokemon> {
+        match form {
+            Some(form) => self.get_forms(id).into_iter().find(|p| p.form.as_deref() == Some(form)),
+            None => self.get_pokemon(id),
         }
     }
 
-    pub fn get_pokemon(&self, id: u16) -> Option<&Pokemon> {
-        self.pokemon_map.values().find(|p| p.id == id)
+    // Todas las formas de una especie, empezando por la base
+    pub fn get_forms(&self, id: u16) -> Vec<&Pokemon> {
+        self.pokemon.iter().filter(|p| p.id == id).collect()
+    }
+
+    // Especie de la que evoluciona (su forma base), si la tiene
+    pub fn pre_evolution(&self, id: u16) -> Option<&Pokemon> {
+        let &(from, _) = self.evolutions.iter().find(|&&(_, to)| to == id)?;
+        self.get_pokemon(from)
+    }
+
+    // Especies a las que evoluciona directamente
+    pub fn evolutions(&self, id: u16) -> Vec<&Pokemon> {
+        self.evolutions
+            .iter()
+            .filter(|&&(from, _)| from == id)
+            .filter_map(|&(_, to)| self.get_pokemon(to))
+            .collect()
+    }
+
+    // Familia evolutiva completa por etapas, empezando por la especie sin preevolución
+    pub fn evolution_family(&self, id: u16) -> Vec<&Pokemon> {
+        let mut root = id;
+        while let Some(previous) = self.pre_evolution(root) {
+            root = previous.id;
+        }
+        let mut family: Vec<&Pokemon> = self.get_pokemon(root).into_iter().collect();
+        let mut next = 0;
+        while next < family.len() {
+            let stage = self.evolutions(family[next].id);
+            family.extend(stage);
+            next += 1;
+        }
+        family
+    }
+
+    // Busca por número ("25", "#025"), por nombre con o sin forma o por el nombre en otro idioma
+    pub fn find(&self, query: &str) -> Option<&Pokemon> {
+        let query = query.trim();
+        if let Ok(id) = query.trim_start_matches('#').parse::<u16>() {
+            return self.get_pokemon(id);
+        }
+        showdown::find_species(self, query).or_else(|| {
+            let query = query.to_lowercase();
+            self.pokemon
+                .iter()
+                .find(|p| self.names.all_species_names(p.id).any(|name| name.to_lowercase() == query))
+        })
+    }
+
+    // Comprobaciones de coherencia de los datos cargados
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        let mut seen = BTreeSet::new();
+        for pokemon in &self.pokemon {
+            let entry = pokemon.display_name();
+            if !seen.insert((pokemon.id, pokemon.form.clone())) {
+                issues.push(ValidationIssue::Duplicate { id: pokemon.id, entry: entry.clone() });
+            }
+            let mut types = pokemon.types.clone();
+            types.dedup();
+            if pokemon.types.is_empty() || pokemon.types.len() > 2 || types.len() != pokemon.types.len() {
+                issues.push(ValidationIssue::InvalidTypes { id: pokemon.id, entry: entry.clone() });
+            }
+            for kind in StatKind::ALL {
+                if pokemon.stats.get(kind) == 0 {
+                    issues.push(ValidationIssue::ZeroStat { id: pokemon.id, entry: entry.clone(), stat: kind });
+                }
+            }
+            if pokemon.generation == 0 {
+                issues.push(ValidationIssue::InvalidGeneration { id: pokemon.id, entry });
+            }
+        }
+        // Números de la Pokédex que faltan entre el 1 y el más alto
+        let ids: BTreeSet<u16> = self.pokemon.iter().map(|p| p.id).collect();
+        let max = ids.last().copied().unwrap_or(0);
+        issues.extend((1..=max).filter(|id| !ids.contains(id)).map(ValidationIssue::MissingSpecies));
+        // Evoluciones hacia o desde especies que no existen, o con dos preevoluciones
+        let mut evolved = BTreeSet::new();
+        for &(from, to) in &self.evolutions {
+            if !ids.contains(&from) || !ids.contains(&to) || !evolved.insert(to) {
+                issues.push(ValidationIssue::InvalidEvolution { from, to });
+            }
+        }
+        issues
     }
 
     pub fn get_all_pokemon(&self) -> Vec<&Pokemon> {
-        let mut pokemons = self.pokemon_map.values().collect::<Vec<_>>();
-        pokemons.sort_by_key(|p| p.id);
-        pokemons
+        self.pokemon.iter().collect()
+    }
+
+    // Generaciones presentes en los datos, ordenadas
+    pub fn generations(&self) -> Vec<u8> {
+        let mut generations: Vec<u8> = self.pokemon.iter().map(|p| p.generation).collect();
+        generations.sort_unstable();
+        generations.dedup();
+        generations
+    }
+
+    // Todas las entradas (incluidas las formas) que cumplen el filtro
+    pub fn filter_entries(&self, filter: &PokemonFilter) -> Vec<&Pokemon> {
+        self.pokemon
+            .iter()
+            .filter(|p| filter.matches(p, &self.names))
+            .collect()
+    }
+
+    // Una fila por especie: la primera de sus formas que cumple el filtro (la base si la cumple)
+    pub fn query(&self, filter: &PokemonFilter) -> Vec<&Pokemon> {
+        one_per_species(self.filter_entries(filter))
+    }
+}
+
+// Se queda con la primera entrada de cada especie; las formas van seguidas en el CSV
+pub fn one_per_species<'a>(entries: impl IntoIterator<Item = &'a Pokemon>) -> Vec<&'a Pokemon> {
+    let mut results: Vec<&Pokemon> = Vec::new();
+    for pokemon in entries {
+        if results.last().is_some_and(|last| last.id == pokemon.id) {
+            continue;
+        }
+        results.push(pokemon);
+    }
+    results
+}
+
+// Filtros compartidos por la lista, el panel de estadísticas y el resto de vistas
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PokemonFilter {
+    pub search: String,
+    pub pokemon_type: Option<PokemonType>,
+    // Junto con pokemon_type: solo los Pokémon que tienen los dos tipos (en cualquier orden)
+    pub second_type: Option<PokemonType>,
+    pub generation: Option<u8>,
+    // Solo estas especies (filtro "mostrar solo la colección X")
+    pub species: Option<BTreeSet<u16>>,
+}
+
+impl PokemonFilter {
+    // El texto se busca en el nombre (con la forma) y en los nombres de cualquier idioma cargado
+    pub fn matches(&self, pokemon: &Pokemon, names: &LocalizedNames) -> bool {
+        if self.pokemon_type.is_some_and(|t| !pokemon.types.contains(&t)) {
+            return false;
+        }
+        if self.second_type.is_some_and(|t| !pokemon.types.contains(&t)) {
+            return false;
+        }
+        if self.generation.is_some_and(|g| pokemon.generation != g) {
+            return false;
+        }
+        if self.species.as_ref().is_some_and(|ids| !ids.contains(&pokemon.id)) {
+            return false;
+        }
+
+        let term = self.search.trim().to_lowercase();
+        term.is_empty()
+            || pokemon.display_name().to_lowercase().contains(&term)
+            || names
+                .all_species_names(pokemon.id)
+                .any(|name| name.to_lowercase().contains(&term))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationIssue {
+    Duplicate { id: u16, entry: String },
+    InvalidTypes { id: u16, entry: String },
+    ZeroStat { id: u16, entry: String, stat: StatKind },
+    InvalidGeneration { id: u16, entry: String },
+    MissingSpecies(u16),
+    InvalidEvolution { from: u16, to: u16 },
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ValidationIssue::Duplicate { id, entry } => write!(f, "#{:03} {}: entrada duplicada", id, entry),
+            ValidationIssue::InvalidTypes { id, entry } => {
+                write!(f, "#{:03} {}: debe tener uno o dos tipos distintos", id, entry)
+            }
+            ValidationIssue::ZeroStat { id, entry, stat } => write!(f, "#{:03} {}: {:?} es 0", id, entry, stat),
+            ValidationIssue::InvalidGeneration { id, entry } => write!(f, "#{:03} {}: generación 0", id, entry),
+            ValidationIssue::MissingSpecies(id) => write!(f, "#{:03}: falta la especie", id),
+            ValidationIssue::InvalidEvolution { from, to } => {
+                write!(f, "#{:03} → #{:03}: evolución no válida", from, to)
+            }
+        }
+    }
+}
+
+// El CSV junta especie y forma en el nombre: "VenusaurMega Venusaur" -> ("Venusaur", "Mega Venusaur")
+fn split_form(raw: &str) -> (String, Option<String>) {
+    let chars: Vec<char> = raw.chars().collect();
+    for i in 1..chars.len() {
+        if chars[i - 1].is_lowercase() && chars[i].is_uppercase() {
+            let name = chars[..i].iter().collect();
+            let form = chars[i..].iter().collect();
+            return (name, Some(form));
+        }
     }
+    (raw.to_string(), None)
 }
\ No newline at end of file
@@ -0,0 +1,259 @@
+use crate::pokemon::{Pokemon, StatKind};
+use serde::ser::{SerializeMap, Serializer};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::error::Error;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum ExportFormat {
+    Csv,
+    Json,
+    #[cfg_attr(feature = "cli", value(name = "jsonl"))]
+    JsonLines,
+    #[cfg_attr(feature = "cli", value(name = "md"))]
+    Markdown,
+    Html,
+    Yaml,
+}
+
+impl ExportFormat {
+    pub const ALL: [ExportFormat; 6] = [
+        ExportFormat::Csv,
+        ExportFormat::Json,
+        ExportFormat::JsonLines,
+        ExportFormat::Markdown,
+        ExportFormat::Html,
+        ExportFormat::Yaml,
+    ];
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+            ExportFormat::JsonLines => "jsonl",
+            ExportFormat::Markdown => "md",
+            ExportFormat::Html => "html",
+            ExportFormat::Yaml => "yaml",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "CSV",
+            ExportFormat::Json => "JSON",
+            ExportFormat::JsonLines => "JSON Lines",
+            ExportFormat::Markdown => "Markdown",
+            ExportFormat::Html => "HTML",
+            ExportFormat::Yaml => "YAML",
+        }
+    }
+
+    // Para deducir el formato a partir del nombre del archivo
+    pub fn from_path(path: &Path) -> Option<ExportFormat> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "yml" => Some(ExportFormat::Yaml),
+            "markdown" => Some(ExportFormat::Markdown),
+            "htm" => Some(ExportFormat::Html),
+            "ndjson" => Some(ExportFormat::JsonLines),
+            _ => ExportFormat::ALL.into_iter().find(|format| format.extension() == extension),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum Column {
+    Id,
+    Name,
+    Form,
+    Types,
+    Hp,
+    Attack,
+    Defense,
+    #[cfg_attr(feature = "cli", value(name = "sp-atk"))]
+    SpecialAttack,
+    #[cfg_attr(feature = "cli", value(name = "sp-def"))]
+    SpecialDefense,
+    Speed,
+    Total,
+    Generation,
+    Legendary,
+}
+
+impl Column {
+    pub const ALL: [Column; 13] = [
+        Column::Id,
+        Column::Name,
+        Column::Form,
+        Column::Types,
+        Column::Hp,
+        Column::Attack,
+        Column::Defense,
+        Column::SpecialAttack,
+        Column::SpecialDefense,
+        Column::Speed,
+        Column::Total,
+        Column::Generation,
+        Column::Legendary,
+    ];
+
+    // Nombre de la columna en la cabecera y en las claves de JSON/YAML
+    pub fn header(&self) -> &'static str {
+        match self {
+            Column::Id => "id",
+            Column::Name => "name",
+            Column::Form => "form",
+            Column::Types => "types",
+            Column::Hp => "hp",
+            Column::Attack => "attack",
+            Column::Defense => "defense",
+            Column::SpecialAttack => "special_attack",
+            Column::SpecialDefense => "special_defense",
+            Column::Speed => "speed",
+            Column::Total => "total",
+            Column::Generation => "generation",
+            Column::Legendary => "legendary",
+        }
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        self.stat().is_some() || matches!(self, Column::Id | Column::Total | Column::Generation)
+    }
+
+    fn stat(&self) -> Option<StatKind> {
+        match self {
+            Column::Hp => Some(StatKind::Hp),
+            Column::Attack => Some(StatKind::Attack),
+            Column::Defense => Some(StatKind::Defense),
+            Column::SpecialAttack => Some(StatKind::SpecialAttack),
+            Column::SpecialDefense => Some(StatKind::SpecialDefense),
+            Column::Speed => Some(StatKind::Speed),
+            _ => None,
+        }
+    }
+
+    // Valor con tipo para JSON y YAML (los tipos como lista, la forma base como null)
+    pub fn value(&self, pokemon: &Pokemon) -> Value {
+        if let Some(kind) = self.stat() {
+            return Value::from(pokemon.stats.get(kind));
+        }
+        match self {
+            Column::Id => Value::from(pokemon.id),
+            Column::Name => Value::from(pokemon.name.as_str()),
+            Column::Form => pokemon.form.as_deref().map_or(Value::Null, Value::from),
+            Column::Types => pokemon.types.iter().map(|t| Value::from(t.to_string())).collect(),
+            Column::Total => Value::from(pokemon.total_stats()),
+            Column::Generation => Value::from(pokemon.generation),
+            Column::Legendary => Value::from(pokemon.legendary),
+            _ => Value::Null,
+        }
+    }
+
+    // Valor como texto para las tablas (CSV, Markdown, HTML)
+    pub fn text(&self, pokemon: &Pokemon) -> String {
+        match self {
+            Column::Form => pokemon.form.clone().unwrap_or_default(),
+            Column::Types => pokemon.types.iter().map(ToString::to_string).collect::<Vec<_>>().join("/"),
+            _ => match self.value(pokemon) {
+                Value::String(text) => text,
+                other => other.to_string(),
+            },
+        }
+    }
+}
+
+// Una fila con las columnas elegidas, en su orden
+struct Row<'a> {
+    pokemon: &'a Pokemon,
+    columns: &'a [Column],
+}
+
+impl Serialize for Row<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.columns.len()))?;
+        for column in self.columns {
+            map.serialize_entry(column.header(), &column.value(self.pokemon))?;
+        }
+        map.end()
+    }
+}
+
+pub fn export(pokemons: &[&Pokemon], columns: &[Column], format: ExportFormat) -> Result<String, Box<dyn Error>> {
+    let columns = if columns.is_empty() { &Column::ALL[..] } else { columns };
+    let rows: Vec<Row> = pokemons.iter().map(|&pokemon| Row { pokemon, columns }).collect();
+    let text = match format {
+        ExportFormat::Csv => {
+            let mut writer = csv::Writer::from_writer(Vec::new());
+            writer.write_record(columns.iter().map(Column::header))?;
+            for pokemon in pokemons {
+                writer.write_record(columns.iter().map(|column| column.text(pokemon)))?;
+            }
+            String::from_utf8(writer.into_inner()?)?
+        }
+        ExportFormat::Json => serde_json::to_string_pretty(&rows)? + "\n",
+        ExportFormat::JsonLines => {
+            let mut text = String::new();
+            for row in &rows {
+                text += &serde_json::to_string(row)?;
+                text.push('\n');
+            }
+            text
+        }
+        ExportFormat::Markdown => markdown(pokemons, columns),
+        ExportFormat::Html => html(pokemons, columns),
+        ExportFormat::Yaml => serde_yaml::to_string(&rows)?,
+    };
+    Ok(text)
+}
+
+pub fn export_to_file(path: &Path, pokemons: &[&Pokemon], columns: &[Column], format: ExportFormat) -> Result<(), Box<dyn Error>> {
+    fs::write(path, export(pokemons, columns, format)?)?;
+    Ok(())
+}
+
+fn markdown(pokemons: &[&Pokemon], columns: &[Column]) -> String {
+    let cell = |text: String| text.replace('|', "\\|");
+    let mut text = String::new();
+    let headers: Vec<&str> = columns.iter().map(Column::header).collect();
+    let _ = writeln!(text, "| {} |", headers.join(" | "));
+    let separators: Vec<&str> = columns
+        .iter()
+        .map(|column| if column.is_numeric() { "---:" } else { "---" })
+        .collect();
+    let _ = writeln!(text, "| {} |", separators.join(" | "));
+    for pokemon in pokemons {
+        let cells: Vec<String> = columns.iter().map(|column| cell(column.text(pokemon))).collect();
+        let _ = writeln!(text, "| {} |", cells.join(" | "));
+    }
+    text
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+// Documento completo, listo para abrir en el navegador
+fn html(pokemons: &[&Pokemon], columns: &[Column]) -> String {
+    let mut text = String::from(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Pokédex</title>\n</head>\n<body>\n<table>\n",
+    );
+    text += "<thead>\n<tr>";
+    for column in columns {
+        let _ = write!(text, "<th>{}</th>", column.header());
+    }
+    text += "</tr>\n</thead>\n<tbody>\n";
+    for pokemon in pokemons {
+        text += "<tr>";
+        for column in columns {
+            let _ = write!(text, "<td>{}</td>", escape_html(&column.text(pokemon)));
+        }
+        text += "</tr>\n";
+    }
+    text += "</tbody>\n</table>\n</body>\n</html>\n";
+    text
+}
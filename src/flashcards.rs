@@ -0,0 +1,264 @@
+use crate::i18n::{I18n, LocalizedNames};
+use crate::pokemon::{Pokemon, PokemonType, StatKind};
+use crate::sprites;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Qué va en cada cara de la tarjeta
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum CardTemplate {
+    NameTypes,
+    NameStats,
+    NameWeaknesses,
+    SilhouetteName,
+}
+
+impl CardTemplate {
+    pub const ALL: [CardTemplate; 4] = [
+        CardTemplate::NameTypes,
+        CardTemplate::NameStats,
+        CardTemplate::NameWeaknesses,
+        CardTemplate::SilhouetteName,
+    ];
+
+    // Clave del texto traducido en los catálogos de i18n
+    pub fn key(&self) -> &'static str {
+        match self {
+            CardTemplate::NameTypes => "deck.template.name_types",
+            CardTemplate::NameStats => "deck.template.name_stats",
+            CardTemplate::NameWeaknesses => "deck.template.name_weaknesses",
+            CardTemplate::SilhouetteName => "deck.template.silhouette_name",
+        }
+    }
+
+    // Pregunta en el anverso; también evita que Anki tome como duplicadas dos tarjetas del mismo Pokémon
+    fn question_key(&self) -> &'static str {
+        match self {
+            CardTemplate::NameTypes => "deck.question.types",
+            CardTemplate::NameStats => "deck.question.stats",
+            CardTemplate::NameWeaknesses => "deck.question.weaknesses",
+            CardTemplate::SilhouetteName => "deck.question.silhouette",
+        }
+    }
+
+    fn tag(&self) -> &'static str {
+        match self {
+            CardTemplate::NameTypes => "tipos",
+            CardTemplate::NameStats => "estadisticas",
+            CardTemplate::NameWeaknesses => "debilidades",
+            CardTemplate::SilhouetteName => "silueta",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum DeckFormat {
+    Tsv,
+    Csv,
+}
+
+impl DeckFormat {
+    pub const ALL: [DeckFormat; 2] = [DeckFormat::Tsv, DeckFormat::Csv];
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            DeckFormat::Tsv => "tsv",
+            DeckFormat::Csv => "csv",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            DeckFormat::Tsv => "TSV",
+            DeckFormat::Csv => "CSV",
+        }
+    }
+
+    fn delimiter(&self) -> u8 {
+        match self {
+            DeckFormat::Tsv => b'\t',
+            DeckFormat::Csv => b',',
+        }
+    }
+
+    // Nombre del separador en la cabecera que entiende Anki
+    fn anki_separator(&self) -> &'static str {
+        match self {
+            DeckFormat::Tsv => "tab",
+            DeckFormat::Csv => "comma",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Flashcard {
+    pub front: String,
+    pub back: String,
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DeckSummary {
+    pub cards: usize,
+    // Sprites copiados para las siluetas (hay que llevarlos a collection.media)
+    pub media: usize,
+    pub media_dir: Option<PathBuf>,
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+// Nombre del archivo en la carpeta de medios de Anki, con prefijo para no chocar con otros mazos
+fn media_name(pokemon: &Pokemon, sprite: &Path) -> String {
+    let extension = sprite.extension().and_then(|e| e.to_str()).unwrap_or("png");
+    format!("pokedex-{}.{}", sprites::sprite_stem(pokemon), extension)
+}
+
+fn type_badges(types: &[PokemonType], names: &LocalizedNames, i18n: &I18n) -> String {
+    types
+        .iter()
+        .map(|&t| {
+            let (r, g, b) = t.rgb();
+            format!(
+                "<span style=\"background:rgb({},{},{});color:white;font-weight:bold;border-radius:10px;padding:2px 8px;margin:2px\">{}</span>",
+                r, g, b, escape(&i18n.type_name(t, names))
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn stats_table(pokemon: &Pokemon, i18n: &I18n) -> String {
+    let mut rows: Vec<String> = StatKind::ALL
+        .iter()
+        .map(|&kind| format!("<tr><td>{}</td><td align=\"right\">{}</td></tr>", escape(i18n.stat_name(kind)), pokemon.stats.get(kind)))
+        .collect();
+    rows.push(format!(
+        "<tr><td><b>{}</b></td><td align=\"right\"><b>{}</b></td></tr>",
+        escape(i18n.tr("stat.total")),
+        pokemon.total_stats()
+    ));
+    format!("<table>{}</table>", rows.concat())
+}
+
+fn weaknesses(pokemon: &Pokemon, names: &LocalizedNames, i18n: &I18n) -> String {
+    let mut lines = Vec::new();
+    for (multiplier, label) in [(4.0, "×4"), (2.0, "×2"), (0.0, "×0")] {
+        let types: Vec<PokemonType> = PokemonType::ALL
+            .into_iter()
+            .filter(|t| t.effectiveness_against(&pokemon.types) == multiplier)
+            .collect();
+        if !types.is_empty() {
+            lines.push(format!("<b>{}</b> {}", label, type_badges(&types, names, i18n)));
+        }
+    }
+    lines.join("<br>")
+}
+
+// Las tarjetas de un Pokémon con una plantilla; `sprite` es el nombre del archivo en los medios de Anki
+pub fn flashcard(
+    pokemon: &Pokemon,
+    template: CardTemplate,
+    sprite: Option<&str>,
+    names: &LocalizedNames,
+    i18n: &I18n,
+) -> Flashcard {
+    let name = escape(&i18n.pokemon_name(pokemon, names));
+    let title = format!("<div style=\"font-size:28px\"><b>{}</b></div><div>#{:03}</div>", name, pokemon.id);
+    let (front, back) = match template {
+        CardTemplate::NameTypes => (title, type_badges(&pokemon.types, names, i18n)),
+        CardTemplate::NameStats => (title, stats_table(pokemon, i18n)),
+        CardTemplate::NameWeaknesses => (title, weaknesses(pokemon, names, i18n)),
+        CardTemplate::SilhouetteName => match sprite {
+            // La silueta es el propio sprite en negro
+            Some(file) => (
+                format!("<img src=\"{}\" style=\"filter:brightness(0);image-rendering:pixelated\" width=\"192\">", escape(file)),
+                format!("<img src=\"{}\" style=\"image-rendering:pixelated\" width=\"192\">{}", escape(file), title),
+            ),
+            // Sin sprite solo queda el número como pista
+            None => (format!("<div style=\"font-size:28px\">#{:03} ?</div>", pokemon.id), title),
+        },
+    };
+    let question = format!("<div style=\"color:gray\">{}</div>", escape(i18n.tr(template.question_key())));
+    Flashcard {
+        front: front + &question,
+        back,
+        tags: vec![
+            "pokedex".to_string(),
+            format!("gen{}", pokemon.generation),
+            template.tag().to_string(),
+        ],
+    }
+}
+
+// Una tarjeta por Pokémon y plantilla, en el orden de la lista
+pub fn build_deck(
+    pokemons: &[&Pokemon],
+    templates: &[CardTemplate],
+    sprites_dir: Option<&Path>,
+    names: &LocalizedNames,
+    i18n: &I18n,
+) -> Vec<Flashcard> {
+    let mut cards = Vec::new();
+    for pokemon in pokemons {
+        let sprite = sprites_dir
+            .and_then(|dir| sprites::find_sprite(dir, pokemon, false))
+            .map(|path| media_name(pokemon, &path));
+        for &template in templates {
+            cards.push(flashcard(pokemon, template, sprite.as_deref(), names, i18n));
+        }
+    }
+    cards
+}
+
+// Texto importable en Anki: cabeceras "#separator", "#html" y "#tags column" y una fila por tarjeta
+pub fn deck_text(cards: &[Flashcard], format: DeckFormat) -> Result<String, Box<dyn Error>> {
+    let mut writer = csv::WriterBuilder::new()
+        .delimiter(format.delimiter())
+        .from_writer(Vec::new());
+    for card in cards {
+        writer.write_record([card.front.as_str(), card.back.as_str(), &card.tags.join(" ")])?;
+    }
+    let rows = String::from_utf8(writer.into_inner()?)?;
+    Ok(format!(
+        "#separator:{}\n#html:true\n#columns:Front{sep}Back{sep}Tags\n#tags column:3\n{}",
+        format.anki_separator(),
+        rows,
+        sep = format.delimiter() as char
+    ))
+}
+
+// Escribe el mazo y, si alguna plantilla usa el sprite, copia los sprites a "<mazo>_media"
+pub fn write_deck(
+    path: &Path,
+    pokemons: &[&Pokemon],
+    templates: &[CardTemplate],
+    format: DeckFormat,
+    names: &LocalizedNames,
+    i18n: &I18n,
+) -> Result<DeckSummary, Box<dyn Error>> {
+    let sprites_dir = sprites::sprites_dir();
+    let cards = build_deck(pokemons, templates, Some(&sprites_dir), names, i18n);
+    fs::write(path, deck_text(&cards, format)?)?;
+
+    let mut summary = DeckSummary { cards: cards.len(), ..DeckSummary::default() };
+    if templates.contains(&CardTemplate::SilhouetteName) {
+        let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("deck");
+        let media_dir = path.with_file_name(format!("{}_media", stem));
+        for pokemon in pokemons {
+            if let Some(sprite) = sprites::find_sprite(&sprites_dir, pokemon, false) {
+                fs::create_dir_all(&media_dir)?;
+                fs::copy(&sprite, media_dir.join(media_name(pokemon, &sprite)))?;
+                summary.media += 1;
+            }
+        }
+        if summary.media > 0 {
+            summary.media_dir = Some(media_dir);
+        }
+    }
+    Ok(summary)
+}
@@ -0,0 +1,206 @@
+use crate::database::Database;
+use crate::pokemon::{Pokemon, PokemonType};
+use crate::rng::{Date, Rng};
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneratorConstraints {
+    // Generaciones permitidas; vacío = todas
+    pub generations: BTreeSet<u8>,
+    pub exclude_legendaries: bool,
+    pub exclude_megas: bool,
+    // Ningún tipo repetido entre los miembros del equipo
+    pub unique_types: bool,
+    pub min_total: Option<u16>,
+    pub max_total: Option<u16>,
+    // Como en los torneos: una sola forma de cada especie por equipo
+    pub species_clause: bool,
+}
+
+impl Default for GeneratorConstraints {
+    fn default() -> Self {
+        Self {
+            generations: BTreeSet::new(),
+            exclude_legendaries: false,
+            exclude_megas: false,
+            unique_types: false,
+            min_total: None,
+            max_total: None,
+            species_clause: true,
+        }
+    }
+}
+
+impl GeneratorConstraints {
+    // Restricciones que se pueden comprobar con un solo Pokémon
+    pub fn allows(&self, pokemon: &Pokemon) -> bool {
+        let total = pokemon.total_stats();
+        (self.generations.is_empty() || self.generations.contains(&pokemon.generation))
+            && !(self.exclude_legendaries && pokemon.legendary)
+            && !(self.exclude_megas && is_mega(pokemon))
+            && self.min_total.is_none_or(|min| total >= min)
+            && self.max_total.is_none_or(|max| total <= max)
+    }
+}
+
+// Megaevoluciones y regresiones primigenias (formas de combate temporales)
+pub fn is_mega(pokemon: &Pokemon) -> bool {
+    pokemon
+        .form
+        .as_deref()
+        .is_some_and(|form| form.starts_with("Mega ") || form.starts_with("Primal "))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GeneratorError {
+    // Con estas restricciones no hay suficientes Pokémon distintos
+    NotEnoughCandidates { found: usize, wanted: usize },
+}
+
+impl fmt::Display for GeneratorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GeneratorError::NotEnoughCandidates { found, wanted } => write!(
+                f,
+                "solo se encontraron {} de {} Pokémon que cumplan las restricciones",
+                found, wanted
+            ),
+        }
+    }
+}
+
+impl Error for GeneratorError {}
+
+// Todas las entradas (incluidas las formas) que cumplen las restricciones individuales
+pub fn candidates<'a>(database: &'a Database, constraints: &GeneratorConstraints) -> Vec<&'a Pokemon> {
+    database
+        .get_all_pokemon()
+        .into_iter()
+        .filter(|p| constraints.allows(p))
+        .collect()
+}
+
+pub fn random_pokemon<'a>(
+    database: &'a Database,
+    constraints: &GeneratorConstraints,
+    rng: &mut Rng,
+) -> Option<&'a Pokemon> {
+    rng.choose(&candidates(database, constraints)).copied()
+}
+
+// Baraja los candidatos y los va tomando si no rompen la cláusula de especie ni la de tipos únicos
+pub fn random_team<'a>(
+    database: &'a Database,
+    constraints: &GeneratorConstraints,
+    size: usize,
+    rng: &mut Rng,
+) -> Result<Vec<&'a Pokemon>, GeneratorError> {
+    let mut pool = candidates(database, constraints);
+    rng.shuffle(&mut pool);
+
+    let mut team: Vec<&Pokemon> = Vec::with_capacity(size);
+    let mut species = BTreeSet::new();
+    let mut types: BTreeSet<PokemonType> = BTreeSet::new();
+    for pokemon in pool {
+        if team.len() == size {
+            break;
+        }
+        if constraints.species_clause && species.contains(&pokemon.id) {
+            continue;
+        }
+        if constraints.unique_types && pokemon.types.iter().any(|t| types.contains(t)) {
+            continue;
+        }
+        species.insert(pokemon.id);
+        types.extend(pokemon.types.iter().copied());
+        team.push(pokemon);
+    }
+
+    if team.len() < size {
+        return Err(GeneratorError::NotEnoughCandidates { found: team.len(), wanted: size });
+    }
+    Ok(team)
+}
+
+// El mismo Pokémon para todos durante un día: la fecha es la semilla
+pub fn pokemon_of_the_day(database: &Database, date: Date) -> Option<&Pokemon> {
+    let constraints = GeneratorConstraints {
+        exclude_megas: true,
+        ..GeneratorConstraints::default()
+    };
+    random_pokemon(database, &constraints, &mut Rng::new(date.seed()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn database() -> Database {
+        let mut database = Database::new();
+        database.load_from_csv().expect("CSV");
+        database
+    }
+
+    fn names(team: &[&Pokemon]) -> Vec<String> {
+        team.iter().map(|p| p.display_name()).collect()
+    }
+
+    #[test]
+    fn same_seed_gives_the_same_team() {
+        let database = database();
+        let constraints = GeneratorConstraints { unique_types: true, ..GeneratorConstraints::default() };
+        let first = random_team(&database, &constraints, 6, &mut Rng::new(1234)).expect("equipo");
+        let second = random_team(&database, &constraints, 6, &mut Rng::new(1234)).expect("equipo");
+        assert_eq!(names(&first), names(&second));
+        let other = random_team(&database, &constraints, 6, &mut Rng::new(4321)).expect("equipo");
+        assert_ne!(names(&first), names(&other));
+    }
+
+    #[test]
+    fn teams_respect_species_clause_and_unique_types() {
+        let database = database();
+        let constraints = GeneratorConstraints {
+            unique_types: true,
+            generations: BTreeSet::from([1, 2]),
+            exclude_legendaries: true,
+            ..GeneratorConstraints::default()
+        };
+        for seed in 0..50 {
+            let team = random_team(&database, &constraints, 6, &mut Rng::new(seed)).expect("equipo");
+            assert_eq!(team.len(), 6);
+            let species: BTreeSet<u16> = team.iter().map(|p| p.id).collect();
+            assert_eq!(species.len(), team.len(), "especie repetida con la semilla {}", seed);
+            let mut types = BTreeSet::new();
+            for pokemon in &team {
+                assert!(constraints.allows(pokemon));
+                for pokemon_type in &pokemon.types {
+                    assert!(types.insert(*pokemon_type), "tipo repetido con la semilla {}", seed);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn impossible_constraints_are_reported() {
+        let database = database();
+        // Hay 18 tipos, así que con tipos únicos no caben 19 Pokémon
+        let constraints = GeneratorConstraints { unique_types: true, ..GeneratorConstraints::default() };
+        let result = random_team(&database, &constraints, 19, &mut Rng::new(0));
+        assert!(matches!(result, Err(GeneratorError::NotEnoughCandidates { wanted: 19, .. })));
+    }
+
+    #[test]
+    fn pokemon_of_the_day_depends_only_on_the_date() {
+        let database = database();
+        let date = Date { year: 2024, month: 3, day: 15 };
+        let pokemon = pokemon_of_the_day(&database, date).expect("Pokémon del día");
+        assert_eq!(pokemon_of_the_day(&database, date).map(|p| p.display_name()), Some(pokemon.display_name()));
+        // Si cambia el generador cambia el Pokémon del día de todos los usuarios
+        assert_eq!(pokemon.display_name(), "Gyarados");
+        assert!(!is_mega(pokemon));
+        let next = Date { day: 16, ..date };
+        assert_ne!(pokemon_of_the_day(&database, next).map(|p| p.display_name()), Some(pokemon.display_name()));
+    }
+}
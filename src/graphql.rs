@@ -0,0 +1,325 @@
+use crate::database::{one_per_species, Database, PokemonFilter};
+use crate::pokemon::{Pokemon, PokemonType, StatKind};
+use async_graphql::{Context, EmptyMutation, EmptySubscription, Enum, InputObject, Object, Request, Schema, Variables};
+use serde_json::Value;
+use std::sync::Arc;
+
+pub const DEFAULT_LIMIT: usize = 50;
+
+pub type PokedexSchema = Schema<QueryRoot, EmptyMutation, EmptySubscription>;
+
+pub fn build_schema(database: Arc<Database>) -> PokedexSchema {
+    Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .data(database)
+        .finish()
+}
+
+// Esquema en SDL, sin necesidad de cargar la base de datos
+pub fn sdl() -> String {
+    Schema::build(QueryRoot, EmptyMutation, EmptySubscription).finish().sdl()
+}
+
+// Ejecuta una consulta de forma síncrona y devuelve la respuesta GraphQL ({"data": ..., "errors": ...})
+pub fn execute(schema: &PokedexSchema, query: &str, variables: Option<Value>, operation_name: Option<String>) -> Value {
+    let mut request = Request::new(query);
+    if let Some(variables) = variables {
+        request = request.variables(Variables::from_json(variables));
+    }
+    if let Some(operation_name) = operation_name {
+        request = request.operation_name(operation_name);
+    }
+    let response = pollster::block_on(schema.execute(request));
+    serde_json::to_value(response).unwrap_or(Value::Null)
+}
+
+fn database<'a>(ctx: &Context<'a>) -> &'a Database {
+    ctx.data_unchecked::<Arc<Database>>()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum)]
+pub enum SortField {
+    Id,
+    Name,
+    Hp,
+    Attack,
+    Defense,
+    SpecialAttack,
+    SpecialDefense,
+    Speed,
+    Total,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct SortInput {
+    pub field: SortField,
+    #[graphql(default)]
+    pub descending: bool,
+}
+
+// Los mismos filtros que la interfaz, más algunos que solo tienen sentido en consultas
+#[derive(Debug, Clone, Default, InputObject)]
+pub struct PokemonFilterInput {
+    pub search: Option<String>,
+    #[graphql(name = "type")]
+    pub pokemon_type: Option<PokemonType>,
+    pub generation: Option<u8>,
+    pub legendary: Option<bool>,
+    pub min_total: Option<u16>,
+    pub max_total: Option<u16>,
+}
+
+impl PokemonFilterInput {
+    fn to_filter(&self) -> PokemonFilter {
+        PokemonFilter {
+            search: self.search.clone().unwrap_or_default(),
+            pokemon_type: self.pokemon_type,
+            generation: self.generation,
+            ..PokemonFilter::default()
+        }
+    }
+
+    fn allows(&self, pokemon: &Pokemon) -> bool {
+        let total = pokemon.total_stats();
+        self.legendary.is_none_or(|legendary| pokemon.legendary == legendary)
+            && self.min_total.is_none_or(|min| total >= min)
+            && self.max_total.is_none_or(|max| total <= max)
+    }
+
+    // Entradas que cumplen todos los filtros; sin `forms`, la primera forma de cada especie que los cumple
+    fn entries<'a>(&self, database: &'a Database, forms: bool) -> Vec<&'a Pokemon> {
+        let matching = database.filter_entries(&self.to_filter()).into_iter().filter(|p| self.allows(p));
+        if forms {
+            matching.collect()
+        } else {
+            one_per_species(matching)
+        }
+    }
+}
+
+fn sort_key(pokemon: &Pokemon, field: SortField) -> (u16, String) {
+    let stat = |kind| pokemon.stats.get(kind) as u16;
+    match field {
+        SortField::Id => (pokemon.id, String::new()),
+        SortField::Name => (0, pokemon.display_name()),
+        SortField::Hp => (stat(StatKind::Hp), String::new()),
+        SortField::Attack => (stat(StatKind::Attack), String::new()),
+        SortField::Defense => (stat(StatKind::Defense), String::new()),
+        SortField::SpecialAttack => (stat(StatKind::SpecialAttack), String::new()),
+        SortField::SpecialDefense => (stat(StatKind::SpecialDefense), String::new()),
+        SortField::Speed => (stat(StatKind::Speed), String::new()),
+        SortField::Total => (pokemon.total_stats(), String::new()),
+    }
+}
+
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    // Por número o por nombre (como `pokedex show`); `form` elige una forma concreta
+    async fn pokemon(&self, ctx: &Context<'_>, id: Option<u16>, name: Option<String>, form: Option<String>) -> Option<PokemonObject> {
+        let database = database(ctx);
+        let found = match (id, name) {
+            (Some(id), _) => database.get_pokemon(id),
+            (None, Some(name)) => database.find(&name),
+            (None, None) => None,
+        }?;
+        let found = match form {
+            Some(form) => database
+                .get_forms(found.id)
+                .into_iter()
+                .find(|p| p.form.as_deref().is_some_and(|f| f.eq_ignore_ascii_case(&form)))?,
+            None => found,
+        };
+        Some(PokemonObject(found.clone()))
+    }
+
+    // Una fila por especie salvo con `forms: true`
+    async fn pokemons(
+        &self,
+        ctx: &Context<'_>,
+        filter: Option<PokemonFilterInput>,
+        sort: Option<SortInput>,
+        #[graphql(default)] forms: bool,
+        #[graphql(default)] offset: usize,
+        #[graphql(default_with = "DEFAULT_LIMIT")] limit: usize,
+    ) -> Vec<PokemonObject> {
+        let filter = filter.unwrap_or_default();
+        let mut entries = filter.entries(database(ctx), forms);
+        // Orden estable: los empates conservan el orden por número
+        if let Some(sort) = sort {
+            if sort.descending {
+                entries.sort_by_key(|p| std::cmp::Reverse(sort_key(p, sort.field)));
+            } else {
+                entries.sort_by_key(|p| sort_key(p, sort.field));
+            }
+        }
+        entries.into_iter().skip(offset).take(limit).map(|p| PokemonObject(p.clone())).collect()
+    }
+
+    async fn count(&self, ctx: &Context<'_>, filter: Option<PokemonFilterInput>, #[graphql(default)] forms: bool) -> usize {
+        filter.unwrap_or_default().entries(database(ctx), forms).len()
+    }
+
+    async fn types(&self) -> Vec<TypeObject> {
+        PokemonType::ALL.into_iter().map(TypeObject).collect()
+    }
+
+    #[graphql(name = "type")]
+    async fn type_info(&self, name: PokemonType) -> TypeObject {
+        TypeObject(name)
+    }
+
+    // Multiplicadores que recibe una combinación de tipos defensora
+    async fn matchup(&self, types: Vec<PokemonType>) -> Vec<Effectiveness> {
+        defense_against(&types)
+    }
+}
+
+pub struct PokemonObject(Pokemon);
+
+#[Object(name = "Pokemon")]
+impl PokemonObject {
+    async fn id(&self) -> u16 {
+        self.0.id
+    }
+
+    async fn name(&self) -> &str {
+        &self.0.name
+    }
+
+    async fn form(&self) -> Option<&str> {
+        self.0.form.as_deref()
+    }
+
+    async fn display_name(&self) -> String {
+        self.0.display_name()
+    }
+
+    async fn types(&self) -> &[PokemonType] {
+        &self.0.types
+    }
+
+    async fn stats(&self) -> StatsObject {
+        StatsObject(self.0.clone())
+    }
+
+    async fn generation(&self) -> u8 {
+        self.0.generation
+    }
+
+    async fn legendary(&self) -> bool {
+        self.0.legendary
+    }
+
+    // Todas las formas de la especie, empezando por la base
+    async fn forms(&self, ctx: &Context<'_>) -> Vec<PokemonObject> {
+        database(ctx).get_forms(self.0.id).into_iter().map(|p| PokemonObject(p.clone())).collect()
+    }
+
+    // Forma base de la especie
+    async fn species(&self, ctx: &Context<'_>) -> Option<PokemonObject> {
+        database(ctx).get_pokemon(self.0.id).map(|p| PokemonObject(p.clone()))
+    }
+
+    // Especie de la que evoluciona
+    async fn pre_evolution(&self, ctx: &Context<'_>) -> Option<PokemonObject> {
+        database(ctx).pre_evolution(self.0.id).map(|p| PokemonObject(p.clone()))
+    }
+
+    // Especies a las que evoluciona directamente
+    async fn evolutions(&self, ctx: &Context<'_>) -> Vec<PokemonObject> {
+        database(ctx).evolutions(self.0.id).into_iter().map(|p| PokemonObject(p.clone())).collect()
+    }
+
+    // Toda la familia evolutiva por etapas, empezando por la primera
+    async fn evolution_chain(&self, ctx: &Context<'_>) -> Vec<PokemonObject> {
+        database(ctx).evolution_family(self.0.id).into_iter().map(|p| PokemonObject(p.clone())).collect()
+    }
+
+    // Multiplicador de cada tipo atacante contra este Pokémon
+    async fn defense(&self) -> Vec<Effectiveness> {
+        defense_against(&self.0.types)
+    }
+
+    async fn weaknesses(&self) -> Vec<Effectiveness> {
+        defense_against(&self.0.types).into_iter().filter(|e| e.multiplier > 1.0).collect()
+    }
+
+    async fn resistances(&self) -> Vec<Effectiveness> {
+        defense_against(&self.0.types).into_iter().filter(|e| e.multiplier < 1.0).collect()
+    }
+}
+
+pub struct StatsObject(Pokemon);
+
+#[Object(name = "Stats")]
+impl StatsObject {
+    async fn hp(&self) -> u8 {
+        self.0.stats.hp
+    }
+
+    async fn attack(&self) -> u8 {
+        self.0.stats.attack
+    }
+
+    async fn defense(&self) -> u8 {
+        self.0.stats.defense
+    }
+
+    async fn special_attack(&self) -> u8 {
+        self.0.stats.special_attack
+    }
+
+    async fn special_defense(&self) -> u8 {
+        self.0.stats.special_defense
+    }
+
+    async fn speed(&self) -> u8 {
+        self.0.stats.speed
+    }
+
+    async fn total(&self) -> u16 {
+        self.0.total_stats()
+    }
+}
+
+pub struct TypeObject(PokemonType);
+
+#[Object(name = "TypeInfo")]
+impl TypeObject {
+    #[graphql(name = "type")]
+    async fn pokemon_type(&self) -> PokemonType {
+        self.0
+    }
+
+    // Multiplicador de un ataque de este tipo contra cada tipo defensor
+    async fn offense(&self) -> Vec<Effectiveness> {
+        PokemonType::ALL
+            .into_iter()
+            .map(|t| Effectiveness { pokemon_type: t, multiplier: self.0.effectiveness(t) })
+            .collect()
+    }
+
+    async fn defense(&self) -> Vec<Effectiveness> {
+        defense_against(&[self.0])
+    }
+
+    async fn pokemons(&self, ctx: &Context<'_>, #[graphql(default_with = "DEFAULT_LIMIT")] limit: usize) -> Vec<PokemonObject> {
+        let filter = PokemonFilter { pokemon_type: Some(self.0), ..PokemonFilter::default() };
+        database(ctx).query(&filter).into_iter().take(limit).map(|p| PokemonObject(p.clone())).collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, async_graphql::SimpleObject)]
+pub struct Effectiveness {
+    #[graphql(name = "type")]
+    pub pokemon_type: PokemonType,
+    pub multiplier: f32,
+}
+
+fn defense_against(types: &[PokemonType]) -> Vec<Effectiveness> {
+    PokemonType::ALL
+        .into_iter()
+        .map(|t| Effectiveness { pokemon_type: t, multiplier: t.effectiveness_against(types) })
+        .collect()
+}
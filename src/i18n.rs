@@ -0,0 +1,138 @@
+use crate::pokemon::{Pokemon, PokemonType, StatKind};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::Display;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Language {
+    Spanish,
+    English,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::Spanish, Language::English];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::Spanish => "es",
+            Language::English => "en",
+        }
+    }
+
+    // Nombre del idioma en su propio idioma, para el selector
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Language::Spanish => "Español",
+            Language::English => "English",
+        }
+    }
+
+    // Los catálogos van incluidos en el binario igual que el CSV
+    fn catalog_source(&self) -> &'static str {
+        match self {
+            Language::Spanish => include_str!("../assets/i18n/es.json"),
+            Language::English => include_str!("../assets/i18n/en.json"),
+        }
+    }
+}
+
+// Archivo opcional con nombres localizados, indexado por código de idioma:
+// { "species": { "ja": { "25": "ピカチュウ" } }, "types": { "ja": { "Electric": "でんき" } } }
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct LocalizedNames {
+    #[serde(default)]
+    species: HashMap<String, HashMap<u16, String>>,
+    #[serde(default)]
+    types: HashMap<String, HashMap<PokemonType, String>>,
+}
+
+impl LocalizedNames {
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let content = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    pub fn species_name(&self, id: u16, language_code: &str) -> Option<&str> {
+        self.species.get(language_code)?.get(&id).map(String::as_str)
+    }
+
+    pub fn type_name(&self, pokemon_type: PokemonType, language_code: &str) -> Option<&str> {
+        self.types.get(language_code)?.get(&pokemon_type).map(String::as_str)
+    }
+
+    // Nombres de la especie en todos los idiomas cargados (para la búsqueda)
+    pub fn all_species_names(&self, id: u16) -> impl Iterator<Item = &str> {
+        self.species
+            .values()
+            .filter_map(move |names| names.get(&id).map(String::as_str))
+    }
+}
+
+pub struct I18n {
+    language: Language,
+    catalogs: HashMap<Language, HashMap<String, String>>,
+}
+
+impl I18n {
+    pub fn new(language: Language) -> Self {
+        let catalogs = Language::ALL
+            .iter()
+            .map(|&lang| {
+                let catalog = serde_json::from_str(lang.catalog_source()).unwrap_or_else(|e| {
+                    eprintln!("Catálogo de idioma '{}' inválido: {}", lang.code(), e);
+                    HashMap::new()
+                });
+                (lang, catalog)
+            })
+            .collect();
+        Self { language, catalogs }
+    }
+
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    pub fn set_language(&mut self, language: Language) {
+        self.language = language;
+    }
+
+    // Busca el texto en el idioma actual, luego en español y si no devuelve la clave
+    pub fn tr<'a>(&'a self, key: &'a str) -> &'a str {
+        [self.language, Language::Spanish]
+            .iter()
+            .find_map(|lang| self.catalogs.get(lang)?.get(key))
+            .map(String::as_str)
+            .unwrap_or(key)
+    }
+
+    // Igual que tr pero reemplazando los parámetros {nombre}
+    pub fn tr_args(&self, key: &str, args: &[(&str, &dyn Display)]) -> String {
+        let mut text = self.tr(key).to_string();
+        for (name, value) in args {
+            text = text.replace(&format!("{{{}}}", name), &value.to_string());
+        }
+        text
+    }
+
+    pub fn stat_name(&self, kind: StatKind) -> &str {
+        self.tr(kind.key())
+    }
+
+    pub fn type_name(&self, pokemon_type: PokemonType, names: &LocalizedNames) -> String {
+        match names.type_name(pokemon_type, self.language.code()) {
+            Some(name) => name.to_string(),
+            None => self.tr(&pokemon_type.key()).to_string(),
+        }
+    }
+
+    // Nombre para mostrar con la especie traducida si existe ("Mega Venusaur" incluido)
+    pub fn pokemon_name(&self, pokemon: &Pokemon, names: &LocalizedNames) -> String {
+        let display_name = pokemon.display_name();
+        match names.species_name(pokemon.id, self.language.code()) {
+            Some(localized) => display_name.replace(&pokemon.name, localized),
+            None => display_name,
+        }
+    }
+}
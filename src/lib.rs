@@ -0,0 +1,31 @@
+// Modelo de datos y análisis de la Pokédex, utilizables sin la interfaz gráfica
+pub mod pokemon;
+pub mod analytics;
+pub mod battle;
+pub mod card;
+pub mod collections;
+pub mod database;
+pub mod export;
+pub mod flashcards;
+pub mod generator;
+#[cfg(feature = "graphql")]
+pub mod graphql;
+pub mod i18n;
+pub mod quiz;
+pub mod rng;
+pub mod rpc;
+#[cfg(feature = "server")]
+pub mod server;
+pub mod showdown;
+pub mod similarity;
+pub mod speed;
+pub mod sprites;
+pub mod storage;
+pub mod team;
+#[cfg(feature = "tui")]
+pub mod tui;
+#[cfg(feature = "gui")]
+pub mod ui;
+
+pub use database::{Database, PokemonFilter};
+pub use pokemon::{Nature, Pokemon, PokemonType, StatKind, Stats};
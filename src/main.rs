@@ -1,36 +1,104 @@
-mod pokemon;
-mod database;
-mod ui;
+#[cfg(feature = "cli")]
+mod cli;
 
-use database::Database;
-use ui::PokedexApp;
-use eframe::egui;
+#[cfg(feature = "cli")]
+use clap::Parser;
+use pokedex::Database;
+#[cfg(feature = "cli")]
+use std::io;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+const DEFAULT_NAMES_FILE: &str = "assets/i18n/names.json";
+
+fn main() -> ExitCode {
+    #[cfg(feature = "cli")]
+    let args = cli::Cli::parse();
 
-fn main() -> Result<(), eframe::Error> {
-    println!("Iniciando PokéDex...");
-    
     // Inicializar la base de datos desde el CSV
     let mut database = Database::new();
-    match database.load_from_csv() {
-        Ok(_) => println!("Base de datos cargada exitosamente"),
+    if let Err(e) = database.load_from_csv() {
+        eprintln!("Error al cargar la base de datos: {}", e);
+        return ExitCode::FAILURE;
+    }
+
+    // Nombres localizados opcionales (POKEDEX_NAMES_FILE o assets/i18n/names.json)
+    let names_file = std::env::var_os("POKEDEX_NAMES_FILE")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_NAMES_FILE));
+    if names_file.exists() {
+        // Los avisos van a stderr: la salida estándar es solo para los resultados (JSON en rpc)
+        match database.load_localized_names(&names_file) {
+            Ok(()) => eprintln!("Nombres localizados cargados desde {}", names_file.display()),
+            Err(e) => eprintln!("Error al cargar {}: {}", names_file.display(), e),
+        }
+    }
+
+    #[cfg(feature = "cli")]
+    if let Some(command) = args.command {
+        return run_command(command, &database, args.json);
+    }
+    run_gui(database)
+}
+
+#[cfg(feature = "cli")]
+fn run_command(command: cli::Command, database: &Database, json: bool) -> ExitCode {
+    let mut out = io::stdout().lock();
+    match cli::run(command, database, json, &mut out) {
+        Ok(code) => code,
+        // La salida se cerró antes de tiempo (p. ej. `pokedex list | head`)
+        Err(e) if e.downcast_ref::<io::Error>().is_some_and(|e| e.kind() == io::ErrorKind::BrokenPipe) => {
+            ExitCode::SUCCESS
+        }
         Err(e) => {
-            eprintln!("Error al cargar la base de datos: {}", e);
-            return Ok(());
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
         }
     }
+}
+
+#[cfg(feature = "gui")]
+fn run_gui(database: Database) -> ExitCode {
+    use eframe::egui;
+    use pokedex::ui::{PokedexApp, Settings};
 
+    println!("Iniciando PokéDex...");
+    println!("Cargados {} Pokémon desde CSV", database.get_all_pokemon().len());
+
+    // La ventana se abre con el tamaño y la posición de la última sesión
+    let settings = Settings::load();
     let native_options = eframe::NativeOptions {
-        initial_window_size: Some(egui::vec2(900.0, 600.0)),
+        initial_window_size: Some(settings.window.initial_size()),
+        initial_window_pos: settings.window.initial_position(),
+        maximized: settings.window.maximized,
         min_window_size: Some(egui::vec2(800.0, 500.0)),
-        centered: true,
+        centered: settings.window.position.is_none(),
         transparent: true,
         default_theme: eframe::Theme::Light,
         ..Default::default()
     };
 
-    eframe::run_native(
+    match eframe::run_native(
         "PokéDex pero en Rust",
         native_options,
-        Box::new(|_cc| Box::new(PokedexApp::new(database)))
-    )
-}
\ No newline at end of file
+        Box::new(|_cc| Box::new(PokedexApp::new(database, settings)))
+    ) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error en la interfaz gráfica: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(all(not(feature = "gui"), feature = "cli"))]
+fn run_gui(_database: Database) -> ExitCode {
+    eprintln!("Esta compilación no incluye la interfaz gráfica (feature \"gui\"); usa un subcomando o --help");
+    ExitCode::FAILURE
+}
+
+#[cfg(all(not(feature = "gui"), not(feature = "cli")))]
+fn run_gui(_database: Database) -> ExitCode {
+    eprintln!("Esta compilación no incluye ni la interfaz gráfica ni los subcomandos (features \"gui\" y \"cli\")");
+    ExitCode::FAILURE
+}
@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(async_graphql::Enum))]
 pub enum PokemonType {
     Normal,
     Fire,
@@ -23,6 +24,123 @@ pub enum PokemonType {
     Fairy,
 }
 
+// Lista de tipos, claves de traducción, colores y tabla de efectividades
+impl PokemonType {
+    pub const ALL: [PokemonType; 18] = [
+        PokemonType::Normal,
+        PokemonType::Fire,
+        PokemonType::Water,
+        PokemonType::Electric,
+        PokemonType::Grass,
+        PokemonType::Ice,
+        PokemonType::Fighting,
+        PokemonType::Poison,
+        PokemonType::Ground,
+        PokemonType::Flying,
+        PokemonType::Psychic,
+        PokemonType::Bug,
+        PokemonType::Rock,
+        PokemonType::Ghost,
+        PokemonType::Dragon,
+        PokemonType::Dark,
+        PokemonType::Steel,
+        PokemonType::Fairy,
+    ];
+
+    // Clave del texto traducido en los catálogos de i18n
+    pub fn key(&self) -> String {
+        format!("type.{}", self.to_string().to_lowercase())
+    }
+
+    // Color RGB de cada tipo, el mismo en la interfaz gráfica y en la terminal
+    pub fn rgb(&self) -> (u8, u8, u8) {
+        match self {
+            PokemonType::Normal => (168, 168, 120),
+            PokemonType::Fire => (240, 128, 48),
+            PokemonType::Water => (104, 144, 240),
+            PokemonType::Grass => (120, 200, 80),
+            PokemonType::Electric => (248, 208, 48),
+            PokemonType::Ice => (152, 216, 216),
+            PokemonType::Fighting => (192, 48, 40),
+            PokemonType::Poison => (160, 64, 160),
+            PokemonType::Ground => (224, 192, 104),
+            PokemonType::Flying => (168, 144, 240),
+            PokemonType::Psychic => (248, 88, 136),
+            PokemonType::Bug => (168, 184, 32),
+            PokemonType::Rock => (184, 160, 56),
+            PokemonType::Ghost => (112, 88, 152),
+            PokemonType::Dragon => (112, 56, 248),
+            PokemonType::Dark => (112, 88, 72),
+            PokemonType::Steel => (184, 184, 208),
+            PokemonType::Fairy => (238, 153, 172),
+        }
+    }
+
+    // Nombre en inglés sin distinguir mayúsculas: "fire", "Fire" -> Fire
+    pub fn from_name(name: &str) -> Option<PokemonType> {
+        PokemonType::ALL
+            .iter()
+            .find(|pokemon_type| pokemon_type.to_string().eq_ignore_ascii_case(name.trim()))
+            .copied()
+    }
+
+    // Multiplicador de daño de un ataque de este tipo contra un tipo defensor (tabla de la 6ª generación)
+    pub fn effectiveness(&self, defender: PokemonType) -> f32 {
+        use PokemonType::*;
+        match (self, defender) {
+            (Normal, Rock | Steel) => 0.5,
+            (Normal, Ghost) => 0.0,
+            (Fire, Grass | Ice | Bug | Steel) => 2.0,
+            (Fire, Fire | Water | Rock | Dragon) => 0.5,
+            (Water, Fire | Ground | Rock) => 2.0,
+            (Water, Water | Grass | Dragon) => 0.5,
+            (Electric, Water | Flying) => 2.0,
+            (Electric, Electric | Grass | Dragon) => 0.5,
+            (Electric, Ground) => 0.0,
+            (Grass, Water | Ground | Rock) => 2.0,
+            (Grass, Fire | Grass | Poison | Flying | Bug | Dragon | Steel) => 0.5,
+            (Ice, Grass | Ground | Flying | Dragon) => 2.0,
+            (Ice, Fire | Water | Ice | Steel) => 0.5,
+            (Fighting, Normal | Ice | Rock | Dark | Steel) => 2.0,
+            (Fighting, Poison | Flying | Psychic | Bug | Fairy) => 0.5,
+            (Fighting, Ghost) => 0.0,
+            (Poison, Grass | Fairy) => 2.0,
+            (Poison, Poison | Ground | Rock | Ghost) => 0.5,
+            (Poison, Steel) => 0.0,
+            (Ground, Fire | Electric | Poison | Rock | Steel) => 2.0,
+            (Ground, Grass | Bug) => 0.5,
+            (Ground, Flying) => 0.0,
+            (Flying, Grass | Fighting | Bug) => 2.0,
+            (Flying, Electric | Rock | Steel) => 0.5,
+            (Psychic, Fighting | Poison) => 2.0,
+            (Psychic, Psychic | Steel) => 0.5,
+            (Psychic, Dark) => 0.0,
+            (Bug, Grass | Psychic | Dark) => 2.0,
+            (Bug, Fire | Fighting | Poison | Flying | Ghost | Steel | Fairy) => 0.5,
+            (Rock, Fire | Ice | Flying | Bug) => 2.0,
+            (Rock, Fighting | Ground | Steel) => 0.5,
+            (Ghost, Psychic | Ghost) => 2.0,
+            (Ghost, Dark) => 0.5,
+            (Ghost, Normal) => 0.0,
+            (Dragon, Dragon) => 2.0,
+            (Dragon, Steel) => 0.5,
+            (Dragon, Fairy) => 0.0,
+            (Dark, Psychic | Ghost) => 2.0,
+            (Dark, Fighting | Dark | Fairy) => 0.5,
+            (Steel, Ice | Rock | Fairy) => 2.0,
+            (Steel, Fire | Water | Electric | Steel) => 0.5,
+            (Fairy, Fighting | Dragon | Dark) => 2.0,
+            (Fairy, Fire | Poison | Steel) => 0.5,
+            _ => 1.0,
+        }
+    }
+
+    // Multiplicador contra un Pokémon con uno o dos tipos
+    pub fn effectiveness_against(&self, defender_types: &[PokemonType]) -> f32 {
+        defender_types.iter().map(|&t| self.effectiveness(t)).product()
+    }
+}
+
 // Implementar Display para PokemonType
 impl fmt::Display for PokemonType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -53,13 +171,131 @@ impl fmt::Display for PokemonType {
 pub struct Pokemon {
     pub id: u16,
     pub name: String,
+    pub form: Option<String>,
     pub types: Vec<PokemonType>,
-    pub height: u16,
-    pub weight: u16,
+    // El CSV no trae altura ni peso; quedan en None si no se conocen
+    pub height: Option<u16>,
+    pub weight: Option<u16>,
     pub stats: Stats,
+    pub generation: u8,
+    pub legendary: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+// Identifica cada una de las seis estadísticas base
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum StatKind {
+    Hp,
+    Attack,
+    Defense,
+    SpecialAttack,
+    SpecialDefense,
+    Speed,
+}
+
+impl StatKind {
+    pub const ALL: [StatKind; 6] = [
+        StatKind::Hp,
+        StatKind::Attack,
+        StatKind::Defense,
+        StatKind::SpecialAttack,
+        StatKind::SpecialDefense,
+        StatKind::Speed,
+    ];
+
+    // Clave del texto traducido en los catálogos de i18n
+    pub fn key(&self) -> &'static str {
+        match self {
+            StatKind::Hp => "stat.hp",
+            StatKind::Attack => "stat.attack",
+            StatKind::Defense => "stat.defense",
+            StatKind::SpecialAttack => "stat.special_attack",
+            StatKind::SpecialDefense => "stat.special_defense",
+            StatKind::Speed => "stat.speed",
+        }
+    }
+}
+
+// Naturalezas: suben un 10% una estadística y bajan otra (las neutras no cambian nada)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Nature {
+    Hardy, Lonely, Brave, Adamant, Naughty,
+    Bold, Docile, Relaxed, Impish, Lax,
+    Timid, Hasty, Serious, Jolly, Naive,
+    Modest, Mild, Quiet, Bashful, Rash,
+    Calm, Gentle, Sassy, Careful, Quirky,
+}
+
+impl Nature {
+    pub const ALL: [Nature; 25] = [
+        Nature::Hardy, Nature::Lonely, Nature::Brave, Nature::Adamant, Nature::Naughty,
+        Nature::Bold, Nature::Docile, Nature::Relaxed, Nature::Impish, Nature::Lax,
+        Nature::Timid, Nature::Hasty, Nature::Serious, Nature::Jolly, Nature::Naive,
+        Nature::Modest, Nature::Mild, Nature::Quiet, Nature::Bashful, Nature::Rash,
+        Nature::Calm, Nature::Gentle, Nature::Sassy, Nature::Careful, Nature::Quirky,
+    ];
+
+    // El orden de ALL sigue la tabla de juegos: fila = estadística que sube, columna = la que baja
+    fn table_position(&self) -> (StatKind, StatKind) {
+        const ORDER: [StatKind; 5] = [
+            StatKind::Attack,
+            StatKind::Defense,
+            StatKind::Speed,
+            StatKind::SpecialAttack,
+            StatKind::SpecialDefense,
+        ];
+        let index = Nature::ALL.iter().position(|n| n == self).unwrap_or(0);
+        (ORDER[index / 5], ORDER[index % 5])
+    }
+
+    pub fn increased(&self) -> Option<StatKind> {
+        let (up, down) = self.table_position();
+        (up != down).then_some(up)
+    }
+
+    pub fn decreased(&self) -> Option<StatKind> {
+        let (up, down) = self.table_position();
+        (up != down).then_some(down)
+    }
+
+    // En porcentaje para hacer el cálculo con enteros como los juegos: 110, 100 o 90
+    pub fn modifier_percent(&self, kind: StatKind) -> u32 {
+        if self.increased() == Some(kind) {
+            110
+        } else if self.decreased() == Some(kind) {
+            90
+        } else {
+            100
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Nature> {
+        Nature::ALL
+            .iter()
+            .find(|nature| nature.to_string().eq_ignore_ascii_case(name.trim()))
+            .copied()
+    }
+}
+
+impl fmt::Display for Nature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+// Estadística real a un nivel con la fórmula de los juegos (3.ª generación en adelante)
+pub fn calculate_stat(kind: StatKind, base: u8, iv: u8, ev: u8, level: u8, nature: Option<Nature>) -> u16 {
+    let core = (2 * base as u32 + iv as u32 + ev as u32 / 4) * level as u32 / 100;
+    let value = match kind {
+        StatKind::Hp => core + level as u32 + 10,
+        _ => {
+            let percent = nature.map_or(100, |n| n.modifier_percent(kind));
+            (core + 5) * percent / 100
+        }
+    };
+    value as u16
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct Stats {
     pub hp: u8,
     pub attack: u8,
@@ -70,6 +306,15 @@ pub struct Stats {
 }
 
 impl Pokemon {
+    // Nombre para mostrar incluyendo la forma: "Mega Venusaur", "Deoxys (Attack Forme)"
+    pub fn display_name(&self) -> String {
+        match &self.form {
+            Some(form) if form.contains(&self.name) => form.clone(),
+            Some(form) => format!("{} ({})", self.name, form),
+            None => self.name.clone(),
+        }
+    }
+
     // Método para obtener el tipo primario
     pub fn primary_type(&self) -> Option<&PokemonType> {
         self.types.first()
@@ -95,48 +340,66 @@ impl Pokemon {
     }
 
     // Método para formatear altura y peso
-    pub fn formatted_height(&self) -> f32 {
-        self.height as f32 / 10.0
+    pub fn formatted_height(&self) -> Option<f32> {
+        self.height.map(|height| height as f32 / 10.0)
     }
 
-    pub fn formatted_weight(&self) -> f32 {
-        self.weight as f32 / 10.0
+    pub fn formatted_weight(&self) -> Option<f32> {
+        self.weight.map(|weight| weight as f32 / 10.0)
     }
 }
 
 impl Stats {
+    // Todas las estadísticas con el mismo valor (p. ej. IVs a 31)
+    pub fn uniform(value: u8) -> Self {
+        Self {
+            hp: value,
+            attack: value,
+            defense: value,
+            special_attack: value,
+            special_defense: value,
+            speed: value,
+        }
+    }
+
+    pub fn get(&self, kind: StatKind) -> u8 {
+        match kind {
+            StatKind::Hp => self.hp,
+            StatKind::Attack => self.attack,
+            StatKind::Defense => self.defense,
+            StatKind::SpecialAttack => self.special_attack,
+            StatKind::SpecialDefense => self.special_defense,
+            StatKind::Speed => self.speed,
+        }
+    }
+
+    pub fn set(&mut self, kind: StatKind, value: u8) {
+        match kind {
+            StatKind::Hp => self.hp = value,
+            StatKind::Attack => self.attack = value,
+            StatKind::Defense => self.defense = value,
+            StatKind::SpecialAttack => self.special_attack = value,
+            StatKind::SpecialDefense => self.special_defense = value,
+            StatKind::Speed => self.speed = value,
+        }
+    }
+
     // Método para obtener el stat más alto
-    pub fn highest_stat(&self) -> (&str, u8) {
-        let stats = [
-            ("HP", self.hp),
-            ("Ataque", self.attack),
-            ("Defensa", self.defense),
-            ("Atq. Esp.", self.special_attack),
-            ("Def. Esp.", self.special_defense),
-            ("Velocidad", self.speed),
-        ];
-        
-        stats.iter()
-            .max_by_key(|(_, value)| value)
-            .map(|&(name, value)| (name, value))
-            .unwrap_or(("HP", 0))
+    pub fn highest_stat(&self) -> (StatKind, u8) {
+        StatKind::ALL
+            .iter()
+            .map(|&kind| (kind, self.get(kind)))
+            .max_by_key(|&(_, value)| value)
+            .unwrap_or((StatKind::Hp, 0))
     }
 
     // Método para obtener el stat más bajo
-    pub fn lowest_stat(&self) -> (&str, u8) {
-        let stats = [
-            ("HP", self.hp),
-            ("Ataque", self.attack),
-            ("Defensa", self.defense),
-            ("Atq. Esp.", self.special_attack),
-            ("Def. Esp.", self.special_defense),
-            ("Velocidad", self.speed),
-        ];
-        
-        stats.iter()
-            .min_by_key(|(_, value)| value)
-            .map(|&(name, value)| (name, value))
-            .unwrap_or(("HP", 0))
+    pub fn lowest_stat(&self) -> (StatKind, u8) {
+        StatKind::ALL
+            .iter()
+            .map(|&kind| (kind, self.get(kind)))
+            .min_by_key(|&(_, value)| value)
+            .unwrap_or((StatKind::Hp, 0))
     }
 
     // Método para obtener el promedio de stats
@@ -153,7 +416,7 @@ impl Stats {
 // Implementar PartialOrd para Pokemon para poder ordenarlos
 impl PartialOrd for Pokemon {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.id.cmp(&other.id))
+        Some(self.cmp(other))
     }
 }
 
@@ -0,0 +1,627 @@
+use crate::database::Database;
+use crate::pokemon::{Pokemon, PokemonType, StatKind};
+use crate::rng::{Date, Rng};
+use crate::storage;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::fmt;
+use std::path::PathBuf;
+
+const HIGH_SCORES_FILE: &str = "quiz_scores.json";
+const CURRENT_VERSION: u32 = 1;
+
+// Preguntas por ronda
+pub const ROUND_LENGTH: u32 = 10;
+// Récords que se guardan por dificultad
+pub const MAX_HIGH_SCORES: usize = 10;
+// Cada tantos aciertos seguidos sube el multiplicador de puntos
+pub const STREAK_STEP: u32 = 3;
+// Intentos para encontrar una pregunta sin ambigüedades antes de probar otro tipo de pregunta
+const MAX_ATTEMPTS: usize = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum QuizKind {
+    // Se muestran las estadísticas base y hay que adivinar el Pokémon
+    NameFromStats,
+    // Se muestra el Pokémon y hay que adivinar su tipo
+    TypeFromName,
+    // Varios Pokémon: ¿cuál tiene más de una estadística?
+    HigherStat,
+    // ¿Qué tipo es supereficaz contra el Pokémon?
+    Weakness,
+}
+
+impl QuizKind {
+    pub const ALL: [QuizKind; 4] = [
+        QuizKind::NameFromStats,
+        QuizKind::TypeFromName,
+        QuizKind::HigherStat,
+        QuizKind::Weakness,
+    ];
+
+    // Clave del texto traducido en los catálogos de i18n
+    pub fn key(&self) -> &'static str {
+        match self {
+            QuizKind::NameFromStats => "quiz.kind.name_from_stats",
+            QuizKind::TypeFromName => "quiz.kind.type_from_name",
+            QuizKind::HigherStat => "quiz.kind.higher_stat",
+            QuizKind::Weakness => "quiz.kind.weakness",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "quiz.difficulty.easy",
+            Difficulty::Normal => "quiz.difficulty.normal",
+            Difficulty::Hard => "quiz.difficulty.hard",
+        }
+    }
+
+    // Generaciones con las que empieza cada dificultad; vacío = todas
+    pub fn generations(&self) -> BTreeSet<u8> {
+        match self {
+            Difficulty::Easy => BTreeSet::from([1]),
+            Difficulty::Normal => (1..=3).collect(),
+            Difficulty::Hard => BTreeSet::new(),
+        }
+    }
+
+    // Opciones entre las que elegir en cada pregunta
+    pub fn choices(&self) -> usize {
+        match self {
+            Difficulty::Easy => 3,
+            Difficulty::Normal => 4,
+            Difficulty::Hard => 6,
+        }
+    }
+
+    // Solo en difícil entran las megas y demás formas
+    pub fn includes_forms(&self) -> bool {
+        *self == Difficulty::Hard
+    }
+
+    pub fn points(&self) -> u32 {
+        match self {
+            Difficulty::Easy => 10,
+            Difficulty::Normal => 20,
+            Difficulty::Hard => 30,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuizSettings {
+    pub kinds: BTreeSet<QuizKind>,
+    pub difficulty: Difficulty,
+    // Generaciones de las que salen los Pokémon; vacío = todas
+    pub generations: BTreeSet<u8>,
+}
+
+impl Default for QuizSettings {
+    fn default() -> Self {
+        Self::with_difficulty(Difficulty::Normal)
+    }
+}
+
+impl QuizSettings {
+    pub fn with_difficulty(difficulty: Difficulty) -> Self {
+        Self {
+            kinds: QuizKind::ALL.into_iter().collect(),
+            difficulty,
+            generations: difficulty.generations(),
+        }
+    }
+
+    pub fn allows(&self, pokemon: &Pokemon) -> bool {
+        (self.generations.is_empty() || self.generations.contains(&pokemon.generation))
+            && (pokemon.form.is_none() || self.difficulty.includes_forms())
+    }
+}
+
+// Referencia a una entrada de la Pokédex que se puede guardar entre fotogramas
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PokemonKey {
+    pub id: u16,
+    pub form: Option<String>,
+}
+
+impl PokemonKey {
+    pub fn of(pokemon: &Pokemon) -> Self {
+        Self {
+            id: pokemon.id,
+            form: pokemon.form.clone(),
+        }
+    }
+
+    pub fn resolve<'a>(&self, database: &'a Database) -> Option<&'a Pokemon> {
+        database.get_pokemon_form(self.id, self.form.as_deref())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Prompt {
+    Stats(PokemonKey),
+    Types(PokemonKey),
+    HigherStat(StatKind),
+    Weakness(PokemonKey),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Choice {
+    Pokemon(PokemonKey),
+    Types(Vec<PokemonType>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Question {
+    pub prompt: Prompt,
+    pub choices: Vec<Choice>,
+    // Posición de la respuesta correcta en `choices`
+    pub answer: usize,
+}
+
+impl Question {
+    pub fn kind(&self) -> QuizKind {
+        match self.prompt {
+            Prompt::Stats(_) => QuizKind::NameFromStats,
+            Prompt::Types(_) => QuizKind::TypeFromName,
+            Prompt::HigherStat(_) => QuizKind::HigherStat,
+            Prompt::Weakness(_) => QuizKind::Weakness,
+        }
+    }
+
+    // El Pokémon por el que se pregunta, si la pregunta es sobre uno solo
+    pub fn subject(&self) -> Option<&PokemonKey> {
+        match &self.prompt {
+            Prompt::Stats(key) | Prompt::Types(key) | Prompt::Weakness(key) => Some(key),
+            Prompt::HigherStat(_) => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum QuizError {
+    NoKinds,
+    // Con estas generaciones no hay Pokémon suficientes para formar ninguna pregunta
+    NotEnoughCandidates,
+}
+
+impl fmt::Display for QuizError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            QuizError::NoKinds => write!(f, "no se ha elegido ningún tipo de pregunta"),
+            QuizError::NotEnoughCandidates => write!(f, "no hay Pokémon suficientes para formar preguntas"),
+        }
+    }
+}
+
+impl Error for QuizError {}
+
+pub fn candidates<'a>(database: &'a Database, settings: &QuizSettings) -> Vec<&'a Pokemon> {
+    database
+        .get_all_pokemon()
+        .into_iter()
+        .filter(|p| settings.allows(p))
+        .collect()
+}
+
+// Coloca la respuesta en una posición al azar entre las opciones incorrectas
+fn shuffle_in(prompt: Prompt, mut choices: Vec<Choice>, answer: Choice, rng: &mut Rng) -> Question {
+    let position = rng.below(choices.len() + 1);
+    choices.insert(position, answer);
+    Question { prompt, choices, answer: position }
+}
+
+fn name_from_stats(pool: &[&Pokemon], count: usize, rng: &mut Rng) -> Option<Question> {
+    let answer = *rng.choose(pool)?;
+    let mut others = pool.to_vec();
+    rng.shuffle(&mut others);
+    // Las opciones no pueden compartir estadísticas con la respuesta (Pokémon con formas idénticas)
+    let mut seen = BTreeSet::new();
+    let distractors: Vec<Choice> = others
+        .into_iter()
+        .filter(|p| p.stats != answer.stats && seen.insert(p.display_name()))
+        .take(count - 1)
+        .map(|p| Choice::Pokemon(PokemonKey::of(p)))
+        .collect();
+    if distractors.len() < count - 1 {
+        return None;
+    }
+    let key = PokemonKey::of(answer);
+    Some(shuffle_in(Prompt::Stats(key.clone()), distractors, Choice::Pokemon(key), rng))
+}
+
+fn type_from_name(pool: &[&Pokemon], count: usize, rng: &mut Rng) -> Option<Question> {
+    let answer = *rng.choose(pool)?;
+    let mut others = pool.to_vec();
+    rng.shuffle(&mut others);
+    // Las opciones incorrectas son combinaciones de tipos que existen, para que no sea tan evidente.
+    // Se comparan sin orden: Roca/Tierra y Tierra/Roca son la misma respuesta
+    let typing = |p: &Pokemon| p.types.iter().copied().collect::<BTreeSet<PokemonType>>();
+    let mut seen = BTreeSet::from([typing(answer)]);
+    let distractors: Vec<Choice> = others
+        .into_iter()
+        .filter(|p| seen.insert(typing(p)))
+        .take(count - 1)
+        .map(|p| Choice::Types(p.types.clone()))
+        .collect();
+    if distractors.len() < count - 1 {
+        return None;
+    }
+    Some(shuffle_in(
+        Prompt::Types(PokemonKey::of(answer)),
+        distractors,
+        Choice::Types(answer.types.clone()),
+        rng,
+    ))
+}
+
+fn higher_stat(pool: &[&Pokemon], count: usize, rng: &mut Rng) -> Option<Question> {
+    let stat = *rng.choose(&StatKind::ALL)?;
+    let mut others = pool.to_vec();
+    rng.shuffle(&mut others);
+    // Valores distintos para que haya un único ganador
+    let mut values = BTreeSet::new();
+    let mut picked: Vec<&Pokemon> = others
+        .into_iter()
+        .filter(|p| values.insert(p.stats.get(stat)))
+        .take(count)
+        .collect();
+    if picked.len() < count {
+        return None;
+    }
+    let best = (0..picked.len()).max_by_key(|&i| picked[i].stats.get(stat))?;
+    let answer = picked.remove(best);
+    let distractors = picked.into_iter().map(|p| Choice::Pokemon(PokemonKey::of(p))).collect();
+    Some(shuffle_in(Prompt::HigherStat(stat), distractors, Choice::Pokemon(PokemonKey::of(answer)), rng))
+}
+
+fn weakness(pool: &[&Pokemon], count: usize, rng: &mut Rng) -> Option<Question> {
+    let pokemon = *rng.choose(pool)?;
+    // Las opciones incorrectas son neutras o poco eficaces
+    let (weak, mut others): (Vec<PokemonType>, Vec<PokemonType>) = PokemonType::ALL
+        .into_iter()
+        .partition(|t| t.effectiveness_against(&pokemon.types) > 1.0);
+    let answer = *rng.choose(&weak)?;
+    if others.len() < count - 1 {
+        return None;
+    }
+    rng.shuffle(&mut others);
+    let distractors = others.into_iter().take(count - 1).map(|t| Choice::Types(vec![t])).collect();
+    Some(shuffle_in(Prompt::Weakness(PokemonKey::of(pokemon)), distractors, Choice::Types(vec![answer]), rng))
+}
+
+pub fn generate_question(database: &Database, settings: &QuizSettings, kind: QuizKind, rng: &mut Rng) -> Option<Question> {
+    let pool = candidates(database, settings);
+    let count = settings.difficulty.choices();
+    for _ in 0..MAX_ATTEMPTS {
+        let question = match kind {
+            QuizKind::NameFromStats => name_from_stats(&pool, count, rng),
+            QuizKind::TypeFromName => type_from_name(&pool, count, rng),
+            QuizKind::HigherStat => higher_stat(&pool, count, rng),
+            QuizKind::Weakness => weakness(&pool, count, rng),
+        };
+        if question.is_some() {
+            return question;
+        }
+    }
+    None
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Score {
+    pub points: u32,
+    pub correct: u32,
+    pub answered: u32,
+    pub streak: u32,
+    pub best_streak: u32,
+}
+
+impl Score {
+    // x1 al empezar, x2 tras STREAK_STEP aciertos seguidos, x3 tras el doble...
+    pub fn multiplier(&self) -> u32 {
+        1 + self.streak / STREAK_STEP
+    }
+
+    // Devuelve los puntos ganados con la respuesta
+    pub fn record(&mut self, correct: bool, difficulty: Difficulty) -> u32 {
+        self.answered += 1;
+        if !correct {
+            self.streak = 0;
+            return 0;
+        }
+        let gained = difficulty.points() * self.multiplier();
+        self.points += gained;
+        self.correct += 1;
+        self.streak += 1;
+        self.best_streak = self.best_streak.max(self.streak);
+        gained
+    }
+}
+
+// Una ronda de ROUND_LENGTH preguntas; con la misma semilla y ajustes salen las mismas preguntas
+#[derive(Debug, Clone)]
+pub struct Quiz {
+    settings: QuizSettings,
+    seed: u64,
+    rng: Rng,
+    score: Score,
+    question: Option<Question>,
+    // Opción elegida en la pregunta actual
+    chosen: Option<usize>,
+}
+
+impl Quiz {
+    pub fn new(database: &Database, settings: QuizSettings, seed: u64) -> Result<Self, QuizError> {
+        let mut quiz = Self {
+            settings,
+            seed,
+            rng: Rng::new(seed),
+            score: Score::default(),
+            question: None,
+            chosen: None,
+        };
+        quiz.next_question(database)?;
+        Ok(quiz)
+    }
+
+    pub fn settings(&self) -> &QuizSettings {
+        &self.settings
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn score(&self) -> &Score {
+        &self.score
+    }
+
+    pub fn question(&self) -> Option<&Question> {
+        self.question.as_ref()
+    }
+
+    pub fn chosen(&self) -> Option<usize> {
+        self.chosen
+    }
+
+    // Número de la pregunta actual, empezando en 1
+    pub fn number(&self) -> u32 {
+        self.score.answered + u32::from(self.chosen.is_none())
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.score.answered >= ROUND_LENGTH
+    }
+
+    // Si un tipo de pregunta no es posible con estas generaciones se prueba con los demás
+    pub fn next_question(&mut self, database: &Database) -> Result<(), QuizError> {
+        let mut kinds: Vec<QuizKind> = self.settings.kinds.iter().copied().collect();
+        if kinds.is_empty() {
+            return Err(QuizError::NoKinds);
+        }
+        self.rng.shuffle(&mut kinds);
+        self.chosen = None;
+        self.question = kinds
+            .into_iter()
+            .find_map(|kind| generate_question(database, &self.settings, kind, &mut self.rng));
+        self.question.as_ref().map(|_| ()).ok_or(QuizError::NotEnoughCandidates)
+    }
+
+    // Responde la pregunta actual; devuelve los puntos ganados o None si ya estaba respondida
+    pub fn answer(&mut self, choice: usize) -> Option<u32> {
+        if self.chosen.is_some() || self.is_finished() {
+            return None;
+        }
+        let question = self.question.as_ref()?;
+        let correct = question.answer == choice;
+        self.chosen = Some(choice);
+        Some(self.score.record(correct, self.settings.difficulty))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HighScore {
+    pub difficulty: Difficulty,
+    pub points: u32,
+    pub correct: u32,
+    pub best_streak: u32,
+    pub seed: u64,
+    pub date: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct HighScoresFile {
+    version: u32,
+    scores: Vec<HighScore>,
+}
+
+// Mejores puntuaciones de cada dificultad, guardadas en la carpeta de datos
+#[derive(Debug, Default)]
+pub struct HighScoreStore {
+    scores: Vec<HighScore>,
+    path: Option<PathBuf>,
+}
+
+impl HighScoreStore {
+    pub fn path() -> Option<PathBuf> {
+        storage::data_file(HIGH_SCORES_FILE)
+    }
+
+    // Si el archivo no existe se empieza sin récords; si está dañado se avisa y no se sobrescribe
+    pub fn load() -> Self {
+        let Some(path) = Self::path() else {
+            return Self::default();
+        };
+        match storage::load_json::<HighScoresFile>(&path) {
+            Ok(file) => Self {
+                scores: file.map(|f| f.scores).unwrap_or_default(),
+                path: Some(path),
+            },
+            Err(e) => {
+                eprintln!("Error al leer {}: {}", path.display(), e);
+                Self::default()
+            }
+        }
+    }
+
+    pub fn save(&self) {
+        let Some(path) = &self.path else {
+            return;
+        };
+        let file = HighScoresFile {
+            version: CURRENT_VERSION,
+            scores: self.scores.clone(),
+        };
+        if let Err(e) = storage::save_json(path, &file) {
+            eprintln!("No se pudo guardar {}: {}", path.display(), e);
+        }
+    }
+
+    // De mayor a menor puntuación
+    pub fn for_difficulty(&self, difficulty: Difficulty) -> Vec<&HighScore> {
+        self.scores.iter().filter(|s| s.difficulty == difficulty).collect()
+    }
+
+    // Guarda el resultado de una ronda terminada; devuelve su puesto (desde 0) si entra en la tabla
+    pub fn record(&mut self, quiz: &Quiz) -> Option<usize> {
+        if quiz.score().points == 0 {
+            return None;
+        }
+        let difficulty = quiz.settings().difficulty;
+        let entry = HighScore {
+            difficulty,
+            points: quiz.score().points,
+            correct: quiz.score().correct,
+            best_streak: quiz.score().best_streak,
+            seed: quiz.seed(),
+            date: Date::today().to_string(),
+        };
+        // A igualdad de puntos se queda delante el récord más antiguo
+        let position = self
+            .for_difficulty(difficulty)
+            .iter()
+            .position(|s| s.points < entry.points)
+            .unwrap_or(self.for_difficulty(difficulty).len());
+        if position >= MAX_HIGH_SCORES {
+            return None;
+        }
+        self.scores.push(entry);
+        self.scores.sort_by_key(|s| (s.difficulty, std::cmp::Reverse(s.points)));
+        let mut kept = 0;
+        self.scores.retain(|s| {
+            if s.difficulty != difficulty {
+                return true;
+            }
+            kept += 1;
+            kept <= MAX_HIGH_SCORES
+        });
+        self.save();
+        Some(position)
+    }
+
+    pub fn clear(&mut self, difficulty: Difficulty) {
+        self.scores.retain(|s| s.difficulty != difficulty);
+        self.save();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn database() -> Database {
+        let mut database = Database::new();
+        database.load_from_csv().expect("CSV");
+        database
+    }
+
+    fn typing(types: &[PokemonType]) -> BTreeSet<PokemonType> {
+        types.iter().copied().collect()
+    }
+
+    // Responde siempre la primera opción y devuelve todas las preguntas de la ronda
+    fn play_round(database: &Database, settings: &QuizSettings, seed: u64) -> Vec<Question> {
+        let mut quiz = Quiz::new(database, settings.clone(), seed).expect("ronda");
+        let mut questions = Vec::new();
+        while !quiz.is_finished() {
+            questions.push(quiz.question().expect("pregunta").clone());
+            quiz.answer(0);
+            if !quiz.is_finished() {
+                quiz.next_question(database).expect("siguiente pregunta");
+            }
+        }
+        questions
+    }
+
+    #[test]
+    fn same_seed_gives_same_questions() {
+        let database = database();
+        for difficulty in Difficulty::ALL {
+            let settings = QuizSettings::with_difficulty(difficulty);
+            let first = play_round(&database, &settings, 42);
+            assert_eq!(first.len(), ROUND_LENGTH as usize);
+            assert_eq!(first, play_round(&database, &settings, 42));
+            assert_ne!(first, play_round(&database, &settings, 43));
+        }
+    }
+
+    #[test]
+    fn reversed_typing_is_not_a_wrong_choice() {
+        let database = database();
+        let geodude = database.find("geodude").unwrap();
+        let rhyhorn = database.find("rhyhorn").unwrap();
+        assert_eq!(typing(&geodude.types), typing(&rhyhorn.types));
+        assert_ne!(geodude.types, rhyhorn.types);
+        let pool = [geodude, rhyhorn, database.find("pikachu").unwrap(), database.find("charizard").unwrap()];
+
+        for seed in 0..100 {
+            let mut rng = Rng::new(seed);
+            let Some(question) = type_from_name(&pool, 3, &mut rng) else {
+                continue;
+            };
+            let typings: Vec<BTreeSet<PokemonType>> = question
+                .choices
+                .iter()
+                .map(|choice| match choice {
+                    Choice::Types(types) => typing(types),
+                    Choice::Pokemon(_) => panic!("opción inesperada"),
+                })
+                .collect();
+            let unique: BTreeSet<_> = typings.iter().collect();
+            assert_eq!(unique.len(), typings.len(), "semilla {}: {:?}", seed, question.choices);
+        }
+    }
+
+    #[test]
+    fn streak_raises_the_multiplier() {
+        let mut score = Score::default();
+        assert_eq!(score.multiplier(), 1);
+        let points = Difficulty::Normal.points();
+        for _ in 0..STREAK_STEP {
+            assert_eq!(score.record(true, Difficulty::Normal), points);
+        }
+        assert_eq!(score.multiplier(), 2);
+        assert_eq!(score.record(true, Difficulty::Normal), points * 2);
+
+        assert_eq!(score.record(false, Difficulty::Normal), 0);
+        assert_eq!(score.streak, 0);
+        assert_eq!(score.multiplier(), 1);
+        assert_eq!(score.best_streak, STREAK_STEP + 1);
+        assert_eq!(score.correct, STREAK_STEP + 1);
+        assert_eq!(score.answered, STREAK_STEP + 2);
+        assert_eq!(score.points, points * (STREAK_STEP + 2));
+    }
+}
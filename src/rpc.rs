@@ -0,0 +1,338 @@
+use crate::analytics::Metric;
+use crate::database::{Database, PokemonFilter};
+use crate::pokemon::{self, Nature, Pokemon, PokemonType, StatKind, Stats};
+use crate::team::{self, MAX_EV, MAX_EV_TOTAL, MAX_IV, MAX_TEAM_SIZE};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+// Códigos de error de la especificación JSON-RPC 2.0
+pub const PARSE_ERROR: i64 = -32700;
+pub const INVALID_REQUEST: i64 = -32600;
+pub const METHOD_NOT_FOUND: i64 = -32601;
+pub const INVALID_PARAMS: i64 = -32602;
+pub const INTERNAL_ERROR: i64 = -32603;
+// Propios de la Pokédex (rango reservado para la aplicación)
+pub const NOT_FOUND: i64 = -32001;
+
+pub const METHODS: [&str; 5] = ["lookup", "search", "matchups", "calculate_stats", "analyze_team"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RpcError {
+    pub code: i64,
+    pub message: String,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self { code, message: message.into() }
+    }
+
+    fn invalid_params(message: impl Into<String>) -> Self {
+        Self::new(INVALID_PARAMS, message)
+    }
+
+    fn to_json(&self) -> Value {
+        json!({ "code": self.code, "message": self.message })
+    }
+}
+
+impl fmt::Display for RpcError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({})", self.message, self.code)
+    }
+}
+
+impl std::error::Error for RpcError {}
+
+// Una petición o lote por línea; cada respuesta se escribe también en una sola línea
+pub fn serve(database: &Database, input: &mut dyn BufRead, output: &mut dyn Write) -> io::Result<()> {
+    let mut line = String::new();
+    loop {
+        line.clear();
+        if input.read_line(&mut line)? == 0 {
+            return Ok(());
+        }
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Some(response) = handle_line(database, &line) {
+            writeln!(output, "{}", response)?;
+            output.flush()?;
+        }
+    }
+}
+
+// None cuando no hay nada que responder (notificaciones)
+pub fn handle_line(database: &Database, line: &str) -> Option<String> {
+    let response = match serde_json::from_str::<Value>(line) {
+        Ok(message) => handle(database, message)?,
+        Err(e) => error_response(Value::Null, &RpcError::new(PARSE_ERROR, format!("JSON no válido: {}", e))),
+    };
+    Some(response.to_string())
+}
+
+pub fn handle(database: &Database, message: Value) -> Option<Value> {
+    match message {
+        Value::Array(batch) if batch.is_empty() => {
+            Some(error_response(Value::Null, &RpcError::new(INVALID_REQUEST, "lote vacío")))
+        }
+        Value::Array(batch) => {
+            let responses: Vec<Value> = batch.into_iter().filter_map(|request| handle_single(database, request)).collect();
+            (!responses.is_empty()).then_some(Value::Array(responses))
+        }
+        request => handle_single(database, request),
+    }
+}
+
+fn handle_single(database: &Database, request: Value) -> Option<Value> {
+    let Value::Object(request) = request else {
+        return Some(error_response(Value::Null, &RpcError::new(INVALID_REQUEST, "la petición debe ser un objeto")));
+    };
+    let id = request.get("id").cloned();
+    // Sin id es una notificación: se ejecuta pero no se responde
+    let respond = |result: Result<Value, RpcError>| {
+        let id = id.clone()?;
+        Some(match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "result": result, "id": id }),
+            Err(error) => error_response(id, &error),
+        })
+    };
+
+    if request.get("jsonrpc").and_then(Value::as_str) != Some("2.0") {
+        return Some(error_response(id.unwrap_or(Value::Null), &RpcError::new(INVALID_REQUEST, "falta \"jsonrpc\": \"2.0\"")));
+    }
+    if id.as_ref().is_some_and(|id| !(id.is_string() || id.is_number() || id.is_null())) {
+        return Some(error_response(Value::Null, &RpcError::new(INVALID_REQUEST, "el id debe ser una cadena o un número")));
+    }
+    let Some(method) = request.get("method").and_then(Value::as_str) else {
+        return Some(error_response(id.unwrap_or(Value::Null), &RpcError::new(INVALID_REQUEST, "falta el método")));
+    };
+    let params = match request.get("params") {
+        None => Value::Object(Default::default()),
+        Some(params @ Value::Object(_)) => params.clone(),
+        Some(_) => return respond(Err(RpcError::invalid_params("los parámetros deben ser un objeto con nombre"))),
+    };
+    respond(call(database, method, params))
+}
+
+fn error_response(id: Value, error: &RpcError) -> Value {
+    json!({ "jsonrpc": "2.0", "error": error.to_json(), "id": id })
+}
+
+pub fn call(database: &Database, method: &str, params: Value) -> Result<Value, RpcError> {
+    match method {
+        "lookup" => lookup(database, parse_params(params)?),
+        "search" => search(database, parse_params(params)?),
+        "matchups" => matchups(parse_params(params)?),
+        "calculate_stats" => calculate_stats(database, parse_params(params)?),
+        "analyze_team" => analyze_team(database, parse_params(params)?),
+        _ => Err(RpcError::new(
+            METHOD_NOT_FOUND,
+            format!("método desconocido: {} (disponibles: {})", method, METHODS.join(", ")),
+        )),
+    }
+}
+
+fn parse_params<T: DeserializeOwned>(params: Value) -> Result<T, RpcError> {
+    serde_json::from_value(params).map_err(|e| RpcError::invalid_params(e.to_string()))
+}
+
+fn to_value(value: impl serde::Serialize) -> Result<Value, RpcError> {
+    serde_json::to_value(value).map_err(|e| RpcError::new(INTERNAL_ERROR, e.to_string()))
+}
+
+fn type_param(name: &str) -> Result<PokemonType, RpcError> {
+    PokemonType::from_name(name).ok_or_else(|| RpcError::invalid_params(format!("tipo desconocido: {}", name)))
+}
+
+// Mismo criterio que `pokedex show`: número, nombre o nombre traducido; `form` elige una forma
+fn find<'a>(database: &'a Database, query: &str, form: Option<&str>) -> Result<&'a Pokemon, RpcError> {
+    let found = database
+        .find(query)
+        .ok_or_else(|| RpcError::new(NOT_FOUND, format!("no se encontró ningún Pokémon con «{}»", query)))?;
+    match form {
+        None => Ok(found),
+        Some(form) => database
+            .get_forms(found.id)
+            .into_iter()
+            .find(|p| p.form.as_deref().is_some_and(|f| f.eq_ignore_ascii_case(form)))
+            .ok_or_else(|| RpcError::new(NOT_FOUND, format!("{} no tiene la forma «{}»", found.name, form))),
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LookupParams {
+    query: String,
+    form: Option<String>,
+}
+
+fn lookup(database: &Database, params: LookupParams) -> Result<Value, RpcError> {
+    to_value(find(database, &params.query, params.form.as_deref())?)
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SearchParams {
+    #[serde(default)]
+    query: String,
+    #[serde(rename = "type")]
+    pokemon_type: Option<String>,
+    generation: Option<u8>,
+    #[serde(default)]
+    forms: bool,
+    limit: Option<usize>,
+}
+
+fn search(database: &Database, params: SearchParams) -> Result<Value, RpcError> {
+    let filter = PokemonFilter {
+        search: params.query,
+        pokemon_type: params.pokemon_type.as_deref().map(type_param).transpose()?,
+        generation: params.generation,
+        ..PokemonFilter::default()
+    };
+    let mut entries = if params.forms { database.filter_entries(&filter) } else { database.query(&filter) };
+    if let Some(limit) = params.limit {
+        entries.truncate(limit);
+    }
+    to_value(entries)
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MatchupParams {
+    types: Vec<String>,
+}
+
+// Multiplicadores que recibe una combinación de tipos defensora
+fn matchups(params: MatchupParams) -> Result<Value, RpcError> {
+    if params.types.is_empty() || params.types.len() > 2 {
+        return Err(RpcError::invalid_params("se esperan uno o dos tipos"));
+    }
+    let types = params.types.iter().map(|t| type_param(t)).collect::<Result<Vec<_>, _>>()?;
+    let defense: BTreeMap<PokemonType, f32> =
+        PokemonType::ALL.iter().map(|&t| (t, t.effectiveness_against(&types))).collect();
+    let group = |keep: fn(f32) -> bool| -> Vec<PokemonType> {
+        defense.iter().filter(|(_, &m)| keep(m)).map(|(&t, _)| t).collect()
+    };
+    Ok(json!({
+        "types": types,
+        "defense": defense,
+        "weaknesses": group(|m| m > 1.0),
+        "resistances": group(|m| m > 0.0 && m < 1.0),
+        "immunities": group(|m| m == 0.0),
+    }))
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StatParams {
+    pokemon: String,
+    form: Option<String>,
+    #[serde(default = "default_level")]
+    level: u8,
+    nature: Option<String>,
+    #[serde(default)]
+    ivs: BTreeMap<String, u8>,
+    #[serde(default)]
+    evs: BTreeMap<String, u8>,
+}
+
+fn default_level() -> u8 {
+    100
+}
+
+// {"hp": 31, "speed": 0} sobre un valor por defecto; las claves son las de `Stats`
+fn stat_spread(values: &BTreeMap<String, u8>, default: u8, max: u8, what: &str) -> Result<Stats, RpcError> {
+    let mut stats = Stats::uniform(default);
+    for (name, &value) in values {
+        let kind = StatKind::ALL
+            .into_iter()
+            .find(|kind| kind.key().trim_start_matches("stat.") == name)
+            .ok_or_else(|| RpcError::invalid_params(format!("estadística desconocida en {}: {}", what, name)))?;
+        if value > max {
+            return Err(RpcError::invalid_params(format!("{} de {} fuera de rango (máximo {})", what, name, max)));
+        }
+        stats.set(kind, value);
+    }
+    Ok(stats)
+}
+
+fn calculate_stats(database: &Database, params: StatParams) -> Result<Value, RpcError> {
+    let pokemon = find(database, &params.pokemon, params.form.as_deref())?;
+    if !(1..=100).contains(&params.level) {
+        return Err(RpcError::invalid_params("el nivel debe estar entre 1 y 100"));
+    }
+    let nature = params
+        .nature
+        .as_deref()
+        .map(|name| Nature::from_name(name).ok_or_else(|| RpcError::invalid_params(format!("naturaleza desconocida: {}", name))))
+        .transpose()?;
+    let ivs = stat_spread(&params.ivs, MAX_IV, MAX_IV, "ivs")?;
+    let evs = stat_spread(&params.evs, 0, MAX_EV, "evs")?;
+    let ev_total: u16 = StatKind::ALL.iter().map(|&kind| evs.get(kind) as u16).sum();
+    if ev_total > MAX_EV_TOTAL {
+        return Err(RpcError::invalid_params(format!("los EVs suman {} (máximo {})", ev_total, MAX_EV_TOTAL)));
+    }
+
+    let stats: BTreeMap<&str, u16> = StatKind::ALL
+        .into_iter()
+        .map(|kind| {
+            let value = pokemon::calculate_stat(kind, pokemon.stats.get(kind), ivs.get(kind), evs.get(kind), params.level, nature);
+            (kind.key().trim_start_matches("stat."), value)
+        })
+        .collect();
+    Ok(json!({
+        "pokemon": pokemon.display_name(),
+        "level": params.level,
+        "nature": nature,
+        "stats": stats,
+    }))
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum MemberParam {
+    Query(String),
+    Form { query: String, form: Option<String> },
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TeamParams {
+    members: Vec<MemberParam>,
+}
+
+fn analyze_team(database: &Database, params: TeamParams) -> Result<Value, RpcError> {
+    if params.members.is_empty() || params.members.len() > MAX_TEAM_SIZE {
+        return Err(RpcError::invalid_params(format!("un equipo tiene entre 1 y {} miembros", MAX_TEAM_SIZE)));
+    }
+    let members = params
+        .members
+        .iter()
+        .map(|member| match member {
+            MemberParam::Query(query) => find(database, query, None),
+            MemberParam::Form { query, form } => find(database, query, form.as_deref()),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let analysis = team::analyze(&members);
+
+    Ok(json!({
+        "members": members.iter().map(|p| p.display_name()).collect::<Vec<_>>(),
+        "risks": analysis.risks(),
+        "uncovered": analysis.uncovered(),
+        "defense": analysis.defense,
+        "offense": analysis.offense,
+        "stat_totals": by_metric_name(&analysis.stat_totals),
+        "stat_averages": by_metric_name(&analysis.stat_averages),
+    }))
+}
+
+// Las métricas no son claves JSON válidas; se usan sus nombres ("hp", "total"...)
+fn by_metric_name<T: Copy>(values: &BTreeMap<Metric, T>) -> BTreeMap<&'static str, T> {
+    values.iter().map(|(metric, &value)| (metric.key().trim_start_matches("stat."), value)).collect()
+}
@@ -0,0 +1,304 @@
+use crate::analytics::{self, Metric};
+use crate::database::{Database, PokemonFilter};
+use crate::pokemon::{Pokemon, PokemonType};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::net::SocketAddr;
+use tiny_http::{Header, Method, Request, Response};
+
+pub const DEFAULT_PER_PAGE: usize = 50;
+pub const MAX_PER_PAGE: usize = 500;
+
+// Respuesta ya serializada, separada de tiny_http para poder probar el enrutado sin red
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiResponse {
+    pub status: u16,
+    pub body: Value,
+}
+
+impl ApiResponse {
+    fn ok(body: impl Serialize) -> Self {
+        Self { status: 200, body: serde_json::to_value(body).unwrap_or(Value::Null) }
+    }
+
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        Self { status, body: json!({ "error": message.into() }) }
+    }
+}
+
+#[derive(Serialize)]
+struct Page<'a> {
+    page: usize,
+    per_page: usize,
+    total: usize,
+    items: Vec<&'a Pokemon>,
+}
+
+pub struct ApiServer {
+    server: tiny_http::Server,
+}
+
+impl ApiServer {
+    // "127.0.0.1:0" elige un puerto libre (útil en las pruebas)
+    pub fn bind(address: &str) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        Ok(Self { server: tiny_http::Server::http(address)? })
+    }
+
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        self.server.server_addr().to_ip()
+    }
+
+    // Atiende peticiones una a una hasta que se cierre el proceso
+    pub fn serve(&self, database: &Database) {
+        #[cfg(feature = "graphql")]
+        let schema = crate::graphql::build_schema(std::sync::Arc::new(database.clone()));
+        for request in self.server.incoming_requests() {
+            #[cfg(feature = "graphql")]
+            if request.url().split('?').next() == Some("/graphql") {
+                respond_graphql(&schema, request);
+                continue;
+            }
+            respond(database, request);
+        }
+    }
+}
+
+fn send(request: Request, response: ApiResponse) {
+    let content_type = Header::from_bytes("Content-Type", "application/json; charset=utf-8")
+        .expect("cabecera válida");
+    let body = serde_json::to_string(&response.body).unwrap_or_default();
+    let _ = request.respond(
+        Response::from_string(body)
+            .with_status_code(response.status)
+            .with_header(content_type),
+    );
+}
+
+fn respond(database: &Database, request: Request) {
+    let response = if *request.method() == Method::Get {
+        handle(database, request.url())
+    } else {
+        ApiResponse::error(405, "solo se admiten peticiones GET")
+    };
+    send(request, response);
+}
+
+// GET /graphql?query=... o POST /graphql con {"query", "variables", "operationName"}
+#[cfg(feature = "graphql")]
+fn respond_graphql(schema: &crate::graphql::PokedexSchema, mut request: Request) {
+    let params = parse_query(request.url().split_once('?').map_or("", |(_, query)| query));
+    let body = match request.method() {
+        Method::Get => Ok(json!({
+            "query": params.get("query"),
+            "variables": params.get("variables").and_then(|v| serde_json::from_str::<Value>(v).ok()),
+            "operationName": params.get("operationName"),
+        })),
+        Method::Post => {
+            let mut text = String::new();
+            match request.as_reader().read_to_string(&mut text) {
+                Ok(_) => serde_json::from_str::<Value>(&text).map_err(|e| e.to_string()),
+                Err(e) => Err(e.to_string()),
+            }
+        }
+        _ => Err("solo se admiten peticiones GET y POST".to_string()),
+    };
+    let response = match body {
+        Ok(body) => match body["query"].as_str() {
+            Some(query) => {
+                let variables = Some(body["variables"].clone()).filter(|v| !v.is_null());
+                let operation_name = body["operationName"].as_str().map(str::to_string);
+                ApiResponse::ok(crate::graphql::execute(schema, query, variables, operation_name))
+            }
+            None => ApiResponse::error(400, "falta la consulta (query)"),
+        },
+        Err(message) => ApiResponse::error(400, message),
+    };
+    send(request, response);
+}
+
+// Enruta una URL ("/pokemon/25?form=...") a su respuesta JSON
+pub fn handle(database: &Database, url: &str) -> ApiResponse {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let params = parse_query(query);
+    let segments: Vec<String> = path
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .map(percent_decode)
+        .collect();
+    let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+
+    let result = match segments.as_slice() {
+        [] => Ok(index()),
+        ["pokemon"] => list(database, &params, false),
+        ["pokemon", key] => pokemon(database, key, params.get("form").map(String::as_str)),
+        ["pokemon", key, "forms"] => forms(database, key),
+        ["search"] => list(database, &params, true),
+        ["types"] => Ok(ApiResponse::ok(PokemonType::ALL)),
+        ["types", name] => type_matchups(name),
+        ["matchups"] => matchups(&params),
+        ["stats"] => stats(database, &params),
+        _ => Err(ApiResponse::error(404, format!("ruta desconocida: {}", path))),
+    };
+    result.unwrap_or_else(|error| error)
+}
+
+fn index() -> ApiResponse {
+    #[allow(unused_mut)]
+    let mut endpoints = vec![
+        "/pokemon?page=&per_page=&forms=",
+        "/pokemon/{id|nombre}?form=",
+        "/pokemon/{id|nombre}/forms",
+        "/search?q=&type=&generation=&page=&per_page=&forms=",
+        "/types",
+        "/types/{tipo}",
+        "/matchups?types=fire,flying",
+        "/stats?type=&generation=&forms=&by=type|generation",
+    ];
+    #[cfg(feature = "graphql")]
+    endpoints.push("/graphql?query=");
+    ApiResponse::ok(json!({ "endpoints": endpoints }))
+}
+
+fn parse_query(query: &str) -> BTreeMap<String, String> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (percent_decode(key), percent_decode(value))
+        })
+        .collect()
+}
+
+// %XX y '+' como espacio; las secuencias inválidas se dejan tal cual
+fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' => match text.get(i + 1..i + 3).and_then(|hex| u8::from_str_radix(hex, 16).ok()) {
+                Some(byte) => {
+                    decoded.push(byte);
+                    i += 2;
+                }
+                None => decoded.push(b'%'),
+            },
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn number_param<T: std::str::FromStr>(params: &BTreeMap<String, String>, name: &str) -> Result<Option<T>, ApiResponse> {
+    match params.get(name) {
+        None => Ok(None),
+        Some(value) => value
+            .parse()
+            .map(Some)
+            .map_err(|_| ApiResponse::error(400, format!("parámetro {} no válido: {}", name, value))),
+    }
+}
+
+fn type_param(value: &str) -> Result<PokemonType, ApiResponse> {
+    PokemonType::from_name(value).ok_or_else(|| ApiResponse::error(400, format!("tipo desconocido: {}", value)))
+}
+
+// Mismos filtros que la barra de búsqueda de la interfaz
+fn filter_from(params: &BTreeMap<String, String>) -> Result<PokemonFilter, ApiResponse> {
+    Ok(PokemonFilter {
+        search: params.get("q").cloned().unwrap_or_default(),
+        pokemon_type: params.get("type").map(|t| type_param(t)).transpose()?,
+        generation: number_param(params, "generation")?,
+        ..PokemonFilter::default()
+    })
+}
+
+// Una entrada por especie, o todas las formas con forms=true; igual en /pokemon, /search y /stats
+fn select<'a>(database: &'a Database, params: &BTreeMap<String, String>, filter: &PokemonFilter) -> Vec<&'a Pokemon> {
+    let forms = params.get("forms").is_some_and(|v| v == "true" || v == "1");
+    if forms {
+        database.filter_entries(filter)
+    } else {
+        database.query(filter)
+    }
+}
+
+fn list(database: &Database, params: &BTreeMap<String, String>, search: bool) -> Result<ApiResponse, ApiResponse> {
+    let filter = if search { filter_from(params)? } else { PokemonFilter::default() };
+    let entries = select(database, params, &filter);
+
+    let page: usize = number_param(params, "page")?.unwrap_or(1).max(1);
+    let per_page = number_param(params, "per_page")?.unwrap_or(DEFAULT_PER_PAGE).clamp(1, MAX_PER_PAGE);
+    // Una página absurda no debe tumbar el servidor (solo atiende una petición a la vez)
+    let offset = (page - 1)
+        .checked_mul(per_page)
+        .ok_or_else(|| ApiResponse::error(400, format!("parámetro page no válido: {}", page)))?;
+    let items = entries.iter().skip(offset).take(per_page).copied().collect();
+    Ok(ApiResponse::ok(Page { page, per_page, total: entries.len(), items }))
+}
+
+fn find<'a>(database: &'a Database, key: &str) -> Result<&'a Pokemon, ApiResponse> {
+    database
+        .find(key)
+        .ok_or_else(|| ApiResponse::error(404, format!("no se encontró ningún Pokémon con «{}»", key)))
+}
+
+fn pokemon(database: &Database, key: &str, form: Option<&str>) -> Result<ApiResponse, ApiResponse> {
+    let found = find(database, key)?;
+    match form {
+        None => Ok(ApiResponse::ok(found)),
+        Some(form) => database
+            .get_forms(found.id)
+            .into_iter()
+            .find(|p| p.form.as_deref().is_some_and(|f| f.eq_ignore_ascii_case(form)))
+            .map(ApiResponse::ok)
+            .ok_or_else(|| ApiResponse::error(404, format!("{} no tiene la forma «{}»", found.name, form))),
+    }
+}
+
+fn forms(database: &Database, key: &str) -> Result<ApiResponse, ApiResponse> {
+    let found = find(database, key)?;
+    Ok(ApiResponse::ok(database.get_forms(found.id)))
+}
+
+fn type_matchups(name: &str) -> Result<ApiResponse, ApiResponse> {
+    let attacking = type_param(name)?;
+    let offense: BTreeMap<PokemonType, f32> = PokemonType::ALL.iter().map(|&t| (t, attacking.effectiveness(t))).collect();
+    let defense: BTreeMap<PokemonType, f32> = PokemonType::ALL.iter().map(|&t| (t, t.effectiveness(attacking))).collect();
+    Ok(ApiResponse::ok(json!({ "type": attacking, "offense": offense, "defense": defense })))
+}
+
+// Multiplicadores que recibe una combinación de tipos defensora
+fn matchups(params: &BTreeMap<String, String>) -> Result<ApiResponse, ApiResponse> {
+    let types = params
+        .get("types")
+        .ok_or_else(|| ApiResponse::error(400, "falta el parámetro types"))?
+        .split(',')
+        .map(type_param)
+        .collect::<Result<Vec<_>, _>>()?;
+    let defense: BTreeMap<PokemonType, f32> =
+        PokemonType::ALL.iter().map(|&t| (t, t.effectiveness_against(&types))).collect();
+    Ok(ApiResponse::ok(json!({ "types": types, "defense": defense })))
+}
+
+// Resumen de cada estadística; con by=type o by=generation, agrupado
+fn stats(database: &Database, params: &BTreeMap<String, String>) -> Result<ApiResponse, ApiResponse> {
+    let entries = select(database, params, &filter_from(params)?);
+    let mut metrics = serde_json::Map::new();
+    for metric in Metric::ALL {
+        let name = metric.key().trim_start_matches("stat.").to_string();
+        let value = match params.get("by").map(String::as_str) {
+            None => serde_json::to_value(analytics::summarize(&analytics::metric_values(&entries, metric))),
+            Some("type") => serde_json::to_value(analytics::summary_by_type(&entries, metric)),
+            Some("generation") => serde_json::to_value(analytics::summary_by_generation(&entries, metric)),
+            Some(other) => return Err(ApiResponse::error(400, format!("agrupación desconocida: {}", other))),
+        };
+        metrics.insert(name, value.unwrap_or(Value::Null));
+    }
+    Ok(ApiResponse::ok(json!({ "count": entries.len(), "metrics": metrics })))
+}
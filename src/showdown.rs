@@ -0,0 +1,573 @@
+use crate::database::Database;
+use crate::pokemon::{Nature, Pokemon, StatKind, Stats};
+use crate::team::{Gender, PokemonSet, TeamMember, MAX_EV, MAX_EV_TOTAL, MAX_IV, MAX_MOVES, MAX_TEAM_SIZE};
+use std::fmt;
+
+// Palabras de la forma que Showdown no incluye en el nombre ("Attack Forme" -> "Deoxys-Attack")
+const FORM_NOISE: [&str; 5] = ["Forme", "Form", "Cloak", "Mode", "Size"];
+// Formas que en Showdown se escriben solo con el nombre de la especie
+const DEFAULT_FORMS: [&str; 12] = [
+    "Normal", "Plant", "Altered", "Land", "Standard", "Incarnate",
+    "Ordinary", "Aria", "Shield", "Average", "Confined", "Male",
+];
+// Campos válidos del formato que esta aplicación no guarda
+const IGNORED_FIELDS: [&str; 7] = [
+    "Happiness", "Tera Type", "Gigantamax", "Dynamax Level", "Hidden Power", "Pokeball", "Ball",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    // Se importó, pero con algún dato descartado o dudoso
+    Warning,
+    // Un Pokémon entero no se pudo importar
+    Error,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DiagnosticKind {
+    UnknownSpecies(String),
+    TooManyMembers(String),
+    UnknownNature(String),
+    UnknownStat(String),
+    InvalidValue(String),
+    EvTotalOverLimit(u16),
+    TooManyMoves(String),
+    IgnoredField(String),
+    UnknownLine(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    // Número de línea empezando en 1
+    pub line: usize,
+    pub kind: DiagnosticKind,
+}
+
+impl Diagnostic {
+    pub fn severity(&self) -> Severity {
+        match self.kind {
+            DiagnosticKind::UnknownSpecies(_) | DiagnosticKind::TooManyMembers(_) => Severity::Error,
+            _ => Severity::Warning,
+        }
+    }
+
+    // Clave del texto traducido en los catálogos de i18n
+    pub fn key(&self) -> &'static str {
+        match self.kind {
+            DiagnosticKind::UnknownSpecies(_) => "showdown.unknown_species",
+            DiagnosticKind::TooManyMembers(_) => "showdown.too_many_members",
+            DiagnosticKind::UnknownNature(_) => "showdown.unknown_nature",
+            DiagnosticKind::UnknownStat(_) => "showdown.unknown_stat",
+            DiagnosticKind::InvalidValue(_) => "showdown.invalid_value",
+            DiagnosticKind::EvTotalOverLimit(_) => "showdown.ev_total",
+            DiagnosticKind::TooManyMoves(_) => "showdown.too_many_moves",
+            DiagnosticKind::IgnoredField(_) => "showdown.ignored_field",
+            DiagnosticKind::UnknownLine(_) => "showdown.unknown_line",
+        }
+    }
+
+    // Valor que se sustituye en {value} del texto traducido
+    pub fn value(&self) -> String {
+        match &self.kind {
+            DiagnosticKind::EvTotalOverLimit(total) => total.to_string(),
+            DiagnosticKind::UnknownSpecies(text)
+            | DiagnosticKind::TooManyMembers(text)
+            | DiagnosticKind::UnknownNature(text)
+            | DiagnosticKind::UnknownStat(text)
+            | DiagnosticKind::InvalidValue(text)
+            | DiagnosticKind::TooManyMoves(text)
+            | DiagnosticKind::IgnoredField(text)
+            | DiagnosticKind::UnknownLine(text) => text.clone(),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = match &self.kind {
+            DiagnosticKind::UnknownSpecies(name) => format!("especie desconocida \"{}\"", name),
+            DiagnosticKind::TooManyMembers(name) => {
+                format!("el equipo ya tiene {} Pokémon, se descarta \"{}\"", MAX_TEAM_SIZE, name)
+            }
+            DiagnosticKind::UnknownNature(name) => format!("naturaleza desconocida \"{}\"", name),
+            DiagnosticKind::UnknownStat(name) => format!("estadística desconocida \"{}\"", name),
+            DiagnosticKind::InvalidValue(text) => format!("valor no válido \"{}\"", text),
+            DiagnosticKind::EvTotalOverLimit(total) => {
+                format!("los EVs suman {} (máximo {})", total, MAX_EV_TOTAL)
+            }
+            DiagnosticKind::TooManyMoves(name) => {
+                format!("más de {} movimientos, se descarta \"{}\"", MAX_MOVES, name)
+            }
+            DiagnosticKind::IgnoredField(name) => format!("campo \"{}\" no soportado, se ignora", name),
+            DiagnosticKind::UnknownLine(text) => format!("línea no reconocida \"{}\"", text),
+        };
+        write!(f, "línea {}: {}", self.line, message)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Import {
+    // Nombre de la cabecera "=== [formato] Nombre ===" si la hay
+    pub name: Option<String>,
+    pub members: Vec<TeamMember>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+// Identificador normalizado como el de Showdown: "Mr. Mime" -> "mrmime", "Nidoran♀" -> "nidoranf"
+fn to_id(name: &str) -> String {
+    name.chars()
+        .flat_map(|c| match c {
+            '♀' => Some('f'),
+            '♂' => Some('m'),
+            'é' | 'É' => Some('e'),
+            c if c.is_ascii_alphanumeric() => Some(c.to_ascii_lowercase()),
+            _ => None,
+        })
+        .collect()
+}
+
+// Nombre de la especie/forma tal y como lo escribe Showdown: "Charizard-Mega-X", "Rotom-Wash"
+pub fn showdown_name(pokemon: &Pokemon) -> String {
+    let base = pokemon.name.replace('♀', "-F").replace('♂', "-M");
+    let Some(form) = &pokemon.form else {
+        return base;
+    };
+    let words: Vec<&str> = form
+        .split_whitespace()
+        .filter(|word| *word != pokemon.name && !FORM_NOISE.contains(word))
+        .map(|word| if word == "Female" { "F" } else { word })
+        .collect();
+    match words.as_slice() {
+        [] => base,
+        [word] if DEFAULT_FORMS.contains(word) => base,
+        _ => format!("{}-{}", base, words.join("-")),
+    }
+}
+
+// Busca por nombre de Showdown, luego por nombre para mostrar y por último por especie
+pub fn find_species<'a>(database: &'a Database, name: &str) -> Option<&'a Pokemon> {
+    let id = to_id(name);
+    if id.is_empty() {
+        return None;
+    }
+    let all = database.get_all_pokemon();
+    all.iter()
+        .find(|p| to_id(&showdown_name(p)) == id)
+        .or_else(|| all.iter().find(|p| to_id(&p.display_name()) == id))
+        .or_else(|| all.iter().find(|p| to_id(&p.name) == id))
+        .copied()
+}
+
+fn stat_abbreviation(kind: StatKind) -> &'static str {
+    match kind {
+        StatKind::Hp => "HP",
+        StatKind::Attack => "Atk",
+        StatKind::Defense => "Def",
+        StatKind::SpecialAttack => "SpA",
+        StatKind::SpecialDefense => "SpD",
+        StatKind::Speed => "Spe",
+    }
+}
+
+fn parse_stat_kind(text: &str) -> Option<StatKind> {
+    StatKind::ALL
+        .iter()
+        .find(|kind| stat_abbreviation(**kind).eq_ignore_ascii_case(text))
+        .copied()
+}
+
+// "252 Atk / 4 SpD / 252 Spe" sobre un punto de partida (0 para EVs, 31 para IVs)
+fn parse_spread(
+    text: &str,
+    start: Stats,
+    max: u8,
+    line: usize,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Stats {
+    let mut stats = start;
+    for part in text.split('/').map(str::trim).filter(|p| !p.is_empty()) {
+        let Some((value, stat)) = part.split_once(' ') else {
+            diagnostics.push(Diagnostic { line, kind: DiagnosticKind::InvalidValue(part.to_string()) });
+            continue;
+        };
+        let Some(kind) = parse_stat_kind(stat.trim()) else {
+            diagnostics.push(Diagnostic { line, kind: DiagnosticKind::UnknownStat(stat.trim().to_string()) });
+            continue;
+        };
+        match value.trim().parse::<u8>() {
+            Ok(value) if value <= max => stats.set(kind, value),
+            _ => diagnostics.push(Diagnostic { line, kind: DiagnosticKind::InvalidValue(part.to_string()) }),
+        }
+    }
+    stats
+}
+
+// Primera línea de un set: "Apodo (Especie) (M) @ Objeto"
+fn parse_header(line: &str) -> (Option<String>, String, Option<Gender>, Option<String>) {
+    let (mut left, item) = match line.rsplit_once(" @ ") {
+        Some((left, item)) => (left.trim(), Some(item.trim().to_string())),
+        None => (line.trim(), None),
+    };
+
+    let mut gender = None;
+    if let Some(rest) = left.strip_suffix(" (M)") {
+        gender = Some(Gender::Male);
+        left = rest.trim_end();
+    } else if let Some(rest) = left.strip_suffix(" (F)") {
+        gender = Some(Gender::Female);
+        left = rest.trim_end();
+    }
+
+    if let (Some(open), true) = (left.rfind(" ("), left.ends_with(')')) {
+        let nickname = left[..open].trim().to_string();
+        let species = left[open + 2..left.len() - 1].trim().to_string();
+        return (Some(nickname), species, gender, item);
+    }
+    (None, left.to_string(), gender, item)
+}
+
+pub fn parse_team(text: &str, database: &Database) -> Import {
+    let mut import = Import::default();
+    let mut current: Option<TeamMember> = None;
+    // Si la especie del bloque no se reconoce, se saltan sus líneas hasta el siguiente
+    let mut skipping = false;
+
+    for (index, raw) in text.lines().enumerate() {
+        let line_number = index + 1;
+        let line = raw.trim();
+
+        if line.is_empty() {
+            if let Some(member) = current.take() {
+                push_member(&mut import, member, line_number - 1, database);
+            }
+            skipping = false;
+            continue;
+        }
+
+        if let Some(header) = line.strip_prefix("===").and_then(|l| l.strip_suffix("===")) {
+            // "=== [gen6ou] Nombre ===": el formato entre corchetes no se guarda
+            let header = header.trim();
+            let name = match header.split_once(']') {
+                Some((_, name)) if header.starts_with('[') => name.trim(),
+                _ => header,
+            };
+            import.name.get_or_insert_with(|| name.to_string());
+            continue;
+        }
+
+        if skipping {
+            continue;
+        }
+
+        let Some(member) = current.as_mut() else {
+            let (nickname, species, gender, item) = parse_header(line);
+            match find_species(database, &species) {
+                Some(pokemon) => {
+                    let mut member = TeamMember::from_pokemon(pokemon);
+                    member.set = PokemonSet { nickname, gender, item, ..PokemonSet::default() };
+                    current = Some(member);
+                }
+                None => {
+                    import.diagnostics.push(Diagnostic {
+                        line: line_number,
+                        kind: DiagnosticKind::UnknownSpecies(species),
+                    });
+                    skipping = true;
+                }
+            }
+            continue;
+        };
+        parse_set_line(line, line_number, &mut member.set, &mut import.diagnostics);
+    }
+
+    if let Some(member) = current.take() {
+        push_member(&mut import, member, text.lines().count(), database);
+    }
+    import
+}
+
+fn push_member(import: &mut Import, member: TeamMember, line: usize, database: &Database) {
+    if import.members.len() >= MAX_TEAM_SIZE {
+        let name = database
+            .get_pokemon_form(member.id, member.form.as_deref())
+            .map(showdown_name)
+            .unwrap_or_default();
+        import.diagnostics.push(Diagnostic { line, kind: DiagnosticKind::TooManyMembers(name) });
+        return;
+    }
+
+    let ev_total: u16 = StatKind::ALL.iter().map(|&k| member.set.evs.get(k) as u16).sum();
+    if ev_total > MAX_EV_TOTAL {
+        import.diagnostics.push(Diagnostic { line, kind: DiagnosticKind::EvTotalOverLimit(ev_total) });
+    }
+    import.members.push(member);
+}
+
+fn parse_set_line(line: &str, line_number: usize, set: &mut PokemonSet, diagnostics: &mut Vec<Diagnostic>) {
+    let diagnostic = |kind| Diagnostic { line: line_number, kind };
+
+    // Movimientos: "- Flamethrower" (los exportadores antiguos usan "~")
+    if let Some(name) = line.strip_prefix('-').or_else(|| line.strip_prefix('~')) {
+        let name = name.trim().to_string();
+        if set.moves.len() >= MAX_MOVES {
+            diagnostics.push(diagnostic(DiagnosticKind::TooManyMoves(name)));
+        } else {
+            set.moves.push(name);
+        }
+        return;
+    }
+
+    if let Some(nature) = line.strip_suffix(" Nature") {
+        match Nature::from_name(nature) {
+            Some(nature) => set.nature = Some(nature),
+            None => diagnostics.push(diagnostic(DiagnosticKind::UnknownNature(nature.trim().to_string()))),
+        }
+        return;
+    }
+
+    let Some((field, value)) = line.split_once(':') else {
+        diagnostics.push(diagnostic(DiagnosticKind::UnknownLine(line.to_string())));
+        return;
+    };
+    let (field, value) = (field.trim(), value.trim());
+    match field {
+        "Ability" => set.ability = Some(value.to_string()),
+        "Level" => match value.parse::<u8>() {
+            Ok(level) if (1..=100).contains(&level) => set.level = level,
+            _ => diagnostics.push(diagnostic(DiagnosticKind::InvalidValue(value.to_string()))),
+        },
+        "Shiny" => set.shiny = value.eq_ignore_ascii_case("yes"),
+        "EVs" => set.evs = parse_spread(value, Stats::default(), MAX_EV, line_number, diagnostics),
+        "IVs" => set.ivs = parse_spread(value, Stats::uniform(MAX_IV), MAX_IV, line_number, diagnostics),
+        field if IGNORED_FIELDS.contains(&field) => {
+            diagnostics.push(diagnostic(DiagnosticKind::IgnoredField(field.to_string())))
+        }
+        _ => diagnostics.push(diagnostic(DiagnosticKind::UnknownLine(line.to_string()))),
+    }
+}
+
+// Solo se escriben las estadísticas distintas del valor por defecto
+fn format_spread(stats: &Stats, default: u8) -> Option<String> {
+    let parts: Vec<String> = StatKind::ALL
+        .iter()
+        .filter(|&&kind| stats.get(kind) != default)
+        .map(|&kind| format!("{} {}", stats.get(kind), stat_abbreviation(kind)))
+        .collect();
+    (!parts.is_empty()).then(|| parts.join(" / "))
+}
+
+pub fn export_member(member: &TeamMember, database: &Database) -> Option<String> {
+    let pokemon = database.get_pokemon_form(member.id, member.form.as_deref())?;
+    let set = &member.set;
+
+    let mut header = match &set.nickname {
+        Some(nickname) => format!("{} ({})", nickname, showdown_name(pokemon)),
+        None => showdown_name(pokemon),
+    };
+    match set.gender {
+        Some(Gender::Male) => header.push_str(" (M)"),
+        Some(Gender::Female) => header.push_str(" (F)"),
+        None => {}
+    }
+    if let Some(item) = &set.item {
+        header.push_str(&format!(" @ {}", item));
+    }
+
+    let mut lines = vec![header];
+    if let Some(ability) = &set.ability {
+        lines.push(format!("Ability: {}", ability));
+    }
+    if set.level != 100 {
+        lines.push(format!("Level: {}", set.level));
+    }
+    if set.shiny {
+        lines.push("Shiny: Yes".to_string());
+    }
+    if let Some(evs) = format_spread(&set.evs, 0) {
+        lines.push(format!("EVs: {}", evs));
+    }
+    if let Some(nature) = set.nature {
+        lines.push(format!("{} Nature", nature));
+    }
+    if let Some(ivs) = format_spread(&set.ivs, MAX_IV) {
+        lines.push(format!("IVs: {}", ivs));
+    }
+    lines.extend(set.moves.iter().map(|name| format!("- {}", name)));
+    Some(lines.join("\n"))
+}
+
+pub fn export_team(members: &[TeamMember], database: &Database) -> String {
+    let sets: Vec<String> = members
+        .iter()
+        .filter_map(|member| export_member(member, database))
+        .collect();
+    sets.join("\n\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Equipo tal y como lo exporta Showdown, con el orden de campos que usa export_member
+    const PASTE: &str = "\
+Sparky (Rotom-Wash) @ Leftovers
+Ability: Levitate
+EVs: 248 HP / 8 SpA / 252 SpD
+Calm Nature
+IVs: 0 Atk
+- Hydro Pump
+- Volt Switch
+- Will-O-Wisp
+- Pain Split
+
+Charizard-Mega-X (M) @ Charizardite X
+Ability: Tough Claws
+EVs: 252 Atk / 4 SpD / 252 Spe
+Jolly Nature
+- Dragon Dance
+- Flare Blitz
+- Outrage
+- Earthquake
+
+Garchomp (F) @ Rocky Helmet
+Ability: Rough Skin
+EVs: 252 HP / 4 Atk / 252 Spe
+Jolly Nature
+- Earthquake
+- Dragon Tail
+- Stealth Rock
+- Fire Blast
+
+Ferrothorn @ Leftovers
+Ability: Iron Barbs
+EVs: 252 HP / 88 Def / 168 SpD
+Relaxed Nature
+IVs: 0 Spe
+- Spikes
+- Leech Seed
+- Gyro Ball
+- Power Whip
+
+Landorus-Therian @ Choice Scarf
+Ability: Intimidate
+EVs: 252 Atk / 4 SpD / 252 Spe
+Jolly Nature
+- Earthquake
+- U-turn
+- Stone Edge
+- Knock Off
+
+Azumarill @ Choice Band
+Ability: Huge Power
+Level: 50
+Shiny: Yes
+EVs: 252 HP / 252 Atk / 4 SpD
+Adamant Nature
+- Play Rough
+- Waterfall
+- Aqua Jet
+- Superpower
+";
+
+    fn database() -> Database {
+        let mut database = Database::new();
+        database.load_from_csv().expect("CSV");
+        database
+    }
+
+    #[test]
+    fn round_trip_of_a_full_team() {
+        let database = database();
+        let import = parse_team(&format!("=== [gen6ou] Lluvia ===\n\n{}", PASTE), &database);
+        assert_eq!(import.diagnostics, []);
+        assert_eq!(import.name.as_deref(), Some("Lluvia"));
+        assert_eq!(import.members.len(), MAX_TEAM_SIZE);
+
+        let exported = export_team(&import.members, &database);
+        assert_eq!(exported, PASTE);
+        assert_eq!(parse_team(&exported, &database).members, import.members);
+    }
+
+    #[test]
+    fn header_with_nickname_gender_and_item() {
+        let database = database();
+        let import = parse_team(PASTE, &database);
+
+        let rotom = &import.members[0];
+        assert_eq!((rotom.id, rotom.form.as_deref()), (479, Some("Wash Rotom")));
+        assert_eq!(rotom.set.nickname.as_deref(), Some("Sparky"));
+        assert_eq!(rotom.set.gender, None);
+        assert_eq!(rotom.set.item.as_deref(), Some("Leftovers"));
+        assert_eq!(rotom.set.ivs.attack, 0);
+        assert_eq!(rotom.set.ivs.speed, MAX_IV);
+
+        let charizard = &import.members[1];
+        assert_eq!(charizard.form.as_deref(), Some("Mega Charizard X"));
+        assert_eq!(charizard.set.nickname, None);
+        assert_eq!(charizard.set.gender, Some(Gender::Male));
+        assert_eq!(charizard.set.item.as_deref(), Some("Charizardite X"));
+
+        assert_eq!(import.members[2].set.gender, Some(Gender::Female));
+        let azumarill = &import.members[5];
+        assert_eq!((azumarill.set.level, azumarill.set.shiny), (50, true));
+        assert_eq!(azumarill.set.nature, Some(Nature::Adamant));
+
+        let (nickname, species, gender, item) = parse_header("Mr. (Big) Chomp (Garchomp) (F)");
+        assert_eq!(nickname.as_deref(), Some("Mr. (Big) Chomp"));
+        assert_eq!((species.as_str(), gender, item), ("Garchomp", Some(Gender::Female), None));
+    }
+
+    #[test]
+    fn out_of_range_evs_and_ivs_are_reported() {
+        let database = database();
+        let text = "Garchomp\nEVs: 300 Atk / 252 Spe / 252 HP / 4 Foo\nIVs: 32 Spe / 0 Atk\n- Earthquake\n";
+        let import = parse_team(text, &database);
+        let set = &import.members[0].set;
+        // Los valores fuera de rango se descartan y se quedan en el valor por defecto
+        assert_eq!((set.evs.attack, set.evs.speed, set.evs.hp), (0, 252, 252));
+        assert_eq!((set.ivs.speed, set.ivs.attack), (MAX_IV, 0));
+        let kinds: Vec<&DiagnosticKind> = import.diagnostics.iter().map(|d| &d.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                &DiagnosticKind::InvalidValue("300 Atk".to_string()),
+                &DiagnosticKind::UnknownStat("Foo".to_string()),
+                &DiagnosticKind::InvalidValue("32 Spe".to_string()),
+            ]
+        );
+        assert!(import.diagnostics.iter().all(|d| d.severity() == Severity::Warning));
+
+        let over = parse_team("Garchomp\nEVs: 252 HP / 252 Atk / 252 Spe\n", &database);
+        assert_eq!(over.members.len(), 1);
+        assert_eq!(over.diagnostics, [Diagnostic { line: 2, kind: DiagnosticKind::EvTotalOverLimit(756) }]);
+    }
+
+    #[test]
+    fn unknown_species_skips_its_block() {
+        let database = database();
+        let text = "Missingno @ Leftovers\nAbility: Glitch\n- Water Gun\n\nPikachu\n- Thunderbolt\n";
+        let import = parse_team(text, &database);
+        assert_eq!(import.members.len(), 1);
+        assert_eq!(import.members[0].id, 25);
+        assert_eq!(
+            import.diagnostics,
+            [Diagnostic { line: 1, kind: DiagnosticKind::UnknownSpecies("Missingno".to_string()) }]
+        );
+        assert_eq!(import.diagnostics[0].severity(), Severity::Error);
+    }
+
+    #[test]
+    fn moves_are_kept_verbatim_and_limited() {
+        let database = database();
+        // No hay lista completa de movimientos: los desconocidos se guardan tal cual
+        let text = "Pikachu\n- Totally Made Up Move\n~ Thunderbolt\n- Surf\n- Quick Attack\n- Volt Tackle\n";
+        let import = parse_team(text, &database);
+        let set = &import.members[0].set;
+        assert_eq!(set.moves, ["Totally Made Up Move", "Thunderbolt", "Surf", "Quick Attack"]);
+        assert_eq!(
+            import.diagnostics,
+            [Diagnostic { line: 6, kind: DiagnosticKind::TooManyMoves("Volt Tackle".to_string()) }]
+        );
+        assert!(export_team(&import.members, &database).contains("- Totally Made Up Move\n"));
+    }
+}
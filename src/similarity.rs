@@ -0,0 +1,128 @@
+use crate::database::Database;
+use crate::pokemon::{Pokemon, PokemonType, StatKind};
+
+pub const DEFAULT_K: usize = 5;
+
+// Cómo se mide la cercanía entre dos vectores de estadísticas
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Distance {
+    // Compara la forma del reparto (proporciones), no la cantidad total
+    Cosine,
+    // Compara los valores absolutos
+    Euclidean,
+}
+
+impl Distance {
+    pub const ALL: [Distance; 2] = [Distance::Cosine, Distance::Euclidean];
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            Distance::Cosine => "similar.cosine",
+            Distance::Euclidean => "similar.euclidean",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypeConstraint {
+    Any,
+    // Al menos un tipo en común con el original
+    SharesType,
+    // Ningún tipo en común: "juega como X pero con otro tipo"
+    DifferentTypes,
+    HasType(PokemonType),
+}
+
+impl TypeConstraint {
+    pub fn allows(&self, original: &Pokemon, candidate: &Pokemon) -> bool {
+        let shares = candidate.types.iter().any(|t| original.types.contains(t));
+        match self {
+            TypeConstraint::Any => true,
+            TypeConstraint::SharesType => shares,
+            TypeConstraint::DifferentTypes => !shares,
+            TypeConstraint::HasType(pokemon_type) => candidate.types.contains(pokemon_type),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimilarityOptions {
+    pub distance: Distance,
+    // Peso de cada estadística en el orden de StatKind::ALL
+    pub weights: [f32; 6],
+    pub types: TypeConstraint,
+    pub k: usize,
+    // Saltar las otras formas de la misma especie (megas, formas regionales...)
+    pub exclude_same_species: bool,
+}
+
+impl Default for SimilarityOptions {
+    fn default() -> Self {
+        Self {
+            distance: Distance::Cosine,
+            weights: [1.0; 6],
+            types: TypeConstraint::Any,
+            k: DEFAULT_K,
+            exclude_same_species: true,
+        }
+    }
+}
+
+impl SimilarityOptions {
+    pub fn weight(&self, kind: StatKind) -> f32 {
+        let index = StatKind::ALL.iter().position(|&k| k == kind).unwrap_or(0);
+        self.weights[index]
+    }
+
+    pub fn set_weight(&mut self, kind: StatKind, weight: f32) {
+        if let Some(index) = StatKind::ALL.iter().position(|&k| k == kind) {
+            self.weights[index] = weight.max(0.0);
+        }
+    }
+
+    // Similitud del coseno (más alta = más parecido) o distancia euclídea (más baja = más parecido)
+    pub fn score(&self, a: &Pokemon, b: &Pokemon) -> f32 {
+        let pairs = StatKind::ALL
+            .iter()
+            .zip(self.weights)
+            .map(|(&kind, weight)| (a.stats.get(kind) as f32, b.stats.get(kind) as f32, weight));
+        match self.distance {
+            Distance::Cosine => {
+                let (mut dot, mut norm_a, mut norm_b) = (0.0, 0.0, 0.0);
+                for (x, y, w) in pairs {
+                    dot += w * x * y;
+                    norm_a += w * x * x;
+                    norm_b += w * y * y;
+                }
+                if norm_a == 0.0 || norm_b == 0.0 {
+                    return 0.0;
+                }
+                dot / (norm_a.sqrt() * norm_b.sqrt())
+            }
+            Distance::Euclidean => pairs.map(|(x, y, w)| w * (x - y) * (x - y)).sum::<f32>().sqrt(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Neighbor<'a> {
+    pub pokemon: &'a Pokemon,
+    pub score: f32,
+}
+
+// Los K Pokémon más parecidos a `original`, del más al menos parecido
+pub fn find_similar<'a>(database: &'a Database, original: &Pokemon, options: &SimilarityOptions) -> Vec<Neighbor<'a>> {
+    let mut neighbors: Vec<Neighbor> = database
+        .get_all_pokemon()
+        .into_iter()
+        .filter(|p| !(p.id == original.id && (options.exclude_same_species || p.form == original.form)))
+        .filter(|p| options.types.allows(original, p))
+        .map(|pokemon| Neighbor { pokemon, score: options.score(original, pokemon) })
+        .collect();
+    neighbors.sort_by(|a, b| match options.distance {
+        Distance::Cosine => b.score.total_cmp(&a.score),
+        Distance::Euclidean => a.score.total_cmp(&b.score),
+    });
+    neighbors.truncate(options.k);
+    neighbors
+}
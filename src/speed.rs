@@ -0,0 +1,123 @@
+use crate::database::Database;
+use crate::pokemon::{calculate_stat, Nature, Pokemon, StatKind};
+use crate::team::{MAX_EV, MAX_IV};
+use std::cmp::Ordering;
+
+pub const MIN_STAGE: i8 = -6;
+pub const MAX_STAGE: i8 = 6;
+
+// Inversiones habituales en velocidad
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Spread {
+    // 0 IV, 0 EV y naturaleza que baja la velocidad (para Espacio Raro)
+    Minimum,
+    // 31 IV, sin EV, naturaleza neutra
+    Uninvested,
+    // 31 IV, 252 EV, naturaleza neutra
+    MaxNeutral,
+    // 31 IV, 252 EV, naturaleza que sube la velocidad
+    MaxPositive,
+}
+
+impl Spread {
+    pub const ALL: [Spread; 4] = [Spread::Minimum, Spread::Uninvested, Spread::MaxNeutral, Spread::MaxPositive];
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            Spread::Minimum => "speed.spread.minimum",
+            Spread::Uninvested => "speed.spread.uninvested",
+            Spread::MaxNeutral => "speed.spread.max_neutral",
+            Spread::MaxPositive => "speed.spread.max_positive",
+        }
+    }
+
+    fn iv_ev_nature(&self) -> (u8, u8, Option<Nature>) {
+        match self {
+            Spread::Minimum => (0, 0, Some(Nature::Brave)),
+            Spread::Uninvested => (MAX_IV, 0, None),
+            Spread::MaxNeutral => (MAX_IV, MAX_EV, None),
+            Spread::MaxPositive => (MAX_IV, MAX_EV, Some(Nature::Jolly)),
+        }
+    }
+}
+
+// Cambios de nivel de velocidad en combate y Pañuelo Elegido
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Modifier {
+    pub stage: i8,
+    pub scarf: bool,
+}
+
+impl Modifier {
+    // Igual que los juegos: +1 = x1.5, +2 = x2, -1 = x2/3... redondeando hacia abajo en cada paso
+    pub fn apply(&self, speed: u16) -> u16 {
+        let stage = self.stage.clamp(MIN_STAGE, MAX_STAGE) as u32;
+        let speed = speed as u32;
+        let staged = if self.stage >= 0 {
+            speed * (2 + stage) / 2
+        } else {
+            speed * 2 / (2 + self.stage.unsigned_abs() as u32)
+        };
+        let scarfed = if self.scarf { staged * 3 / 2 } else { staged };
+        scarfed as u16
+    }
+}
+
+pub fn speed_at(pokemon: &Pokemon, level: u8, spread: Spread, modifier: Modifier) -> u16 {
+    let (iv, ev, nature) = spread.iv_ev_nature();
+    let raw = calculate_stat(StatKind::Speed, pokemon.stats.speed, iv, ev, level, nature);
+    modifier.apply(raw)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SpeedEntry<'a> {
+    pub pokemon: &'a Pokemon,
+    pub speed: u16,
+}
+
+// Todas las entradas de la base de datos ordenadas de más rápida a más lenta
+pub fn speed_tiers(database: &Database, level: u8, spread: Spread, modifier: Modifier) -> Vec<SpeedEntry<'_>> {
+    let mut entries: Vec<SpeedEntry> = database
+        .get_all_pokemon()
+        .into_iter()
+        .map(|pokemon| SpeedEntry { pokemon, speed: speed_at(pokemon, level, spread, modifier) })
+        .collect();
+    entries.sort_by(|a, b| b.speed.cmp(&a.speed).then(a.pokemon.id.cmp(&b.pokemon.id)));
+    entries
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SpeedComparison<'a> {
+    pub speed: u16,
+    // Pokémon a los que supera, con los que empata y que lo superan
+    pub outspeeds: Vec<SpeedEntry<'a>>,
+    pub ties: Vec<SpeedEntry<'a>>,
+    pub underspeeds: Vec<SpeedEntry<'a>>,
+}
+
+impl SpeedComparison<'_> {
+    // Porcentaje de rivales más lentos; los empates cuentan como la mitad
+    pub fn percentile(&self) -> f32 {
+        let total = self.outspeeds.len() + self.ties.len() + self.underspeeds.len();
+        if total == 0 {
+            return 100.0;
+        }
+        (self.outspeeds.len() as f32 + self.ties.len() as f32 / 2.0) * 100.0 / total as f32
+    }
+}
+
+// Compara un Pokémon contra todos los demás (`tiers` ya calculado para los rivales)
+pub fn compare<'a>(subject: &Pokemon, speed: u16, tiers: &[SpeedEntry<'a>]) -> SpeedComparison<'a> {
+    let mut comparison = SpeedComparison { speed, ..SpeedComparison::default() };
+    for entry in tiers {
+        if entry.pokemon.id == subject.id && entry.pokemon.form == subject.form {
+            continue;
+        }
+        match speed.cmp(&entry.speed) {
+            Ordering::Greater => comparison.outspeeds.push(*entry),
+            Ordering::Equal => comparison.ties.push(*entry),
+            Ordering::Less => comparison.underspeeds.push(*entry),
+        }
+    }
+    comparison
+}
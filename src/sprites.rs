@@ -0,0 +1,46 @@
+use crate::pokemon::Pokemon;
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_SPRITES_DIR: &str = "assets/sprites";
+pub const SPRITE_EXTENSIONS: [&str; 2] = ["png", "gif"];
+
+// Los archivos se buscan como:
+//   <dir>/<id>.png, <dir>/<id>-<forma>.png  (p. ej. 6-mega-charizard-x.png)
+//   <dir>/shiny/<id>.png para las versiones shiny
+// Si una forma no tiene sprite propio se usa el de la especie.
+
+// La carpeta se puede cambiar con la variable de entorno POKEDEX_SPRITES_DIR
+pub fn sprites_dir() -> PathBuf {
+    std::env::var_os("POKEDEX_SPRITES_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_SPRITES_DIR))
+}
+
+// "Mega Charizard X" -> "6-mega-charizard-x"
+pub fn sprite_stem(pokemon: &Pokemon) -> String {
+    match &pokemon.form {
+        Some(form) => {
+            let slug: String = form
+                .to_lowercase()
+                .chars()
+                .map(|c| if c.is_alphanumeric() { c } else { '-' })
+                .collect();
+            format!("{}-{}", pokemon.id, slug)
+        }
+        None => pokemon.id.to_string(),
+    }
+}
+
+pub fn find_sprite(dir: &Path, pokemon: &Pokemon, shiny: bool) -> Option<PathBuf> {
+    let dir = if shiny { dir.join("shiny") } else { dir.to_path_buf() };
+    let mut stems = vec![sprite_stem(pokemon)];
+    if pokemon.form.is_some() {
+        stems.push(pokemon.id.to_string());
+    }
+    stems.iter().find_map(|stem| {
+        SPRITE_EXTENSIONS
+            .iter()
+            .map(|ext| dir.join(format!("{}.{}", stem, ext)))
+            .find(|path| path.is_file())
+    })
+}
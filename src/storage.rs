@@ -0,0 +1,43 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const APP_DIR: &str = "pokedex";
+
+// Carpeta de configuración del usuario (por ejemplo ~/.config/pokedex)
+pub fn config_dir() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join(APP_DIR))
+}
+
+pub fn config_file(name: &str) -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join(name))
+}
+
+// Carpeta de datos del usuario (por ejemplo ~/.local/share/pokedex) para colecciones y partidas
+pub fn data_dir() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join(APP_DIR))
+}
+
+pub fn data_file(name: &str) -> Option<PathBuf> {
+    data_dir().map(|dir| dir.join(name))
+}
+
+// Lee un JSON del disco; devuelve None si el archivo no existe
+pub fn load_json<T: DeserializeOwned>(path: &Path) -> Result<Option<T>, Box<dyn Error>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = fs::read_to_string(path)?;
+    Ok(Some(serde_json::from_str(&content)?))
+}
+
+// Escribe un JSON creando las carpetas necesarias
+pub fn save_json<T: Serialize>(path: &Path, value: &T) -> Result<(), Box<dyn Error>> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, serde_json::to_string_pretty(value)?)?;
+    Ok(())
+}
@@ -0,0 +1,321 @@
+use crate::analytics::Metric;
+use crate::collections::{Collection, CollectionError, CollectionKind};
+use crate::database::Database;
+use crate::pokemon::{Nature, Pokemon, PokemonType, Stats};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+pub const MAX_TEAM_SIZE: usize = 6;
+
+pub const MAX_MOVES: usize = 4;
+pub const MAX_EV: u8 = 252;
+pub const MAX_EV_TOTAL: u16 = 510;
+pub const MAX_IV: u8 = 31;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Gender {
+    Male,
+    Female,
+}
+
+// Configuración competitiva de un miembro (lo que se comparte en formato Showdown)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PokemonSet {
+    pub nickname: Option<String>,
+    pub gender: Option<Gender>,
+    pub item: Option<String>,
+    pub ability: Option<String>,
+    pub level: u8,
+    pub shiny: bool,
+    pub evs: Stats,
+    pub ivs: Stats,
+    pub nature: Option<Nature>,
+    pub moves: Vec<String>,
+}
+
+impl Default for PokemonSet {
+    fn default() -> Self {
+        Self {
+            nickname: None,
+            gender: None,
+            item: None,
+            ability: None,
+            level: 100,
+            shiny: false,
+            evs: Stats::default(),
+            ivs: Stats::uniform(MAX_IV),
+            nature: None,
+            moves: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TeamMember {
+    pub id: u16,
+    // Forma concreta (megas, formes...); None es la forma base
+    #[serde(default)]
+    pub form: Option<String>,
+    #[serde(default)]
+    pub set: PokemonSet,
+}
+
+impl TeamMember {
+    pub fn from_pokemon(pokemon: &Pokemon) -> Self {
+        Self {
+            id: pokemon.id,
+            form: pokemon.form.clone(),
+            set: PokemonSet::default(),
+        }
+    }
+}
+
+// Equipo de hasta seis Pokémon: una colección de tipo Team, que es como se guarda
+#[derive(Debug, Clone, PartialEq)]
+pub struct Team(Collection);
+
+impl Team {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self(Collection::new(name, CollectionKind::Team))
+    }
+
+    // Falla si hay más miembros de los que caben
+    pub fn with_members(name: impl Into<String>, members: Vec<TeamMember>) -> Result<Self, CollectionError> {
+        let mut team = Self::new(name);
+        for member in members {
+            team.add(member)?;
+        }
+        Ok(team)
+    }
+
+    pub fn name(&self) -> &str {
+        &self.0.name
+    }
+
+    pub fn members(&self) -> &[TeamMember] {
+        &self.0.members
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.0.is_full()
+    }
+
+    pub fn add(&mut self, member: TeamMember) -> Result<(), CollectionError> {
+        self.0.add(member)
+    }
+
+    pub fn remove(&mut self, index: usize) -> Option<TeamMember> {
+        self.0.remove(index)
+    }
+
+    pub fn resolve<'a>(&self, database: &'a Database) -> Vec<&'a Pokemon> {
+        self.0.resolve(database)
+    }
+
+    pub fn analyze(&self, database: &Database) -> TeamAnalysis {
+        analyze(&self.resolve(database))
+    }
+}
+
+impl TryFrom<Collection> for Team {
+    type Error = CollectionError;
+
+    fn try_from(collection: Collection) -> Result<Self, Self::Error> {
+        match collection.kind {
+            CollectionKind::Team => Ok(Self(collection)),
+            kind => Err(CollectionError::NotATeam(kind)),
+        }
+    }
+}
+
+impl From<Team> for Collection {
+    fn from(team: Team) -> Self {
+        team.0
+    }
+}
+
+// Cómo recibe el equipo los ataques de un tipo
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TypeDefense {
+    pub attacking: PokemonType,
+    // Multiplicador para cada miembro, en orden
+    pub multipliers: Vec<f32>,
+    pub weak: usize,
+    pub resist: usize,
+    pub immune: usize,
+}
+
+impl TypeDefense {
+    // Debilidad compartida: la mitad del equipo (mínimo dos) es débil y nadie lo compensa
+    pub fn is_risk(&self) -> bool {
+        let team_size = self.multipliers.len();
+        self.weak >= 2 && self.weak * 2 >= team_size && self.weak > self.resist + self.immune
+    }
+}
+
+// El mejor multiplicador STAB del equipo contra un tipo defensor
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TypeCoverage {
+    pub defending: PokemonType,
+    pub best: f32,
+    // Tipos STAB del equipo que consiguen ese multiplicador
+    pub sources: Vec<PokemonType>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TeamAnalysis {
+    pub size: usize,
+    pub defense: Vec<TypeDefense>,
+    pub offense: Vec<TypeCoverage>,
+    pub stat_totals: BTreeMap<Metric, u32>,
+    pub stat_averages: BTreeMap<Metric, f32>,
+}
+
+impl TeamAnalysis {
+    pub fn risks(&self) -> Vec<PokemonType> {
+        self.defense
+            .iter()
+            .filter(|d| d.is_risk())
+            .map(|d| d.attacking)
+            .collect()
+    }
+
+    // Tipos que ningún ataque STAB del equipo golpea de forma eficaz
+    pub fn uncovered(&self) -> Vec<PokemonType> {
+        self.offense
+            .iter()
+            .filter(|c| c.best <= 1.0)
+            .map(|c| c.defending)
+            .collect()
+    }
+}
+
+pub fn analyze(members: &[&Pokemon]) -> TeamAnalysis {
+    let defense = PokemonType::ALL
+        .iter()
+        .map(|&attacking| {
+            let multipliers: Vec<f32> = members
+                .iter()
+                .map(|p| attacking.effectiveness_against(&p.types))
+                .collect();
+            TypeDefense {
+                attacking,
+                weak: multipliers.iter().filter(|&&m| m > 1.0).count(),
+                resist: multipliers.iter().filter(|&&m| m > 0.0 && m < 1.0).count(),
+                immune: multipliers.iter().filter(|&&m| m == 0.0).count(),
+                multipliers,
+            }
+        })
+        .collect();
+
+    let mut stab_types: Vec<PokemonType> = members.iter().flat_map(|p| p.types.iter().copied()).collect();
+    stab_types.sort();
+    stab_types.dedup();
+    let offense = PokemonType::ALL
+        .iter()
+        .map(|&defending| {
+            let best = stab_types
+                .iter()
+                .map(|t| t.effectiveness(defending))
+                .fold(0.0, f32::max);
+            let sources = stab_types
+                .iter()
+                .copied()
+                .filter(|t| t.effectiveness(defending) == best)
+                .collect();
+            TypeCoverage { defending, best, sources }
+        })
+        .collect();
+
+    let mut stat_totals = BTreeMap::new();
+    let mut stat_averages = BTreeMap::new();
+    for metric in Metric::ALL {
+        let total: u32 = members.iter().map(|p| metric.value(p) as u32).sum();
+        stat_totals.insert(metric, total);
+        if !members.is_empty() {
+            stat_averages.insert(metric, total as f32 / members.len() as f32);
+        }
+    }
+
+    TeamAnalysis {
+        size: members.len(),
+        defense,
+        offense,
+        stat_totals,
+        stat_averages,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use PokemonType::*;
+
+    fn database() -> Database {
+        let mut database = Database::new();
+        database.load_from_csv().expect("CSV");
+        database
+    }
+
+    fn member(id: u16) -> TeamMember {
+        TeamMember { id, form: None, set: PokemonSet::default() }
+    }
+
+    #[test]
+    fn analysis_of_a_small_team() {
+        let database = database();
+        // Charizard y Moltres (Fuego/Volador) y Venusaur (Planta/Veneno)
+        let team = Team::with_members("Prueba", vec![member(6), member(146), member(3)]).unwrap();
+        let analysis = team.analyze(&database);
+        assert_eq!(analysis.size, 3);
+
+        // Dos de tres débiles y solo Venusaur resiste: es un riesgo
+        let electric = analysis.defense.iter().find(|d| d.attacking == Electric).unwrap();
+        assert_eq!(electric.multipliers, vec![2.0, 2.0, 0.5]);
+        assert_eq!((electric.weak, electric.resist, electric.immune), (2, 1, 0));
+        // Ninguno es débil a Tierra y los voladores son inmunes
+        let ground = analysis.defense.iter().find(|d| d.attacking == Ground).unwrap();
+        assert_eq!((ground.weak, ground.immune), (0, 2));
+        // Solo Venusaur es débil al Psíquico: una debilidad aislada no es un riesgo
+        assert!(!analysis.defense.iter().find(|d| d.attacking == Psychic).unwrap().is_risk());
+        assert_eq!(analysis.risks(), vec![Water, Electric, Rock]);
+
+        // STAB: Fuego, Volador, Planta y Veneno
+        let grass = analysis.offense.iter().find(|c| c.defending == Grass).unwrap();
+        assert_eq!(grass.best, 2.0);
+        assert_eq!(grass.sources, vec![Fire, Poison, Flying]);
+        assert_eq!(
+            analysis.uncovered(),
+            vec![Normal, Fire, Electric, Poison, Flying, Psychic, Ghost, Dragon, Dark]
+        );
+
+        assert_eq!(analysis.stat_totals[&Metric::Total], 534 + 580 + 525);
+        assert_eq!(analysis.stat_averages[&Metric::Stat(crate::pokemon::StatKind::Speed)], 90.0);
+    }
+
+    #[test]
+    fn empty_team_has_no_risks_and_covers_nothing() {
+        let analysis = analyze(&[]);
+        assert!(analysis.risks().is_empty());
+        assert_eq!(analysis.uncovered(), PokemonType::ALL.to_vec());
+        assert!(analysis.offense.iter().all(|c| c.sources.is_empty()));
+        assert!(analysis.stat_averages.is_empty());
+    }
+
+    #[test]
+    fn team_round_trips_through_its_collection() {
+        let mut team = Team::new("Equipo");
+        team.add(member(25)).unwrap();
+        let collection = Collection::from(team.clone());
+        assert_eq!(collection.kind, CollectionKind::Team);
+        assert_eq!(Team::try_from(collection), Ok(team));
+
+        let storage_box = Collection::new("Caja", CollectionKind::Box);
+        assert_eq!(Team::try_from(storage_box), Err(CollectionError::NotATeam(CollectionKind::Box)));
+        assert_eq!(
+            Team::with_members("Siete", vec![member(25); MAX_TEAM_SIZE + 1]),
+            Err(CollectionError::Full(MAX_TEAM_SIZE))
+        );
+    }
+}
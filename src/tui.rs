@@ -0,0 +1,322 @@
+use crate::database::{Database, PokemonFilter};
+use crate::i18n::{I18n, Language};
+use crate::pokemon::{Pokemon, PokemonType, StatKind};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph};
+use ratatui::Frame;
+use std::io;
+
+// Cuántas filas salta Re Pág / Av Pág (igual que en la interfaz gráfica)
+const PAGE_SIZE: isize = 10;
+// Caracteres de bloque para dibujar las barras con precisión de 1/8 de celda
+const EIGHTHS: [char; 8] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+
+struct App<'a> {
+    database: &'a Database,
+    i18n: I18n,
+    filter: PokemonFilter,
+    results: Vec<&'a Pokemon>,
+    list: ListState,
+    // Forma elegida dentro de la especie seleccionada
+    form_index: usize,
+    searching: bool,
+    quit: bool,
+}
+
+impl<'a> App<'a> {
+    fn new(database: &'a Database) -> Self {
+        let mut app = Self {
+            database,
+            i18n: I18n::new(Language::Spanish),
+            filter: PokemonFilter::default(),
+            results: Vec::new(),
+            list: ListState::default(),
+            form_index: 0,
+            searching: false,
+            quit: false,
+        };
+        app.refresh();
+        app
+    }
+
+    // Vuelve a aplicar el filtro manteniendo la selección dentro de los resultados
+    fn refresh(&mut self) {
+        self.results = self.database.query(&self.filter);
+        let selected = self.list.selected().unwrap_or(0);
+        self.list.select((!self.results.is_empty()).then(|| selected.min(self.results.len() - 1)));
+        self.form_index = 0;
+    }
+
+    fn selected(&self) -> Option<&'a Pokemon> {
+        let species = self.results.get(self.list.selected()?)?;
+        let forms = self.database.get_forms(species.id);
+        forms.get(self.form_index).or(forms.first()).copied()
+    }
+
+    fn move_by(&mut self, delta: isize) {
+        if self.results.is_empty() {
+            return;
+        }
+        let current = self.list.selected().unwrap_or(0) as isize;
+        let last = self.results.len() as isize - 1;
+        self.list.select(Some((current + delta).clamp(0, last) as usize));
+        self.form_index = 0;
+    }
+
+    fn cycle_form(&mut self, delta: isize) {
+        let Some(species) = self.list.selected().and_then(|i| self.results.get(i)) else {
+            return;
+        };
+        let count = self.database.get_forms(species.id).len() as isize;
+        if count > 1 {
+            self.form_index = (self.form_index as isize + delta).rem_euclid(count) as usize;
+        }
+    }
+
+    // Todos los tipos -> Normal -> ... -> Hada -> todos los tipos
+    fn cycle_type(&mut self) {
+        self.filter.pokemon_type = match self.filter.pokemon_type {
+            None => Some(PokemonType::ALL[0]),
+            Some(current) => PokemonType::ALL
+                .iter()
+                .position(|&t| t == current)
+                .and_then(|i| PokemonType::ALL.get(i + 1))
+                .copied(),
+        };
+        self.refresh();
+    }
+
+    fn cycle_generation(&mut self) {
+        let generations = self.database.generations();
+        self.filter.generation = match self.filter.generation {
+            None => generations.first().copied(),
+            Some(current) => generations.iter().copied().find(|&g| g > current),
+        };
+        self.refresh();
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            self.quit = true;
+            return;
+        }
+        if self.searching {
+            match key.code {
+                KeyCode::Esc | KeyCode::Enter => self.searching = false,
+                KeyCode::Backspace => {
+                    self.filter.search.pop();
+                    self.refresh();
+                }
+                KeyCode::Char(c) => {
+                    self.filter.search.push(c);
+                    self.refresh();
+                }
+                KeyCode::Up => self.move_by(-1),
+                KeyCode::Down => self.move_by(1),
+                _ => {}
+            }
+            return;
+        }
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+            KeyCode::Char('/') | KeyCode::Char('s') => self.searching = true,
+            KeyCode::Up | KeyCode::Char('k') => self.move_by(-1),
+            KeyCode::Down | KeyCode::Char('j') => self.move_by(1),
+            KeyCode::PageUp => self.move_by(-PAGE_SIZE),
+            KeyCode::PageDown => self.move_by(PAGE_SIZE),
+            KeyCode::Home => self.move_by(-(self.results.len() as isize)),
+            KeyCode::End => self.move_by(self.results.len() as isize),
+            KeyCode::Left | KeyCode::Char('h') => self.cycle_form(-1),
+            KeyCode::Right | KeyCode::Char('l') => self.cycle_form(1),
+            KeyCode::Char('t') => self.cycle_type(),
+            KeyCode::Char('g') => self.cycle_generation(),
+            KeyCode::Char('i') => {
+                let next = match self.i18n.language() {
+                    Language::Spanish => Language::English,
+                    Language::English => Language::Spanish,
+                };
+                self.i18n.set_language(next);
+            }
+            KeyCode::Char('x') => {
+                self.filter = PokemonFilter::default();
+                self.refresh();
+            }
+            _ => {}
+        }
+    }
+}
+
+// Abre la interfaz de texto y la mantiene hasta que el usuario sale
+pub fn run(database: &Database) -> io::Result<()> {
+    let mut terminal = ratatui::init();
+    let mut app = App::new(database);
+    let result: io::Result<()> = (|| {
+        while !app.quit {
+            terminal.draw(|frame| draw(frame, &mut app))?;
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    app.handle_key(key);
+                }
+            }
+        }
+        Ok(())
+    })();
+    ratatui::restore();
+    result
+}
+
+fn type_style(pokemon_type: PokemonType) -> Style {
+    let (r, g, b) = pokemon_type.rgb();
+    Style::default().fg(Color::White).bg(Color::Rgb(r, g, b)).add_modifier(Modifier::BOLD)
+}
+
+fn type_badges(pokemon: &Pokemon, app: &App) -> Vec<Span<'static>> {
+    pokemon
+        .types
+        .iter()
+        .flat_map(|&t| {
+            let name = app.i18n.type_name(t, app.database.names());
+            [Span::styled(format!(" {} ", name), type_style(t)), Span::raw(" ")]
+        })
+        .collect()
+}
+
+// Barra de `width` celdas proporcional a value/255
+fn stat_bar(value: u8, width: usize) -> String {
+    let eighths = value as usize * width * 8 / 255;
+    let (full, remainder) = (eighths / 8, eighths % 8);
+    let mut bar = "█".repeat(full);
+    if remainder > 0 {
+        bar.push(EIGHTHS[remainder]);
+    }
+    bar
+}
+
+// Mismo degradado que las barras de la interfaz gráfica
+fn stat_color(value: u8) -> Color {
+    let progress = value as f32 / 255.0;
+    Color::Rgb(
+        (200.0 - progress * 100.0) as u8,
+        (180.0 + progress * 75.0) as u8,
+        (140.0 + progress * 40.0) as u8,
+    )
+}
+
+fn draw(frame: &mut Frame, app: &mut App) {
+    let [search_area, body_area, help_area] =
+        Layout::vertical([Constraint::Length(3), Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+    let [list_area, details_area] =
+        Layout::horizontal([Constraint::Percentage(40), Constraint::Percentage(60)]).areas(body_area);
+
+    draw_search(frame, app, search_area);
+    draw_list(frame, app, list_area);
+    draw_details(frame, app, details_area);
+    frame.render_widget(
+        Paragraph::new(app.i18n.tr("tui.help")).style(Style::default().fg(Color::DarkGray)),
+        help_area,
+    );
+}
+
+fn draw_search(frame: &mut Frame, app: &App, area: Rect) {
+    let type_label = match app.filter.pokemon_type {
+        Some(t) => app.i18n.type_name(t, app.database.names()),
+        None => app.i18n.tr("filter.all_types").to_string(),
+    };
+    let generation_label = match app.filter.generation {
+        Some(g) => app.i18n.tr_args("filter.generation", &[("number", &g)]),
+        None => app.i18n.tr("filter.all_generations").to_string(),
+    };
+    let border = if app.searching { Color::Yellow } else { Color::Blue };
+    let text = if app.filter.search.is_empty() && !app.searching {
+        Span::styled(app.i18n.tr("search.hint"), Style::default().fg(Color::DarkGray))
+    } else {
+        Span::raw(format!("{}{}", app.filter.search, if app.searching { "▏" } else { "" }))
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(border))
+        .title(format!(" {} · {} · {} ", app.i18n.tr("app.header"), type_label, generation_label));
+    frame.render_widget(Paragraph::new(Line::from(text)).block(block), area);
+}
+
+fn draw_list(frame: &mut Frame, app: &mut App, area: Rect) {
+    let items: Vec<ListItem> = app
+        .results
+        .iter()
+        .map(|p| {
+            let mut spans = vec![Span::raw(format!("#{:03} {} ", p.id, app.i18n.pokemon_name(p, app.database.names())))];
+            // Solo un punto de color por tipo para que la lista no se desborde
+            spans.extend(p.types.iter().map(|&t| {
+                let (r, g, b) = t.rgb();
+                Span::styled("●", Style::default().fg(Color::Rgb(r, g, b)))
+            }));
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+    let title = format!(" {} ({}) ", app.i18n.tr("list.title"), app.results.len());
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .highlight_style(Style::default().bg(Color::Rgb(75, 110, 170)).fg(Color::White))
+        .highlight_symbol("▶ ");
+    frame.render_stateful_widget(list, area, &mut app.list);
+}
+
+fn draw_details(frame: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default().borders(Borders::ALL).title(format!(" {} ", app.i18n.tr("details.stats")));
+    let Some(pokemon) = app.selected() else {
+        let message = if app.results.is_empty() { "list.empty" } else { "details.empty" };
+        frame.render_widget(Paragraph::new(app.i18n.tr(message)).block(block), area);
+        return;
+    };
+
+    let heading = Style::default().fg(Color::Rgb(40, 110, 80)).add_modifier(Modifier::BOLD);
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(app.i18n.pokemon_name(pokemon, app.database.names()), heading),
+            Span::styled(format!("  #{:03}", pokemon.id), Style::default().fg(Color::Rgb(60, 130, 100))),
+        ]),
+        Line::from(type_badges(pokemon, app)),
+    ];
+    let forms = app.database.get_forms(pokemon.id);
+    if forms.len() > 1 {
+        let spans: Vec<Span> = forms
+            .iter()
+            .map(|form| {
+                let label = form.form.as_deref().unwrap_or(&form.name).to_string();
+                if form.form == pokemon.form {
+                    Span::styled(format!("[{}] ", label), Style::default().add_modifier(Modifier::BOLD))
+                } else {
+                    Span::styled(format!("{} ", label), Style::default().fg(Color::DarkGray))
+                }
+            })
+            .collect();
+        lines.push(Line::from(spans));
+    }
+    let mut info = app.i18n.tr_args("details.generation", &[("number", &pokemon.generation)]);
+    if pokemon.legendary {
+        info.push_str(" · ");
+        info.push_str(app.i18n.tr("details.legendary"));
+    }
+    lines.push(Line::from(info));
+    lines.push(Line::default());
+
+    // Etiqueta (12) + valor (4) + bordes; el resto para la barra
+    let bar_width = (area.width as usize).saturating_sub(20);
+    for kind in StatKind::ALL {
+        let value = pokemon.stats.get(kind);
+        lines.push(Line::from(vec![
+            Span::raw(format!("{:<12}", app.i18n.stat_name(kind))),
+            Span::styled(format!("{:>3} ", value), Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled(stat_bar(value, bar_width), Style::default().fg(stat_color(value))),
+        ]));
+    }
+    lines.push(Line::from(Span::styled(
+        format!("{:<12}{:>3}", app.i18n.tr("stat.total"), pokemon.total_stats()),
+        Style::default().fg(Color::Rgb(40, 80, 120)).add_modifier(Modifier::BOLD),
+    )));
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
@@ -0,0 +1,252 @@
+use crate::battle::{self, BatchResult, BattleEvent, BattleResult, Side};
+use crate::collections::{CollectionKind, CollectionStore};
+use crate::database::Database;
+use crate::i18n::I18n;
+use crate::rng::Rng;
+use eframe::egui;
+
+// Segundos entre pasos al reproducir
+const STEP_SECONDS: f64 = 0.5;
+
+pub struct BattleState {
+    // Índices de los equipos en el almacén de colecciones
+    player: Option<usize>,
+    opponent: Option<usize>,
+    seed_text: String,
+    result: Option<BattleResult>,
+    seed_used: u64,
+    // Eventos mostrados en el visor
+    step: usize,
+    playing: bool,
+    last_step_time: f64,
+    batch_runs: usize,
+    batch: Option<BatchResult>,
+}
+
+impl Default for BattleState {
+    fn default() -> Self {
+        Self {
+            player: None,
+            opponent: None,
+            seed_text: String::new(),
+            result: None,
+            seed_used: 0,
+            step: 0,
+            playing: false,
+            last_step_time: 0.0,
+            batch_runs: 100,
+            batch: None,
+        }
+    }
+}
+
+impl BattleState {
+    fn seed(&self) -> u64 {
+        self.seed_text.trim().parse().unwrap_or_else(|_| Rng::random_seed())
+    }
+}
+
+// Último Pokémon en el campo de un bando y sus PS tras los primeros `step` eventos
+fn field_state(events: &[BattleEvent], side: Side) -> Option<(&str, u16, u16)> {
+    events.iter().rev().find_map(|event| match event {
+        BattleEvent::SwitchIn { side: s, name, hp, max_hp } | BattleEvent::Damage { side: s, name, hp, max_hp, .. }
+            if *s == side =>
+        {
+            Some((name.as_str(), *hp, *max_hp))
+        }
+        _ => None,
+    })
+}
+
+pub fn render_vista_battle_panel(
+    ui: &mut egui::Ui,
+    database: &Database,
+    store: &CollectionStore,
+    state: &mut BattleState,
+    i18n: &I18n,
+) {
+    let vista_panel_frame = egui::Frame::none()
+        .fill(egui::Color32::from_rgba_premultiplied(240, 248, 255, 200))
+        .rounding(egui::Rounding::same(8.0))
+        .stroke(egui::Stroke::new(1.0, egui::Color32::from_rgb(180, 200, 220)))
+        .inner_margin(egui::style::Margin::same(8.0))
+        .shadow(egui::epaint::Shadow {
+            extrusion: 6.0,
+            color: egui::Color32::from_rgba_premultiplied(0, 0, 0, 40),
+        });
+
+    vista_panel_frame.show(ui, |ui| {
+        ui.set_width(ui.available_width());
+        ui.set_height(450.0);
+
+        ui.heading(
+            egui::RichText::new(i18n.tr("battle.title"))
+                .size(24.0)
+                .color(egui::Color32::from_rgb(40, 110, 80))
+                .strong()
+        );
+
+        let teams = store.indices_of(CollectionKind::Team);
+        if teams.len() < 2 {
+            ui.label(
+                egui::RichText::new(i18n.tr("battle.need_teams"))
+                    .color(egui::Color32::from_rgb(120, 140, 160))
+            );
+            return;
+        }
+
+        let team_name = |index: Option<usize>| {
+            index.and_then(|i| store.get(i)).map(|c| c.name.clone()).unwrap_or_default()
+        };
+        ui.horizontal(|ui| {
+            for (id, selected) in [("battle_player", &mut state.player), ("battle_opponent", &mut state.opponent)] {
+                egui::ComboBox::from_id_source(id)
+                    .selected_text(team_name(*selected))
+                    .show_ui(ui, |ui| {
+                        for &index in &teams {
+                            ui.selectable_value(selected, Some(index), team_name(Some(index)));
+                        }
+                    });
+                if id == "battle_player" {
+                    ui.label(egui::RichText::new("vs").strong());
+                }
+            }
+        });
+
+        let team = |index: Option<usize>| index.and_then(|i| store.team(i));
+        let (player, opponent) = (team(state.player), team(state.opponent));
+        ui.horizontal(|ui| {
+            ui.label(
+                egui::RichText::new(i18n.tr("generator.seed"))
+                    .color(egui::Color32::from_rgb(40, 80, 120))
+            );
+            ui.add(
+                egui::TextEdit::singleline(&mut state.seed_text)
+                    .hint_text(i18n.tr("generator.seed_hint"))
+                    .desired_width(120.0)
+            );
+            let ready = player.is_some() && opponent.is_some();
+            if ui.add_enabled(ready, egui::Button::new(i18n.tr("battle.simulate"))).clicked() {
+                if let (Some(player), Some(opponent)) = (&player, &opponent) {
+                    state.seed_used = state.seed();
+                    state.result = Some(battle::simulate(player.members(), opponent.members(), database, state.seed_used));
+                    state.step = 0;
+                    state.playing = true;
+                }
+            }
+            ui.add(egui::DragValue::new(&mut state.batch_runs).clamp_range(1..=10_000));
+            if ui.add_enabled(ready, egui::Button::new(i18n.tr("battle.batch"))).clicked() {
+                if let (Some(player), Some(opponent)) = (&player, &opponent) {
+                    let (player, opponent) = (player.members(), opponent.members());
+                    state.batch = Some(battle::run_batch(player, opponent, database, state.batch_runs, state.seed()));
+                }
+            }
+        });
+
+        if let Some(batch) = &state.batch {
+            ui.label(
+                egui::RichText::new(i18n.tr_args(
+                    "battle.batch_result",
+                    &[
+                        ("runs", &batch.runs),
+                        ("player", &format!("{:.1}", batch.win_rate(Side::Player))),
+                        ("opponent", &format!("{:.1}", batch.win_rate(Side::Opponent))),
+                        ("draws", &batch.draws),
+                        ("turns", &format!("{:.1}", batch.average_turns)),
+                    ],
+                ))
+                .color(egui::Color32::from_rgb(40, 80, 120))
+            );
+        }
+
+        let names = [team_name(state.player), team_name(state.opponent)];
+        if let Some(result) = state.result.take() {
+            render_replay(ui, &result, state, [&names[0], &names[1]], i18n);
+            state.result = Some(result);
+        }
+    });
+}
+
+fn render_replay(ui: &mut egui::Ui, result: &BattleResult, state: &mut BattleState, team_names: [&str; 2], i18n: &I18n) {
+    let total = result.events.len();
+    let now = ui.input(|input| input.time);
+    if state.playing {
+        if state.step >= total {
+            state.playing = false;
+        } else if now - state.last_step_time >= STEP_SECONDS {
+            state.step += 1;
+            state.last_step_time = now;
+        }
+        ui.ctx().request_repaint();
+    }
+
+    ui.separator();
+    ui.horizontal(|ui| {
+        if ui.button("⏮").clicked() {
+            state.step = 0;
+        }
+        if ui.button("◀").clicked() {
+            state.step = state.step.saturating_sub(1);
+        }
+        let play_label = if state.playing { "⏸" } else { "▶" };
+        if ui.button(play_label).clicked() {
+            state.playing = !state.playing;
+        }
+        if ui.button("▶|").clicked() {
+            state.step = (state.step + 1).min(total);
+        }
+        if ui.button("⏭").clicked() {
+            state.step = total;
+        }
+        ui.add(egui::Slider::new(&mut state.step, 0..=total));
+        ui.label(
+            egui::RichText::new(i18n.tr_args("generator.seed_used", &[("seed", &state.seed_used)]))
+                .size(12.0)
+                .color(egui::Color32::from_rgb(120, 140, 160))
+        );
+    });
+
+    // Pokémon en el campo con su barra de PS
+    let shown = &result.events[..state.step.min(total)];
+    ui.horizontal(|ui| {
+        for (side, team_name) in [(Side::Player, team_names[0]), (Side::Opponent, team_names[1])] {
+            ui.vertical(|ui| {
+                ui.set_width(200.0);
+                ui.label(egui::RichText::new(team_name).color(egui::Color32::from_rgb(40, 80, 120)).strong());
+                if let Some((name, hp, max_hp)) = field_state(shown, side) {
+                    let fraction = hp as f32 / max_hp.max(1) as f32;
+                    let color = if fraction > 0.5 {
+                        egui::Color32::from_rgb(80, 180, 90)
+                    } else if fraction > 0.2 {
+                        egui::Color32::from_rgb(230, 180, 40)
+                    } else {
+                        egui::Color32::from_rgb(210, 70, 60)
+                    };
+                    ui.label(egui::RichText::new(name).strong());
+                    ui.add(
+                        egui::ProgressBar::new(fraction)
+                            .fill(color)
+                            .text(format!("{}/{}", hp, max_hp))
+                            .desired_width(180.0)
+                    );
+                }
+            });
+        }
+    });
+
+    egui::ScrollArea::vertical()
+        .id_source("battle_log")
+        .stick_to_bottom(true)
+        .show(ui, |ui| {
+            for event in shown {
+                let text = egui::RichText::new(event.text(team_names, i18n));
+                let text = match event {
+                    BattleEvent::Turn(_) => text.color(egui::Color32::from_rgb(40, 80, 120)).strong(),
+                    BattleEvent::Faint { .. } => text.color(egui::Color32::from_rgb(200, 60, 50)),
+                    BattleEvent::Victory(_) | BattleEvent::Draw => text.size(16.0).strong(),
+                    _ => text,
+                };
+                ui.label(text);
+            }
+        });
+}
@@ -0,0 +1,259 @@
+use super::sprites::{render_sprite, SpriteCache};
+use crate::collections::{CollectionKind, CollectionStore};
+use crate::database::Database;
+use crate::i18n::I18n;
+use crate::team::TeamMember;
+use eframe::egui;
+use std::path::PathBuf;
+
+const MEMBER_SPRITE: f32 = 32.0;
+
+pub struct CollectionsState {
+    selected: Option<usize>,
+    new_name: String,
+    new_kind: CollectionKind,
+    rename_text: String,
+    rename_source: Option<usize>,
+    // Ruta del archivo para exportar o importar una colección
+    file_path: String,
+    // Resultado de la última operación: (correcto, mensaje)
+    message: Option<(bool, String)>,
+}
+
+impl Default for CollectionsState {
+    fn default() -> Self {
+        Self {
+            selected: None,
+            new_name: String::new(),
+            new_kind: CollectionKind::Box,
+            rename_text: String::new(),
+            rename_source: None,
+            file_path: String::new(),
+            message: None,
+        }
+    }
+}
+
+impl CollectionsState {
+    // Añade la especie, con la forma que se ve en la lista, a la colección seleccionada (en cajas y listas sin repetir)
+    pub fn add_species(&mut self, store: &mut CollectionStore, database: &Database, id: u16, form: Option<&str>, i18n: &I18n) {
+        let (Some(index), Some(pokemon)) = (self.selected, database.get_pokemon_form(id, form)) else {
+            return;
+        };
+        let Some(collection) = store.get_mut(index) else {
+            return;
+        };
+        if collection.kind != CollectionKind::Team && collection.contains(id) {
+            return;
+        }
+        match collection.add(TeamMember::from_pokemon(pokemon)) {
+            Ok(()) => store.save(),
+            Err(_) => self.message = Some((false, i18n.tr("collections.full").to_string())),
+        }
+    }
+}
+
+pub fn render_vista_collections_panel(
+    ui: &mut egui::Ui,
+    database: &Database,
+    store: &mut CollectionStore,
+    state: &mut CollectionsState,
+    sprites: &mut SpriteCache,
+    i18n: &I18n,
+) {
+    let vista_panel_frame = egui::Frame::none()
+        .fill(egui::Color32::from_rgba_premultiplied(240, 248, 255, 200))
+        .rounding(egui::Rounding::same(8.0))
+        .stroke(egui::Stroke::new(1.0, egui::Color32::from_rgb(180, 200, 220)))
+        .inner_margin(egui::style::Margin::same(8.0))
+        .shadow(egui::epaint::Shadow {
+            extrusion: 6.0,
+            color: egui::Color32::from_rgba_premultiplied(0, 0, 0, 40),
+        });
+
+    vista_panel_frame.show(ui, |ui| {
+        ui.set_width(ui.available_width());
+        ui.set_height(450.0);
+
+        ui.heading(
+            egui::RichText::new(i18n.tr("collections.title"))
+                .size(24.0)
+                .color(egui::Color32::from_rgb(40, 110, 80))
+                .strong()
+        );
+
+        // Crear una colección nueva
+        ui.horizontal(|ui| {
+            egui::ComboBox::from_id_source("new_collection_kind")
+                .selected_text(i18n.tr(state.new_kind.key()))
+                .show_ui(ui, |ui| {
+                    for kind in CollectionKind::ALL {
+                        ui.selectable_value(&mut state.new_kind, kind, i18n.tr(kind.key()));
+                    }
+                });
+            ui.add(
+                egui::TextEdit::singleline(&mut state.new_name)
+                    .hint_text(i18n.tr("collections.name_hint"))
+                    .desired_width(140.0)
+            );
+            let name = state.new_name.trim().to_string();
+            if ui.add_enabled(!name.is_empty(), egui::Button::new(i18n.tr("collections.create"))).clicked() {
+                state.selected = Some(store.create(&name, state.new_kind));
+                state.new_name.clear();
+                store.save();
+            }
+        });
+
+        // Importar un archivo exportado (añade sus colecciones)
+        ui.horizontal(|ui| {
+            ui.add(
+                egui::TextEdit::singleline(&mut state.file_path)
+                    .hint_text(i18n.tr("collections.path_hint"))
+                    .desired_width(200.0)
+            );
+            let path = PathBuf::from(state.file_path.trim());
+            let has_path = !state.file_path.trim().is_empty();
+            if ui.add_enabled(has_path, egui::Button::new(i18n.tr("collections.import"))).clicked() {
+                state.message = Some(match store.import(&path) {
+                    Ok(count) => {
+                        store.save();
+                        (true, i18n.tr_args("collections.imported", &[("count", &count)]))
+                    }
+                    Err(e) => (false, i18n.tr_args("collections.error", &[("error", &e)])),
+                });
+            }
+            let can_export = has_path && state.selected.is_some();
+            if ui.add_enabled(can_export, egui::Button::new(i18n.tr("collections.export"))).clicked() {
+                if let Some(index) = state.selected {
+                    state.message = Some(match store.export(index, &path) {
+                        Ok(()) => (true, i18n.tr_args("collections.exported", &[("path", &path.display())])),
+                        Err(e) => (false, i18n.tr_args("collections.error", &[("error", &e)])),
+                    });
+                }
+            }
+        });
+        if let Some((ok, message)) = &state.message {
+            let color = if *ok {
+                egui::Color32::from_rgb(40, 140, 70)
+            } else {
+                egui::Color32::from_rgb(200, 60, 50)
+            };
+            ui.label(egui::RichText::new(message).size(12.0).color(color));
+        }
+        ui.separator();
+
+        if store.all().is_empty() {
+            ui.label(
+                egui::RichText::new(i18n.tr("collections.empty"))
+                    .color(egui::Color32::from_rgb(120, 140, 160))
+            );
+            return;
+        }
+
+        ui.horizontal_top(|ui| {
+            // Lista de colecciones
+            egui::ScrollArea::vertical()
+                .id_source("collections_list")
+                .max_width(150.0)
+                .show(ui, |ui| {
+                    ui.set_width(150.0);
+                    for (index, collection) in store.all().iter().enumerate() {
+                        let text = format!(
+                            "{}\n{} · {}",
+                            collection.name,
+                            i18n.tr(collection.kind.key()),
+                            collection.members.len()
+                        );
+                        if ui.selectable_label(state.selected == Some(index), text).clicked() {
+                            state.selected = Some(index);
+                        }
+                    }
+                });
+
+            ui.separator();
+            ui.vertical(|ui| render_selected_collection(ui, database, store, state, sprites, i18n));
+        });
+    });
+}
+
+fn render_selected_collection(
+    ui: &mut egui::Ui,
+    database: &Database,
+    store: &mut CollectionStore,
+    state: &mut CollectionsState,
+    sprites: &mut SpriteCache,
+    i18n: &I18n,
+) {
+    let Some(index) = state.selected.filter(|&i| store.get(i).is_some()) else {
+        ui.label(
+            egui::RichText::new(i18n.tr("collections.select"))
+                .color(egui::Color32::from_rgb(120, 140, 160))
+        );
+        return;
+    };
+    if state.rename_source != Some(index) {
+        state.rename_text = store.get(index).map(|c| c.name.clone()).unwrap_or_default();
+        state.rename_source = Some(index);
+    }
+
+    ui.horizontal(|ui| {
+        ui.add(egui::TextEdit::singleline(&mut state.rename_text).desired_width(120.0));
+        if ui.button(i18n.tr("collections.rename")).clicked() {
+            store.rename(index, &state.rename_text);
+            store.save();
+            state.rename_source = None;
+        }
+    });
+    ui.horizontal(|ui| {
+        if ui.button(i18n.tr("collections.duplicate")).clicked() {
+            state.selected = store.duplicate(index);
+            store.save();
+        }
+        if ui.button(i18n.tr("collections.delete")).clicked() {
+            store.delete(index);
+            store.save();
+            state.selected = None;
+        }
+    });
+    let Some(collection) = state.selected.and_then(|i| store.get_mut(i)) else {
+        return;
+    };
+
+    let capacity = match collection.kind.capacity() {
+        Some(capacity) => format!("{}/{}", collection.members.len(), capacity),
+        None => collection.members.len().to_string(),
+    };
+    ui.label(
+        egui::RichText::new(i18n.tr_args("collections.members", &[("count", &capacity)]))
+            .color(egui::Color32::from_rgb(40, 80, 120))
+            .strong()
+    );
+    if collection.members.is_empty() {
+        ui.label(
+            egui::RichText::new(i18n.tr("collections.add_hint"))
+                .size(12.0)
+                .color(egui::Color32::from_rgb(120, 140, 160))
+        );
+    }
+
+    let mut remove = None;
+    egui::ScrollArea::vertical().id_source("collection_members").show(ui, |ui| {
+        for (position, member) in collection.members.iter().enumerate() {
+            let Some(pokemon) = database.get_pokemon_form(member.id, member.form.as_deref()) else {
+                continue;
+            };
+            ui.horizontal(|ui| {
+                let texture = sprites.get(ui.ctx(), pokemon, false);
+                render_sprite(ui, texture.as_ref(), MEMBER_SPRITE);
+                ui.label(format!("#{:03} {}", pokemon.id, i18n.pokemon_name(pokemon, database.names())));
+                if ui.small_button("✖").on_hover_text(i18n.tr("collections.remove")).clicked() {
+                    remove = Some(position);
+                }
+            });
+        }
+    });
+    if let Some(position) = remove {
+        collection.remove(position);
+        store.save();
+    }
+}
@@ -0,0 +1,369 @@
+use super::type_color;
+use crate::analytics::{self, Metric, Summary};
+use crate::database::Database;
+use crate::i18n::I18n;
+use crate::pokemon::{Pokemon, PokemonType};
+use eframe::egui;
+use std::collections::BTreeMap;
+
+const HISTOGRAM_BINS: usize = 16;
+const HEATMAP_CELL: f32 = 18.0;
+
+pub struct DashboardState {
+    pub metric: Metric,
+    pub top_n: usize,
+}
+
+impl Default for DashboardState {
+    fn default() -> Self {
+        Self {
+            metric: Metric::Total,
+            top_n: 5,
+        }
+    }
+}
+
+pub fn render_vista_dashboard_panel(
+    ui: &mut egui::Ui,
+    database: &Database,
+    entries: &[&Pokemon],
+    state: &mut DashboardState,
+    i18n: &I18n,
+) {
+    let vista_panel_frame = egui::Frame::none()
+        .fill(egui::Color32::from_rgba_premultiplied(240, 248, 255, 200))
+        .rounding(egui::Rounding::same(8.0))
+        .stroke(egui::Stroke::new(1.0, egui::Color32::from_rgb(180, 200, 220)))
+        .inner_margin(egui::style::Margin::same(8.0))
+        .shadow(egui::epaint::Shadow {
+            extrusion: 6.0,
+            color: egui::Color32::from_rgba_premultiplied(0, 0, 0, 40),
+        });
+
+    vista_panel_frame.show(ui, |ui| {
+        ui.set_width(ui.available_width());
+        ui.set_height(450.0);
+
+        ui.horizontal(|ui| {
+            ui.heading(
+                egui::RichText::new(i18n.tr("dashboard.title"))
+                    .size(24.0)
+                    .color(egui::Color32::from_rgb(40, 110, 80))
+                    .strong()
+            );
+            ui.add_space(12.0);
+            ui.label(
+                egui::RichText::new(i18n.tr_args("dashboard.entries", &[("count", &entries.len())]))
+                    .color(egui::Color32::from_rgb(70, 110, 160))
+            );
+        });
+
+        if entries.is_empty() {
+            ui.vertical_centered(|ui| {
+                ui.add_space(100.0);
+                ui.label(
+                    egui::RichText::new(i18n.tr("list.empty"))
+                        .size(18.0)
+                        .color(egui::Color32::from_rgb(120, 140, 160))
+                );
+            });
+            return;
+        }
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            // Distribución de tipos y mapa de calor de combinaciones
+            let distribution = analytics::type_distribution(entries);
+            section_title(ui, i18n.tr("dashboard.type_distribution"));
+            ui.horizontal_top(|ui| {
+                render_type_bars(ui, &distribution.by_type, database, i18n);
+                ui.add_space(20.0);
+                render_type_heatmap(ui, &distribution, database, i18n);
+            });
+
+            // Un histograma pequeño por cada estadística y el total
+            section_title(ui, i18n.tr("dashboard.histograms"));
+            ui.horizontal_wrapped(|ui| {
+                for metric in Metric::ALL {
+                    let values = analytics::metric_values(entries, metric);
+                    render_histogram(ui, i18n.tr(metric.key()), &values);
+                }
+            });
+
+            // Tablas por tipo y por generación para la métrica elegida
+            ui.horizontal(|ui| {
+                section_title(ui, i18n.tr("dashboard.summaries"));
+                ui.add_space(8.0);
+                egui::ComboBox::from_id_source("dashboard_metric")
+                    .selected_text(i18n.tr(state.metric.key()))
+                    .show_ui(ui, |ui| {
+                        for metric in Metric::ALL {
+                            ui.selectable_value(&mut state.metric, metric, i18n.tr(metric.key()));
+                        }
+                    });
+            });
+            let by_type: Vec<(String, Summary)> = analytics::summary_by_type(entries, state.metric)
+                .into_iter()
+                .map(|(t, s)| (i18n.type_name(t, database.names()), s))
+                .collect();
+            let by_generation: Vec<(String, Summary)> = analytics::summary_by_generation(entries, state.metric)
+                .into_iter()
+                .map(|(g, s)| (i18n.tr_args("filter.generation", &[("number", &g)]), s))
+                .collect();
+            ui.label(egui::RichText::new(i18n.tr("dashboard.by_type")).strong());
+            render_summary_table(ui, "summary_by_type", &by_type, i18n);
+            ui.add_space(8.0);
+            ui.label(egui::RichText::new(i18n.tr("dashboard.by_generation")).strong());
+            render_summary_table(ui, "summary_by_generation", &by_generation, i18n);
+
+            // Los mejores en cada estadística
+            ui.horizontal(|ui| {
+                section_title(ui, i18n.tr("dashboard.top"));
+                ui.add_space(8.0);
+                ui.add(egui::Slider::new(&mut state.top_n, 3..=20));
+            });
+            egui::Grid::new("dashboard_top_n")
+                .spacing([16.0, 4.0])
+                .striped(true)
+                .show(ui, |ui| {
+                    for metric in Metric::ALL {
+                        ui.label(egui::RichText::new(i18n.tr(metric.key())).strong());
+                    }
+                    ui.end_row();
+
+                    let tops: Vec<Vec<&Pokemon>> = Metric::ALL
+                        .iter()
+                        .map(|&metric| analytics::top_n(entries, metric, state.top_n))
+                        .collect();
+                    for row in 0..state.top_n {
+                        for (metric, top) in Metric::ALL.iter().zip(&tops) {
+                            match top.get(row) {
+                                Some(pokemon) => ui.label(format!(
+                                    "{} ({})",
+                                    i18n.pokemon_name(pokemon, database.names()),
+                                    metric.value(pokemon)
+                                )),
+                                None => ui.label(""),
+                            };
+                        }
+                        ui.end_row();
+                    }
+                });
+        });
+    });
+}
+
+fn section_title(ui: &mut egui::Ui, text: &str) {
+    ui.add_space(8.0);
+    ui.label(
+        egui::RichText::new(text)
+            .size(18.0)
+            .color(egui::Color32::from_rgb(40, 80, 120))
+            .strong()
+    );
+}
+
+fn render_type_bars(
+    ui: &mut egui::Ui,
+    by_type: &BTreeMap<PokemonType, usize>,
+    database: &Database,
+    i18n: &I18n,
+) {
+    let max = by_type.values().copied().max().unwrap_or(1).max(1);
+    let row_height = 16.0;
+    let label_width = 80.0;
+    let bar_max_width = 220.0;
+    let size = egui::vec2(label_width + bar_max_width + 40.0, row_height * PokemonType::ALL.len() as f32);
+    let (rect, _) = ui.allocate_exact_size(size, egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+
+    for (index, pokemon_type) in PokemonType::ALL.iter().enumerate() {
+        let count = by_type.get(pokemon_type).copied().unwrap_or(0);
+        let top = rect.top() + index as f32 * row_height;
+        painter.text(
+            egui::pos2(rect.left() + label_width - 6.0, top + row_height / 2.0),
+            egui::Align2::RIGHT_CENTER,
+            i18n.type_name(*pokemon_type, database.names()),
+            egui::FontId::proportional(12.0),
+            egui::Color32::from_rgb(40, 80, 120),
+        );
+        let width = bar_max_width * count as f32 / max as f32;
+        let bar = egui::Rect::from_min_size(
+            egui::pos2(rect.left() + label_width, top + 2.0),
+            egui::vec2(width, row_height - 4.0),
+        );
+        painter.rect_filled(bar, egui::Rounding::same(3.0), type_color(pokemon_type));
+        painter.text(
+            egui::pos2(bar.right() + 4.0, top + row_height / 2.0),
+            egui::Align2::LEFT_CENTER,
+            count.to_string(),
+            egui::FontId::proportional(11.0),
+            egui::Color32::from_rgb(60, 80, 100),
+        );
+    }
+}
+
+fn render_type_heatmap(
+    ui: &mut egui::Ui,
+    distribution: &analytics::TypeDistribution,
+    database: &Database,
+    i18n: &I18n,
+) {
+    let types = PokemonType::ALL;
+    let label_size = 40.0;
+    let grid = HEATMAP_CELL * types.len() as f32;
+    let (rect, response) = ui.allocate_exact_size(egui::vec2(label_size + grid, label_size + grid), egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+    let origin = rect.min + egui::vec2(label_size, label_size);
+    let max = distribution.pairs.values().copied().max().unwrap_or(1).max(1);
+
+    // Abreviaturas de tres letras en los ejes
+    for (index, pokemon_type) in types.iter().enumerate() {
+        let short: String = i18n.type_name(*pokemon_type, database.names()).chars().take(3).collect();
+        let offset = index as f32 * HEATMAP_CELL + HEATMAP_CELL / 2.0;
+        painter.text(
+            egui::pos2(origin.x - 4.0, origin.y + offset),
+            egui::Align2::RIGHT_CENTER,
+            &short,
+            egui::FontId::proportional(10.0),
+            type_color(pokemon_type),
+        );
+        painter.text(
+            egui::pos2(origin.x + offset, origin.y - 4.0),
+            egui::Align2::CENTER_BOTTOM,
+            &short,
+            egui::FontId::proportional(10.0),
+            type_color(pokemon_type),
+        );
+    }
+
+    let mut hovered = None;
+    for (row, &a) in types.iter().enumerate() {
+        for (col, &b) in types.iter().enumerate() {
+            let count = distribution.pair_count(a, b);
+            let cell = egui::Rect::from_min_size(
+                origin + egui::vec2(col as f32 * HEATMAP_CELL, row as f32 * HEATMAP_CELL),
+                egui::vec2(HEATMAP_CELL, HEATMAP_CELL),
+            );
+            let intensity = count as f32 / max as f32;
+            let color = egui::Color32::from_rgb(
+                (235.0 - intensity * 190.0) as u8,
+                (242.0 - intensity * 150.0) as u8,
+                (250.0 - intensity * 90.0) as u8,
+            );
+            painter.rect(cell.shrink(0.5), egui::Rounding::ZERO, color, egui::Stroke::NONE);
+            if response.hover_pos().is_some_and(|pos| cell.contains(pos)) {
+                painter.rect_stroke(cell, egui::Rounding::ZERO, egui::Stroke::new(1.5, egui::Color32::from_rgb(30, 90, 150)));
+                hovered = Some((a, b, count));
+            }
+        }
+    }
+
+    if let Some((a, b, count)) = hovered {
+        response.on_hover_text(format!(
+            "{} / {}: {}",
+            i18n.type_name(a, database.names()),
+            i18n.type_name(b, database.names()),
+            count
+        ));
+    }
+}
+
+fn render_histogram(ui: &mut egui::Ui, title: &str, values: &[u16]) {
+    let histogram = analytics::histogram(values, HISTOGRAM_BINS);
+    let size = egui::vec2(250.0, 110.0);
+    let (rect, response) = ui.allocate_exact_size(size, egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+
+    painter.rect(
+        rect,
+        egui::Rounding::same(4.0),
+        egui::Color32::from_rgb(245, 250, 255),
+        egui::Stroke::new(1.0, egui::Color32::from_rgb(180, 200, 220)),
+    );
+    painter.text(
+        rect.left_top() + egui::vec2(6.0, 4.0),
+        egui::Align2::LEFT_TOP,
+        title,
+        egui::FontId::proportional(12.0),
+        egui::Color32::from_rgb(40, 80, 120),
+    );
+
+    let chart = egui::Rect::from_min_max(rect.min + egui::vec2(6.0, 20.0), rect.max - egui::vec2(6.0, 16.0));
+    let max = histogram.counts.iter().copied().max().unwrap_or(1).max(1);
+    let bar_width = chart.width() / histogram.counts.len() as f32;
+    let mut hovered = None;
+    for (index, &count) in histogram.counts.iter().enumerate() {
+        let height = chart.height() * count as f32 / max as f32;
+        let bar = egui::Rect::from_min_max(
+            egui::pos2(chart.left() + index as f32 * bar_width + 1.0, chart.bottom() - height),
+            egui::pos2(chart.left() + (index + 1) as f32 * bar_width - 1.0, chart.bottom()),
+        );
+        let column = egui::Rect::from_x_y_ranges(bar.x_range(), chart.y_range());
+        let is_hovered = response.hover_pos().is_some_and(|pos| column.contains(pos));
+        let color = if is_hovered {
+            egui::Color32::from_rgb(30, 90, 150)
+        } else {
+            egui::Color32::from_rgb(100, 150, 210)
+        };
+        painter.rect_filled(bar, egui::Rounding::same(2.0), color);
+        if is_hovered {
+            hovered = Some((index, count));
+        }
+    }
+
+    // Rango de valores en el eje X
+    let (first, _) = histogram.bin_range(0);
+    let (_, last) = histogram.bin_range(histogram.counts.len() - 1);
+    for (text, align, x) in [
+        (first.to_string(), egui::Align2::LEFT_TOP, chart.left()),
+        (last.to_string(), egui::Align2::RIGHT_TOP, chart.right()),
+    ] {
+        painter.text(
+            egui::pos2(x, chart.bottom() + 2.0),
+            align,
+            text,
+            egui::FontId::proportional(10.0),
+            egui::Color32::from_rgb(90, 110, 130),
+        );
+    }
+
+    if let Some((index, count)) = hovered {
+        let (from, to) = histogram.bin_range(index);
+        response.on_hover_text(format!("{}–{}: {}", from, to - 1, count));
+    }
+}
+
+fn render_summary_table(ui: &mut egui::Ui, id: &str, rows: &[(String, Summary)], i18n: &I18n) {
+    egui::Grid::new(id)
+        .spacing([14.0, 3.0])
+        .striped(true)
+        .show(ui, |ui| {
+            ui.label("");
+            for key in [
+                "dashboard.count",
+                "dashboard.mean",
+                "dashboard.median",
+                "dashboard.p25",
+                "dashboard.p75",
+                "dashboard.p90",
+                "dashboard.min",
+                "dashboard.max",
+            ] {
+                ui.label(egui::RichText::new(i18n.tr(key)).strong());
+            }
+            ui.end_row();
+
+            for (label, summary) in rows {
+                ui.label(label);
+                ui.label(summary.count.to_string());
+                ui.label(format!("{:.1}", summary.mean));
+                ui.label(format!("{:.1}", summary.median));
+                ui.label(format!("{:.1}", summary.p25));
+                ui.label(format!("{:.1}", summary.p75));
+                ui.label(format!("{:.1}", summary.p90));
+                ui.label(summary.min.to_string());
+                ui.label(summary.max.to_string());
+                ui.end_row();
+            }
+        });
+}
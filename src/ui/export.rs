@@ -0,0 +1,289 @@
+use crate::card::{self, CardFormat, CardOptions};
+use crate::export::{self, Column, ExportFormat};
+use crate::flashcards::{self, CardTemplate, DeckFormat};
+use crate::i18n::{I18n, LocalizedNames};
+use crate::pokemon::Pokemon;
+use eframe::egui;
+use std::path::PathBuf;
+
+// Cuántas casillas de columna por fila
+const COLUMNS_PER_ROW: usize = 5;
+
+pub struct ExportState {
+    pub open: bool,
+    format: ExportFormat,
+    columns: Vec<Column>,
+    file_path: String,
+    // Resultado de la última exportación: (correcto, mensaje)
+    message: Option<(bool, String)>,
+    // Tarjetas imprimibles
+    card_options: CardOptions,
+    cards_dir: String,
+    card_message: Option<(bool, String)>,
+    // Mazo para Anki
+    deck_templates: Vec<CardTemplate>,
+    deck_format: DeckFormat,
+    deck_path: String,
+    deck_message: Option<(bool, String)>,
+}
+
+impl Default for ExportState {
+    fn default() -> Self {
+        Self {
+            open: false,
+            format: ExportFormat::Csv,
+            columns: Column::ALL.to_vec(),
+            file_path: "pokedex.csv".to_string(),
+            message: None,
+            card_options: CardOptions::default(),
+            cards_dir: "cards".to_string(),
+            card_message: None,
+            deck_templates: vec![CardTemplate::NameTypes],
+            deck_format: DeckFormat::Tsv,
+            deck_path: "pokedex-anki.tsv".to_string(),
+            deck_message: None,
+        }
+    }
+}
+
+impl ExportState {
+    // Al cambiar de formato se cambia también la extensión si la ruta tenía una conocida
+    fn set_format(&mut self, format: ExportFormat) {
+        self.format = format;
+        let mut path = PathBuf::from(self.file_path.trim());
+        if ExportFormat::from_path(&path).is_some() {
+            path.set_extension(format.extension());
+            self.file_path = path.display().to_string();
+        }
+    }
+
+    fn toggle(&mut self, column: Column, enabled: bool) {
+        self.columns.retain(|&c| c != column);
+        if enabled {
+            self.columns.push(column);
+            // Se mantiene el orden de Column::ALL
+            self.columns.sort();
+        }
+    }
+}
+
+fn column_label(column: Column, i18n: &I18n) -> &str {
+    match column {
+        Column::Id => i18n.tr("export.column.id"),
+        Column::Name => i18n.tr("export.column.name"),
+        Column::Form => i18n.tr("export.column.form"),
+        Column::Types => i18n.tr("export.column.types"),
+        Column::Hp => i18n.tr("stat.hp"),
+        Column::Attack => i18n.tr("stat.attack"),
+        Column::Defense => i18n.tr("stat.defense"),
+        Column::SpecialAttack => i18n.tr("stat.special_attack"),
+        Column::SpecialDefense => i18n.tr("stat.special_defense"),
+        Column::Speed => i18n.tr("stat.speed"),
+        Column::Total => i18n.tr("stat.total"),
+        Column::Generation => i18n.tr("export.column.generation"),
+        Column::Legendary => i18n.tr("details.legendary"),
+    }
+}
+
+fn render_message(ui: &mut egui::Ui, message: &Option<(bool, String)>) {
+    if let Some((ok, message)) = message {
+        let color = if *ok {
+            egui::Color32::from_rgb(40, 140, 70)
+        } else {
+            egui::Color32::from_rgb(200, 60, 50)
+        };
+        ui.label(egui::RichText::new(message).size(12.0).color(color));
+    }
+}
+
+// Exporta la lista tal como se ve (mismos filtros y orden) y genera tarjetas imprimibles
+pub fn render_export_window(
+    ctx: &egui::Context,
+    state: &mut ExportState,
+    pokemons: &[&Pokemon],
+    selected: Option<&Pokemon>,
+    names: &LocalizedNames,
+    i18n: &I18n,
+) {
+    let mut open = state.open;
+    egui::Window::new(i18n.tr("export.title"))
+        .open(&mut open)
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .show(ctx, |ui| {
+            ui.label(
+                egui::RichText::new(i18n.tr_args("export.count", &[("count", &pokemons.len())]))
+                    .color(egui::Color32::from_rgb(120, 140, 160))
+            );
+            ui.add_space(4.0);
+
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new(i18n.tr("export.format")).color(egui::Color32::from_rgb(40, 80, 120)));
+                let mut format = state.format;
+                egui::ComboBox::from_id_source("export_format")
+                    .selected_text(format.label())
+                    .show_ui(ui, |ui| {
+                        for option in ExportFormat::ALL {
+                            ui.selectable_value(&mut format, option, option.label());
+                        }
+                    });
+                if format != state.format {
+                    state.set_format(format);
+                }
+            });
+
+            ui.add_space(4.0);
+            ui.label(egui::RichText::new(i18n.tr("export.columns")).color(egui::Color32::from_rgb(40, 80, 120)));
+            egui::Grid::new("export_columns_grid").spacing([12.0, 4.0]).show(ui, |ui| {
+                for (i, column) in Column::ALL.into_iter().enumerate() {
+                    let mut enabled = state.columns.contains(&column);
+                    if ui.checkbox(&mut enabled, column_label(column, i18n)).changed() {
+                        state.toggle(column, enabled);
+                    }
+                    if (i + 1) % COLUMNS_PER_ROW == 0 {
+                        ui.end_row();
+                    }
+                }
+            });
+            ui.horizontal(|ui| {
+                if ui.small_button(i18n.tr("export.all_columns")).clicked() {
+                    state.columns = Column::ALL.to_vec();
+                }
+                if ui.small_button(i18n.tr("export.no_columns")).clicked() {
+                    state.columns.clear();
+                }
+            });
+
+            ui.add_space(6.0);
+            ui.horizontal(|ui| {
+                ui.add(
+                    egui::TextEdit::singleline(&mut state.file_path)
+                        .hint_text(i18n.tr("export.path_hint"))
+                        .desired_width(260.0)
+                );
+                let path = PathBuf::from(state.file_path.trim());
+                let can_export = !state.file_path.trim().is_empty() && !state.columns.is_empty();
+                if ui.add_enabled(can_export, egui::Button::new(i18n.tr("export.save"))).clicked() {
+                    state.message = Some(match export::export_to_file(&path, pokemons, &state.columns, state.format) {
+                        Ok(()) => (true, i18n.tr_args("export.saved", &[("count", &pokemons.len()), ("path", &path.display())])),
+                        Err(e) => (false, i18n.tr_args("export.error", &[("error", &e)])),
+                    });
+                }
+            });
+
+            render_message(ui, &state.message);
+
+            ui.separator();
+            render_cards_section(ui, state, pokemons, selected, names, i18n);
+
+            ui.separator();
+            render_deck_section(ui, state, pokemons, names, i18n);
+        });
+    state.open = open;
+}
+
+fn render_cards_section(
+    ui: &mut egui::Ui,
+    state: &mut ExportState,
+    pokemons: &[&Pokemon],
+    selected: Option<&Pokemon>,
+    names: &LocalizedNames,
+    i18n: &I18n,
+) {
+    ui.label(egui::RichText::new(i18n.tr("cards.title")).color(egui::Color32::from_rgb(40, 80, 120)).strong());
+    ui.horizontal(|ui| {
+        egui::ComboBox::from_id_source("card_format")
+            .selected_text(state.card_options.format.label())
+            .show_ui(ui, |ui| {
+                for format in CardFormat::ALL {
+                    ui.selectable_value(&mut state.card_options.format, format, format.label());
+                }
+            });
+        ui.checkbox(&mut state.card_options.defense, i18n.tr("card.defense"));
+        ui.checkbox(&mut state.card_options.sprite, i18n.tr("cards.sprite"));
+    });
+
+    ui.horizontal(|ui| {
+        ui.add(
+            egui::TextEdit::singleline(&mut state.cards_dir)
+                .hint_text(i18n.tr("cards.dir_hint"))
+                .desired_width(160.0)
+        );
+        let dir = PathBuf::from(state.cards_dir.trim());
+        let has_dir = !state.cards_dir.trim().is_empty();
+        let mut targets = None;
+        if ui.add_enabled(has_dir && selected.is_some(), egui::Button::new(i18n.tr("cards.selected"))).clicked() {
+            targets = selected.map(|pokemon| vec![pokemon]);
+        }
+        if ui.add_enabled(has_dir && !pokemons.is_empty(), egui::Button::new(i18n.tr("cards.list"))).clicked() {
+            targets = Some(pokemons.to_vec());
+        }
+        if let Some(targets) = targets {
+            state.card_message = Some(match card::write_cards(&dir, &targets, names, i18n, &state.card_options) {
+                Ok(count) => (true, i18n.tr_args("cards.saved", &[("count", &count), ("path", &dir.display())])),
+                Err(e) => (false, i18n.tr_args("export.error", &[("error", &e)])),
+            });
+        }
+    });
+    render_message(ui, &state.card_message);
+}
+
+// Mazo de tarjetas de estudio para Anki con la lista filtrada
+fn render_deck_section(ui: &mut egui::Ui, state: &mut ExportState, pokemons: &[&Pokemon], names: &LocalizedNames, i18n: &I18n) {
+    ui.label(egui::RichText::new(i18n.tr("deck.title")).color(egui::Color32::from_rgb(40, 80, 120)).strong());
+    ui.horizontal_wrapped(|ui| {
+        for template in CardTemplate::ALL {
+            let mut enabled = state.deck_templates.contains(&template);
+            if ui.checkbox(&mut enabled, i18n.tr(template.key())).changed() {
+                state.deck_templates.retain(|&t| t != template);
+                if enabled {
+                    state.deck_templates.push(template);
+                    state.deck_templates.sort();
+                }
+            }
+        }
+    });
+
+    ui.horizontal(|ui| {
+        let mut format = state.deck_format;
+        egui::ComboBox::from_id_source("deck_format")
+            .selected_text(format.label())
+            .show_ui(ui, |ui| {
+                for option in DeckFormat::ALL {
+                    ui.selectable_value(&mut format, option, option.label());
+                }
+            });
+        if format != state.deck_format {
+            state.deck_format = format;
+            let mut path = PathBuf::from(state.deck_path.trim());
+            if DeckFormat::ALL.iter().any(|f| path.extension().is_some_and(|e| e == f.extension())) {
+                path.set_extension(format.extension());
+                state.deck_path = path.display().to_string();
+            }
+        }
+        ui.add(
+            egui::TextEdit::singleline(&mut state.deck_path)
+                .hint_text(i18n.tr("deck.path_hint"))
+                .desired_width(180.0)
+        );
+        let path = PathBuf::from(state.deck_path.trim());
+        let can_save = !state.deck_path.trim().is_empty() && !state.deck_templates.is_empty() && !pokemons.is_empty();
+        if ui.add_enabled(can_save, egui::Button::new(i18n.tr("deck.save"))).clicked() {
+            state.deck_message = Some(
+                match flashcards::write_deck(&path, pokemons, &state.deck_templates, state.deck_format, names, i18n) {
+                    Ok(summary) => {
+                        let mut message = i18n.tr_args("deck.saved", &[("count", &summary.cards), ("path", &path.display())]);
+                        if let Some(media_dir) = summary.media_dir {
+                            message += "\n";
+                            message += &i18n.tr_args("deck.media", &[("count", &summary.media), ("path", &media_dir.display())]);
+                        }
+                        (true, message)
+                    }
+                    Err(e) => (false, i18n.tr_args("export.error", &[("error", &e)])),
+                },
+            );
+        }
+    });
+    render_message(ui, &state.deck_message);
+}
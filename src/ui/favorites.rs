@@ -0,0 +1,87 @@
+use crate::storage;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+const FAVORITES_FILE: &str = "favorites.json";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct FavoritesFile {
+    #[serde(default)]
+    favorites: BTreeSet<u16>,
+}
+
+// Pokémon marcados como favoritos, guardados entre sesiones
+#[derive(Debug, Default)]
+pub struct Favorites {
+    ids: BTreeSet<u16>,
+    path: Option<PathBuf>,
+    // Hay cambios sin guardar; se escriben al cerrar
+    dirty: bool,
+}
+
+impl Favorites {
+    pub fn path() -> Option<PathBuf> {
+        storage::data_file(FAVORITES_FILE)
+    }
+
+    // Si el archivo está dañado se avisa y se empieza sin favoritos
+    pub fn load() -> Self {
+        let Some(path) = Self::path() else {
+            return Self::default();
+        };
+        let file = match storage::load_json::<FavoritesFile>(&path) {
+            Ok(file) => file.unwrap_or_default(),
+            Err(e) => {
+                eprintln!("Error al leer {}: {}", path.display(), e);
+                FavoritesFile::default()
+            }
+        };
+        Self {
+            ids: file.favorites,
+            path: Some(path),
+            dirty: false,
+        }
+    }
+
+    // Escribe el archivo solo si algo cambió desde el último guardado
+    pub fn save(&mut self) {
+        if !std::mem::take(&mut self.dirty) {
+            return;
+        }
+        let Some(path) = &self.path else {
+            return;
+        };
+        let file = FavoritesFile { favorites: self.ids.clone() };
+        if let Err(e) = storage::save_json(path, &file) {
+            eprintln!("No se pudo guardar {}: {}", path.display(), e);
+        }
+    }
+
+    pub fn contains(&self, id: u16) -> bool {
+        self.ids.contains(&id)
+    }
+
+    pub fn toggle(&mut self, id: u16) {
+        if !self.ids.remove(&id) {
+            self.ids.insert(id);
+        }
+        self.dirty = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toggle_marks_and_unmarks() {
+        let mut favorites = Favorites::default();
+        favorites.toggle(25);
+        assert!(favorites.contains(25) && favorites.dirty);
+        favorites.save();
+        assert!(!favorites.dirty);
+        favorites.toggle(25);
+        assert!(!favorites.contains(25) && favorites.dirty);
+    }
+}
@@ -0,0 +1,289 @@
+use super::render_vista_type_badge;
+use super::sprites::{render_sprite, SpriteCache};
+use crate::collections::{CollectionKind, CollectionStore};
+use crate::database::Database;
+use crate::generator::{self, GeneratorConstraints};
+use crate::i18n::I18n;
+use crate::pokemon::Pokemon;
+use crate::rng::{Date, Rng};
+use crate::team::{TeamMember, MAX_TEAM_SIZE};
+use eframe::egui;
+
+// Límites de los controles de total de estadísticas
+const TOTAL_RANGE: std::ops::RangeInclusive<u16> = 150..=800;
+
+pub struct GeneratorState {
+    constraints: GeneratorConstraints,
+    use_min_total: bool,
+    use_max_total: bool,
+    min_total: u16,
+    max_total: u16,
+    team_size: usize,
+    // Semilla escrita por el usuario; vacía = aleatoria
+    seed_text: String,
+    last_seed: Option<u64>,
+    // Resultado de la última generación (id y forma)
+    results: Vec<TeamMember>,
+    error: Option<String>,
+    today: Date,
+}
+
+impl Default for GeneratorState {
+    fn default() -> Self {
+        Self {
+            constraints: GeneratorConstraints::default(),
+            use_min_total: false,
+            use_max_total: false,
+            min_total: 300,
+            max_total: 600,
+            team_size: MAX_TEAM_SIZE,
+            seed_text: String::new(),
+            last_seed: None,
+            results: Vec::new(),
+            error: None,
+            today: Date::today(),
+        }
+    }
+}
+
+impl GeneratorState {
+    fn constraints(&self) -> GeneratorConstraints {
+        GeneratorConstraints {
+            min_total: self.use_min_total.then_some(self.min_total),
+            max_total: self.use_max_total.then_some(self.max_total),
+            ..self.constraints.clone()
+        }
+    }
+
+    fn seed(&self) -> u64 {
+        self.seed_text.trim().parse().unwrap_or_else(|_| Rng::random_seed())
+    }
+
+    fn generate(&mut self, database: &Database, size: usize, i18n: &I18n) {
+        let seed = self.seed();
+        let mut rng = Rng::new(seed);
+        let constraints = self.constraints();
+        self.last_seed = Some(seed);
+        self.error = None;
+        self.results.clear();
+
+        let generated = if size == 1 {
+            generator::random_pokemon(database, &constraints, &mut rng)
+                .map(|p| vec![p])
+                .ok_or(generator::GeneratorError::NotEnoughCandidates { found: 0, wanted: 1 })
+        } else {
+            generator::random_team(database, &constraints, size, &mut rng)
+        };
+        match generated {
+            Ok(pokemons) => self.results = pokemons.into_iter().map(TeamMember::from_pokemon).collect(),
+            Err(generator::GeneratorError::NotEnoughCandidates { found, wanted }) => {
+                self.error = Some(i18n.tr_args(
+                    "generator.not_enough",
+                    &[("found", &found), ("wanted", &wanted)],
+                ));
+            }
+        }
+    }
+}
+
+// Devuelve el Pokémon que el usuario quiere ver en la Pokédex
+pub fn render_vista_generator_panel(
+    ui: &mut egui::Ui,
+    database: &Database,
+    store: &mut CollectionStore,
+    state: &mut GeneratorState,
+    sprites: &mut SpriteCache,
+    i18n: &I18n,
+) -> Option<u16> {
+    let mut show = None;
+    let vista_panel_frame = egui::Frame::none()
+        .fill(egui::Color32::from_rgba_premultiplied(240, 248, 255, 200))
+        .rounding(egui::Rounding::same(8.0))
+        .stroke(egui::Stroke::new(1.0, egui::Color32::from_rgb(180, 200, 220)))
+        .inner_margin(egui::style::Margin::same(8.0))
+        .shadow(egui::epaint::Shadow {
+            extrusion: 6.0,
+            color: egui::Color32::from_rgba_premultiplied(0, 0, 0, 40),
+        });
+
+    vista_panel_frame.show(ui, |ui| {
+        ui.set_width(ui.available_width());
+        ui.set_height(450.0);
+
+        ui.horizontal_top(|ui| {
+            ui.vertical(|ui| {
+                ui.set_width(ui.available_width() - 240.0);
+                ui.heading(
+                    egui::RichText::new(i18n.tr("generator.title"))
+                        .size(24.0)
+                        .color(egui::Color32::from_rgb(40, 110, 80))
+                        .strong()
+                );
+                render_constraints(ui, database, state, i18n);
+
+                ui.add_space(6.0);
+                ui.horizontal(|ui| {
+                    if ui.button(i18n.tr("generator.random_pokemon")).clicked() {
+                        state.generate(database, 1, i18n);
+                    }
+                    ui.add(egui::Slider::new(&mut state.team_size, 2..=MAX_TEAM_SIZE));
+                    if ui.button(i18n.tr("generator.random_team")).clicked() {
+                        state.generate(database, state.team_size, i18n);
+                    }
+                });
+
+                if let Some(error) = &state.error {
+                    ui.label(egui::RichText::new(error).color(egui::Color32::from_rgb(200, 60, 50)));
+                }
+                if let Some(seed) = state.last_seed {
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            egui::RichText::new(i18n.tr_args("generator.seed_used", &[("seed", &seed)]))
+                                .size(12.0)
+                                .color(egui::Color32::from_rgb(120, 140, 160))
+                        );
+                        if ui.small_button(i18n.tr("generator.reuse_seed")).clicked() {
+                            state.seed_text = seed.to_string();
+                        }
+                    });
+                }
+
+                egui::ScrollArea::vertical().id_source("generator_results").show(ui, |ui| {
+                    for member in &state.results {
+                        let Some(pokemon) = database.get_pokemon_form(member.id, member.form.as_deref()) else {
+                            continue;
+                        };
+                        if render_result_row(ui, database, pokemon, sprites, i18n) {
+                            show = Some(pokemon.id);
+                        }
+                    }
+                    if state.results.len() > 1 && ui.button(i18n.tr("generator.save_team")).clicked() {
+                        let number = store.indices_of(CollectionKind::Team).len() + 1;
+                        let name = i18n.tr_args("team.default_name", &[("number", &number)]);
+                        let index = store.create(&name, CollectionKind::Team);
+                        if let Some(team) = store.get_mut(index) {
+                            team.members = state.results.clone();
+                        }
+                        store.save();
+                    }
+                });
+            });
+
+            ui.separator();
+            ui.vertical(|ui| {
+                if render_pokemon_of_the_day(ui, database, state.today, sprites, i18n) {
+                    show = generator::pokemon_of_the_day(database, state.today).map(|p| p.id);
+                }
+            });
+        });
+    });
+    show
+}
+
+fn render_constraints(ui: &mut egui::Ui, database: &Database, state: &mut GeneratorState, i18n: &I18n) {
+    ui.horizontal_wrapped(|ui| {
+        ui.label(
+            egui::RichText::new(i18n.tr("generator.generations"))
+                .color(egui::Color32::from_rgb(40, 80, 120))
+        );
+        for generation in database.generations() {
+            let mut allowed = state.constraints.generations.contains(&generation);
+            let label = i18n.tr_args("filter.generation", &[("number", &generation)]);
+            if ui.checkbox(&mut allowed, label).changed() {
+                if allowed {
+                    state.constraints.generations.insert(generation);
+                } else {
+                    state.constraints.generations.remove(&generation);
+                }
+            }
+        }
+    });
+    ui.horizontal_wrapped(|ui| {
+        ui.checkbox(&mut state.constraints.exclude_legendaries, i18n.tr("generator.exclude_legendaries"));
+        ui.checkbox(&mut state.constraints.exclude_megas, i18n.tr("generator.exclude_megas"));
+        ui.checkbox(&mut state.constraints.unique_types, i18n.tr("generator.unique_types"));
+        ui.checkbox(&mut state.constraints.species_clause, i18n.tr("generator.species_clause"));
+    });
+    ui.horizontal(|ui| {
+        ui.checkbox(&mut state.use_min_total, i18n.tr("generator.min_total"));
+        ui.add_enabled(state.use_min_total, egui::DragValue::new(&mut state.min_total).clamp_range(TOTAL_RANGE));
+        ui.checkbox(&mut state.use_max_total, i18n.tr("generator.max_total"));
+        ui.add_enabled(state.use_max_total, egui::DragValue::new(&mut state.max_total).clamp_range(TOTAL_RANGE));
+    });
+    ui.horizontal(|ui| {
+        ui.label(
+            egui::RichText::new(i18n.tr("generator.seed"))
+                .color(egui::Color32::from_rgb(40, 80, 120))
+        );
+        ui.add(
+            egui::TextEdit::singleline(&mut state.seed_text)
+                .hint_text(i18n.tr("generator.seed_hint"))
+                .desired_width(140.0)
+        );
+    });
+}
+
+// Fila de resultado; devuelve true si se pulsa para verlo en la Pokédex
+fn render_result_row(
+    ui: &mut egui::Ui,
+    database: &Database,
+    pokemon: &Pokemon,
+    sprites: &mut SpriteCache,
+    i18n: &I18n,
+) -> bool {
+    let mut clicked = false;
+    ui.horizontal(|ui| {
+        let texture = sprites.get(ui.ctx(), pokemon, false);
+        render_sprite(ui, texture.as_ref(), 40.0);
+        let name = format!("#{:03} {}", pokemon.id, i18n.pokemon_name(pokemon, database.names()));
+        clicked = ui
+            .link(egui::RichText::new(name).color(egui::Color32::from_rgb(40, 110, 80)).strong())
+            .clicked();
+        for pokemon_type in &pokemon.types {
+            render_vista_type_badge(ui, pokemon_type, &i18n.type_name(*pokemon_type, database.names()));
+        }
+        ui.label(
+            egui::RichText::new(format!("{} {}", i18n.tr("stat.total"), pokemon.total_stats()))
+                .color(egui::Color32::from_rgb(120, 140, 160))
+        );
+    });
+    clicked
+}
+
+fn render_pokemon_of_the_day(
+    ui: &mut egui::Ui,
+    database: &Database,
+    today: Date,
+    sprites: &mut SpriteCache,
+    i18n: &I18n,
+) -> bool {
+    ui.label(
+        egui::RichText::new(i18n.tr("generator.of_the_day"))
+            .size(18.0)
+            .color(egui::Color32::from_rgb(40, 80, 120))
+            .strong()
+    );
+    ui.label(
+        egui::RichText::new(today.to_string())
+            .color(egui::Color32::from_rgb(120, 140, 160))
+    );
+    let Some(pokemon) = generator::pokemon_of_the_day(database, today) else {
+        return false;
+    };
+
+    let texture = sprites.get(ui.ctx(), pokemon, false);
+    render_sprite(ui, texture.as_ref(), 96.0);
+    ui.label(
+        egui::RichText::new(format!("#{:03} {}", pokemon.id, i18n.pokemon_name(pokemon, database.names())))
+            .size(20.0)
+            .color(egui::Color32::from_rgb(40, 110, 80))
+            .strong()
+    );
+    ui.horizontal(|ui| {
+        for pokemon_type in &pokemon.types {
+            render_vista_type_badge(ui, pokemon_type, &i18n.type_name(*pokemon_type, database.names()));
+        }
+    });
+    ui.label(format!("{} {}", i18n.tr("stat.total"), pokemon.total_stats()));
+    ui.button(i18n.tr("generator.show")).clicked()
+}
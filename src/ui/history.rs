@@ -0,0 +1,249 @@
+use crate::storage;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+const HISTORY_FILE: &str = "history.json";
+// Límite de cada pila de navegación; lo más antiguo se descarta
+const MAX_STACK: usize = 100;
+// Pokémon en la lista de vistos recientemente
+pub const MAX_RECENT: usize = 15;
+// Segundos entre guardados mientras la aplicación está abierta; al cerrar se guarda siempre
+const SAVE_INTERVAL: f64 = 30.0;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct HistoryFile {
+    #[serde(default)]
+    back: Vec<u16>,
+    #[serde(default)]
+    forward: Vec<u16>,
+    #[serde(default)]
+    recent: Vec<u16>,
+}
+
+// Historial de selección con atrás/adelante y vistos recientemente, guardado entre sesiones
+#[derive(Debug, Default)]
+pub struct History {
+    back: Vec<u16>,
+    forward: Vec<u16>,
+    // El más reciente primero, sin repetidos
+    recent: Vec<u16>,
+    path: Option<PathBuf>,
+    // Hay cambios sin guardar; se escriben en save_if_due() o al cerrar
+    dirty: bool,
+    last_save: f64,
+}
+
+fn push_bounded(stack: &mut Vec<u16>, id: u16) {
+    stack.push(id);
+    if stack.len() > MAX_STACK {
+        stack.remove(0);
+    }
+}
+
+impl History {
+    pub fn path() -> Option<PathBuf> {
+        storage::data_file(HISTORY_FILE)
+    }
+
+    // Si el archivo está dañado se avisa y se empieza con el historial vacío
+    pub fn load() -> Self {
+        let Some(path) = Self::path() else {
+            return Self::default();
+        };
+        let file = match storage::load_json::<HistoryFile>(&path) {
+            Ok(file) => file.unwrap_or_default(),
+            Err(e) => {
+                eprintln!("Error al leer {}: {}", path.display(), e);
+                HistoryFile::default()
+            }
+        };
+        Self::from_file(file, Some(path))
+    }
+
+    // Por si el archivo se editó a mano, se recortan las pilas a sus límites
+    fn from_file(file: HistoryFile, path: Option<PathBuf>) -> Self {
+        let mut history = Self {
+            back: file.back,
+            forward: file.forward,
+            recent: file.recent,
+            path,
+            ..Self::default()
+        };
+        history.back.drain(..history.back.len().saturating_sub(MAX_STACK));
+        history.forward.drain(..history.forward.len().saturating_sub(MAX_STACK));
+        history.recent.truncate(MAX_RECENT);
+        history
+    }
+
+    // Escribe el archivo solo si algo cambió desde el último guardado
+    pub fn save(&mut self) {
+        if !std::mem::take(&mut self.dirty) {
+            return;
+        }
+        let Some(path) = &self.path else {
+            return;
+        };
+        let file = HistoryFile {
+            back: self.back.clone(),
+            forward: self.forward.clone(),
+            recent: self.recent.clone(),
+        };
+        if let Err(e) = storage::save_json(path, &file) {
+            eprintln!("No se pudo guardar {}: {}", path.display(), e);
+        }
+    }
+
+    // Se llama en cada frame con el reloj de egui
+    pub fn save_if_due(&mut self, now: f64) {
+        if self.dirty && now - self.last_save >= SAVE_INTERVAL {
+            self.last_save = now;
+            self.save();
+        }
+    }
+
+    fn touch(&mut self, id: u16) {
+        self.recent.retain(|&r| r != id);
+        self.recent.insert(0, id);
+        self.recent.truncate(MAX_RECENT);
+        self.dirty = true;
+    }
+
+    // Nueva selección: la anterior pasa a la pila de atrás y se pierde el camino hacia delante
+    pub fn visit(&mut self, previous: Option<u16>, id: u16) {
+        if let Some(previous) = previous {
+            push_bounded(&mut self.back, previous);
+        }
+        self.forward.clear();
+        self.touch(id);
+    }
+
+    // Devuelve el Pokémon al que volver, guardando el actual para poder ir hacia delante
+    pub fn back(&mut self, current: Option<u16>) -> Option<u16> {
+        let previous = self.back.pop()?;
+        if let Some(current) = current {
+            push_bounded(&mut self.forward, current);
+        }
+        self.touch(previous);
+        Some(previous)
+    }
+
+    pub fn forward(&mut self, current: Option<u16>) -> Option<u16> {
+        let next = self.forward.pop()?;
+        if let Some(current) = current {
+            push_bounded(&mut self.back, current);
+        }
+        self.touch(next);
+        Some(next)
+    }
+
+    pub fn can_go_back(&self) -> bool {
+        !self.back.is_empty()
+    }
+
+    pub fn can_go_forward(&self) -> bool {
+        !self.forward.is_empty()
+    }
+
+    pub fn recent(&self) -> &[u16] {
+        &self.recent
+    }
+
+    pub fn clear(&mut self) {
+        self.back.clear();
+        self.forward.clear();
+        self.recent.clear();
+        self.dirty = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn back_and_forward_walk_the_visits() {
+        let mut history = History::default();
+        history.visit(None, 1);
+        history.visit(Some(1), 2);
+        history.visit(Some(2), 3);
+        assert_eq!(history.back(Some(3)), Some(2));
+        assert_eq!(history.back(Some(2)), Some(1));
+        assert!(!history.can_go_back());
+        assert_eq!(history.back(Some(1)), None);
+        assert_eq!(history.forward(Some(1)), Some(2));
+        assert_eq!(history.forward(Some(2)), Some(3));
+        assert!(!history.can_go_forward());
+    }
+
+    #[test]
+    fn a_new_visit_clears_forward() {
+        let mut history = History::default();
+        history.visit(None, 1);
+        history.visit(Some(1), 2);
+        assert_eq!(history.back(Some(2)), Some(1));
+        assert!(history.can_go_forward());
+        history.visit(Some(1), 5);
+        assert!(!history.can_go_forward());
+        assert_eq!(history.back(Some(5)), Some(1));
+    }
+
+    #[test]
+    fn stacks_drop_the_oldest_entries() {
+        let mut history = History::default();
+        let mut previous = None;
+        for id in 1..=(MAX_STACK as u16 + 10) {
+            history.visit(previous, id);
+            previous = Some(id);
+        }
+        assert_eq!(history.back.len(), MAX_STACK);
+        // La entrada más antigua que queda es la 10 (de la 1 a la 9 se descartaron)
+        assert_eq!(history.back.first(), Some(&10));
+        assert_eq!(history.recent().len(), MAX_RECENT);
+    }
+
+    #[test]
+    fn loaded_files_are_trimmed_to_the_limits() {
+        let ids: Vec<u16> = (1..=200).collect();
+        let file = HistoryFile { back: ids.clone(), forward: ids.clone(), recent: ids };
+        let history = History::from_file(file, None);
+        assert_eq!(history.back.len(), MAX_STACK);
+        // Se conservan las entradas más cercanas, que están al final de las pilas
+        assert_eq!(history.back.last(), Some(&200));
+        assert_eq!(history.forward.first(), Some(&101));
+        assert_eq!(history.recent(), &(1..=MAX_RECENT as u16).collect::<Vec<_>>()[..]);
+        assert!(!history.dirty);
+    }
+
+    #[test]
+    fn recent_is_newest_first_without_duplicates() {
+        let mut history = History::default();
+        history.visit(None, 1);
+        history.visit(Some(1), 2);
+        history.visit(Some(2), 3);
+        history.visit(Some(3), 1);
+        assert_eq!(history.recent(), &[1, 3, 2]);
+        history.back(Some(1));
+        assert_eq!(history.recent(), &[3, 1, 2]);
+    }
+
+    #[test]
+    fn saves_only_when_dirty_and_due() {
+        let mut history = History::default();
+        history.save_if_due(100.0);
+        assert_eq!(history.last_save, 0.0);
+
+        history.visit(None, 1);
+        history.save_if_due(SAVE_INTERVAL - 1.0);
+        assert!(history.dirty);
+        history.save_if_due(SAVE_INTERVAL);
+        assert!(!history.dirty);
+        assert_eq!(history.last_save, SAVE_INTERVAL);
+
+        history.clear();
+        assert!(history.dirty);
+        history.save_if_due(SAVE_INTERVAL + 1.0);
+        assert!(history.dirty);
+        history.save();
+        assert!(!history.dirty);
+    }
+}
@@ -1,65 +1,389 @@
-use crate::database::Database;
-use crate::pokemon::PokemonType;  
+mod battle;
+mod collections;
+mod dashboard;
+mod export;
+mod favorites;
+mod generator;
+mod history;
+mod quiz;
+mod settings;
+mod shortcuts;
+mod similar;
+mod speed;
+mod sprites;
+mod team_builder;
+mod type_chart;
+
+use crate::analytics::{Metric, RankScope, Ranking, StatRankings};
+use crate::collections::CollectionStore;
+use crate::database::{Database, PokemonFilter};
+use crate::i18n::{I18n, Language, LocalizedNames};
+use crate::pokemon::{Pokemon, PokemonType, StatKind};
 use eframe::egui;
+use battle::{render_vista_battle_panel, BattleState};
+use collections::{render_vista_collections_panel, CollectionsState};
+use dashboard::{render_vista_dashboard_panel, DashboardState};
+use export::{render_export_window, ExportState};
+use favorites::Favorites;
+use generator::{render_vista_generator_panel, GeneratorState};
+use history::History;
+use quiz::{render_vista_quiz_panel, QuizState};
+use serde::{Deserialize, Serialize};
+use settings::{render_settings_window, render_sort_selector, Session, Theme};
+use shortcuts::{render_shortcuts_help, Action, Keymap};
+use similar::{render_similar_section, SimilarState};
+use speed::{render_vista_speed_panel, SpeedState};
+use sprites::{paint_sprite, render_sprite, SpriteCache};
+use team_builder::{render_vista_team_builder_panel, TeamBuilderState};
+use type_chart::{render_vista_type_chart_panel, TypeChartState};
+
+pub use settings::Settings;
+
+// Cuántas filas salta Re Pág / Av Pág
+const PAGE_SIZE: isize = 10;
+
+fn search_field_id() -> egui::Id {
+    egui::Id::new("search_field")
+}
+
+// Pantallas principales de la aplicación
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+enum View {
+    #[default]
+    Pokedex,
+    Team,
+    Collections,
+    Generator,
+    Quiz,
+    Battle,
+    Speed,
+    TypeChart,
+    Dashboard,
+}
+
+// Ámbito del ranking que se muestra junto a cada estadística
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RankView {
+    All,
+    Type,
+    Generation,
+}
+
+impl RankView {
+    const ALL: [RankView; 3] = [RankView::All, RankView::Type, RankView::Generation];
+
+    fn key(&self) -> &'static str {
+        match self {
+            RankView::All => "rank.all",
+            RankView::Type => "rank.type",
+            RankView::Generation => "rank.generation",
+        }
+    }
+
+    // Se usa el tipo principal del Pokémon para el ranking por tipo
+    fn scope_for(&self, pokemon: &Pokemon) -> RankScope {
+        match (self, pokemon.primary_type()) {
+            (RankView::Type, Some(primary)) => RankScope::Type(*primary),
+            (RankView::Generation, _) => RankScope::Generation(pokemon.generation),
+            _ => RankScope::All,
+        }
+    }
+}
+
+impl View {
+    const ALL: [View; 9] = [
+        View::Pokedex,
+        View::Team,
+        View::Collections,
+        View::Generator,
+        View::Quiz,
+        View::Battle,
+        View::Speed,
+        View::TypeChart,
+        View::Dashboard,
+    ];
+
+    fn key(&self) -> &'static str {
+        match self {
+            View::Pokedex => "tab.pokedex",
+            View::Team => "tab.team",
+            View::Collections => "tab.collections",
+            View::Generator => "tab.generator",
+            View::Quiz => "tab.quiz",
+            View::Battle => "tab.battle",
+            View::Speed => "tab.speed",
+            View::TypeChart => "tab.type_chart",
+            View::Dashboard => "tab.dashboard",
+        }
+    }
+}
 
 pub struct PokedexApp {
     database: Database,
     selected_pokemon: Option<u16>,
-    search_term: String,
+    selected_form: Option<String>,
+    filter: PokemonFilter,
+    view: View,
+    dashboard: DashboardState,
+    team_builder: TeamBuilderState,
+    collections: CollectionStore,
+    collections_view: CollectionsState,
+    generator: GeneratorState,
+    quiz: QuizState,
+    battle: BattleState,
+    speed: SpeedState,
+    type_chart: TypeChartState,
+    similar: SimilarState,
+    export: ExportState,
+    // Nombre de la colección por la que se filtra la lista
+    collection_filter: Option<String>,
+    favorites: Favorites,
+    compare_pokemon: Option<u16>,
+    history: History,
+    keymap: Keymap,
+    show_shortcuts_help: bool,
+    scroll_to_selected: bool,
+    sprites: SpriteCache,
+    show_shiny: bool,
+    rank_view: RankView,
+    settings: Settings,
+    show_settings: bool,
+    i18n: I18n,
 }
 
 impl PokedexApp {
-    pub fn new(database: Database) -> Self {
-        Self {
+    pub fn new(database: Database, settings: Settings) -> Self {
+        let mut app = Self {
             database,
             selected_pokemon: None,
-            search_term: String::new(),
+            selected_form: None,
+            filter: PokemonFilter::default(),
+            view: View::Pokedex,
+            dashboard: DashboardState::default(),
+            team_builder: TeamBuilderState::default(),
+            collections: CollectionStore::load(),
+            collections_view: CollectionsState::default(),
+            generator: GeneratorState::default(),
+            quiz: QuizState::load(),
+            battle: BattleState::default(),
+            speed: SpeedState::default(),
+            type_chart: TypeChartState::default(),
+            similar: SimilarState::default(),
+            export: ExportState::default(),
+            collection_filter: None,
+            favorites: Favorites::load(),
+            compare_pokemon: None,
+            history: History::load(),
+            keymap: Keymap::load(),
+            show_shortcuts_help: false,
+            scroll_to_selected: false,
+            sprites: SpriteCache::from_env(),
+            show_shiny: false,
+            rank_view: RankView::All,
+            i18n: I18n::new(settings.language),
+            show_settings: false,
+            settings,
+        };
+        if app.settings.restore_session {
+            app.restore_session();
+        }
+        app
+    }
+
+    // Recupera la búsqueda, los filtros y la selección de la sesión anterior
+    fn restore_session(&mut self) {
+        let session = self.settings.session.clone();
+        self.view = session.view;
+        self.filter.search = session.search;
+        self.filter.pokemon_type = session.pokemon_type;
+        self.filter.second_type = session.second_type.filter(|_| session.pokemon_type.is_some());
+        self.filter.generation = session.generation;
+        self.collection_filter = session.collection;
+        self.sync_collection_filter();
+        // Un Pokémon o una forma que ya no existan no se restauran
+        self.selected_pokemon = session.selected.filter(|&id| self.database.get_pokemon(id).is_some());
+        self.selected_form = session
+            .selected_form
+            .filter(|form| self.selected_pokemon.is_some_and(|id| self.database.get_pokemon_form(id, Some(form)).is_some()));
+        self.scroll_to_selected = self.selected_pokemon.is_some();
+    }
+
+    fn capture_session(&mut self) {
+        self.settings.language = self.i18n.language();
+        self.settings.session = Session {
+            view: self.view,
+            search: self.filter.search.clone(),
+            pokemon_type: self.filter.pokemon_type,
+            second_type: self.filter.second_type,
+            generation: self.filter.generation,
+            collection: self.collection_filter.clone(),
+            selected: self.selected_pokemon,
+            selected_form: self.selected_form.clone(),
+        };
+    }
+
+    // El filtro de la lista usa las especies de la colección elegida; si ya no existe se quita
+    fn sync_collection_filter(&mut self) {
+        let collection = self
+            .collection_filter
+            .as_ref()
+            .and_then(|name| self.collections.all().iter().find(|c| &c.name == name));
+        if collection.is_none() {
+            self.collection_filter = None;
+        }
+        self.filter.species = collection.map(|c| c.species_ids());
+    }
+
+    // Cambia la selección guardando la anterior en el historial
+    fn select(&mut self, id: u16) {
+        if self.selected_pokemon == Some(id) {
+            return;
+        }
+        self.history.visit(self.selected_pokemon, id);
+        self.selected_pokemon = Some(id);
+        self.selected_form = None;
+    }
+
+    // Selecciona la fila de la lista tal cual: si el filtro eligió una forma, se abre esa forma
+    fn select_entry(&mut self, id: u16, form: Option<String>) {
+        self.select(id);
+        self.selected_form = form;
+    }
+
+    fn go_back(&mut self) {
+        if let Some(previous) = self.history.back(self.selected_pokemon) {
+            self.selected_pokemon = Some(previous);
+            self.selected_form = None;
+            self.scroll_to_selected = true;
+        }
+    }
+
+    fn go_forward(&mut self) {
+        if let Some(next) = self.history.forward(self.selected_pokemon) {
+            self.selected_pokemon = Some(next);
+            self.selected_form = None;
+            self.scroll_to_selected = true;
+        }
+    }
+
+    // Mueve la selección dentro de la lista filtrada
+    fn move_selection(&mut self, offset: isize) {
+        let entries: Vec<(u16, Option<String>)> = self
+            .settings
+            .sorted_list(&self.database, &self.filter, self.i18n.language())
+            .iter()
+            .map(|p| (p.id, p.form.clone()))
+            .collect();
+        if entries.is_empty() {
+            return;
+        }
+
+        let last = entries.len() as isize - 1;
+        let index = match self.selected_pokemon.and_then(|id| entries.iter().position(|(x, _)| *x == id)) {
+            Some(current) => (current as isize + offset).clamp(0, last),
+            None if offset < 0 => last,
+            None => 0,
+        };
+        let (id, form) = entries[index as usize].clone();
+        self.select_entry(id, form);
+        self.scroll_to_selected = true;
+    }
+
+    fn handle_shortcuts(&mut self, ctx: &egui::Context) {
+        for action in self.keymap.pressed_actions(ctx) {
+            match action {
+                Action::SelectNext => self.move_selection(1),
+                Action::SelectPrevious => self.move_selection(-1),
+                Action::PageDown => self.move_selection(PAGE_SIZE),
+                Action::PageUp => self.move_selection(-PAGE_SIZE),
+                Action::FocusSearch => ctx.memory_mut(|mem| mem.request_focus(search_field_id())),
+                Action::ClearSearch => {
+                    if self.show_shortcuts_help {
+                        self.show_shortcuts_help = false;
+                    } else {
+                        self.filter.search.clear();
+                        ctx.memory_mut(|mem| mem.surrender_focus(search_field_id()));
+                    }
+                }
+                Action::ToggleFavorite => {
+                    if let Some(id) = self.selected_pokemon {
+                        self.favorites.toggle(id);
+                    }
+                }
+                Action::ToggleCompare => {
+                    self.compare_pokemon = match (self.compare_pokemon, self.selected_pokemon) {
+                        (Some(current), Some(id)) if current == id => None,
+                        (_, selected) => selected,
+                    };
+                }
+                Action::HistoryBack => self.go_back(),
+                Action::HistoryForward => self.go_forward(),
+                Action::ToggleHelp => self.show_shortcuts_help = !self.show_shortcuts_help,
+            }
+        }
+
+        // Botones laterales del ratón
+        let (back, forward) = ctx.input(|i| {
+            (
+                i.pointer.button_pressed(egui::PointerButton::Extra1),
+                i.pointer.button_pressed(egui::PointerButton::Extra2),
+            )
+        });
+        if back {
+            self.go_back();
+        }
+        if forward {
+            self.go_forward();
+        }
+    }
+
+    fn navigate(&mut self, navigation: Navigation) {
+        match navigation {
+            Navigation::Back => self.go_back(),
+            Navigation::Forward => self.go_forward(),
+            Navigation::Show(id) => {
+                self.select(id);
+                self.view = View::Pokedex;
+                self.scroll_to_selected = true;
+            }
+            Navigation::ClearHistory => self.history.clear(),
         }
     }
 }
 
 impl eframe::App for PokedexApp {
-    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        // Estilo global para simular Windows Vista
-        let mut style = (*ctx.style()).clone();
-        
+    fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
+        self.handle_shortcuts(ctx);
+        self.history.save_if_due(ctx.input(|i| i.time));
+
+        // Estilo global según el tema elegido
+        match self.settings.theme {
+            Theme::Vista => apply_vista_style(ctx),
+            Theme::Classic => ctx.set_style(egui::Style {
+                visuals: egui::Visuals::light(),
+                ..egui::Style::default()
+            }),
+        }
+        if let Some(native) = frame.info().native_pixels_per_point {
+            let pixels_per_point = native * self.settings.zoom;
+            if (ctx.pixels_per_point() - pixels_per_point).abs() > f32::EPSILON {
+                ctx.set_pixels_per_point(pixels_per_point);
+            }
+            // El tamaño de la ventana se guarda sin el zoom de la aplicación
+            let scale = ctx.pixels_per_point() / native;
+            self.settings.window.capture(&frame.info().window_info, scale);
+        }
+
         // Colores base de Windows Vista
-        let vista_blue = egui::Color32::from_rgb(81, 111, 170);         // Azul base de Vista
         let vista_light_blue = egui::Color32::from_rgb(135, 162, 217);  // Azul claro para degradados
-        let vista_dark_blue = egui::Color32::from_rgb(37, 64, 122);     // Azul oscuro para degradados
-        let vista_window = egui::Color32::from_rgba_premultiplied(240, 248, 255, 180); // Ventana semi-transparente
-        
-        // Aplicar estilos de Windows Vista
-        style.visuals.window_fill = vista_window;
-        style.visuals.panel_fill = vista_window;
-        style.visuals.widgets.noninteractive.bg_fill = egui::Color32::from_rgba_premultiplied(240, 248, 255, 220);
-        style.visuals.widgets.inactive.bg_fill = egui::Color32::from_rgba_premultiplied(200, 220, 240, 200);
-        style.visuals.widgets.hovered.bg_fill = egui::Color32::from_rgba_premultiplied(210, 230, 250, 220);
-        style.visuals.widgets.active.bg_fill = egui::Color32::from_rgba_premultiplied(180, 210, 240, 230);
-        
-        // Sombras y bordes estilo Aero
-        style.visuals.window_shadow = egui::epaint::Shadow {
-            extrusion: 10.0,
-            color: egui::Color32::from_rgba_premultiplied(0, 0, 0, 40),
-        };
-        style.visuals.popup_shadow = egui::epaint::Shadow {
-            extrusion: 8.0,
-            color: egui::Color32::from_rgba_premultiplied(0, 0, 0, 40),
+        let background = match self.settings.theme {
+            Theme::Vista => vista_light_blue,
+            Theme::Classic => ctx.style().visuals.panel_fill,
         };
-        
-        // Bordes redondeados para todos los widgets
-        style.visuals.window_rounding = egui::Rounding::same(8.0);
-        style.visuals.menu_rounding = egui::Rounding::same(6.0);
-        style.visuals.widgets.noninteractive.rounding = egui::Rounding::same(4.0);
-        style.visuals.widgets.inactive.rounding = egui::Rounding::same(4.0);
-        style.visuals.widgets.hovered.rounding = egui::Rounding::same(4.0);
-        style.visuals.widgets.active.rounding = egui::Rounding::same(4.0);
-        
-        ctx.set_style(style);
 
         // Fondo principal con degradado tipo Vista (simulado con un panel)
         let vista_bg_frame = egui::Frame::none()
-            .fill(vista_light_blue) // Color base para el degradado
+            .fill(background) // Color base para el degradado
             .inner_margin(egui::style::Margin::same(8.0))
             .shadow(egui::epaint::Shadow {
                 extrusion: 0.0,
@@ -71,19 +395,19 @@ impl eframe::App for PokedexApp {
             .frame(vista_bg_frame)
             .show(ctx, |ui| {
                 // Barra de título estilo Windows Vista
-                render_title_bar(ui);
+                render_title_bar(ui, &self.i18n);
                 
                 // Header
                 ui.vertical_centered(|ui| {
                     ui.add_space(10.0);
                     ui.heading(
-                        egui::RichText::new("PokéDex")
+                        egui::RichText::new(self.i18n.tr("app.header"))
                             .size(40.0)
                             .color(egui::Color32::from_rgb(40, 110, 80))
                             .strong()
                     );
                     ui.label(
-                        egui::RichText::new("GRAAWW POKEDEX EN RUST")
+                        egui::RichText::new(self.i18n.tr("app.subtitle"))
                             .size(18.0)
                             .color(egui::Color32::from_rgb(60, 130, 100))
                             .strong()
@@ -92,28 +416,298 @@ impl eframe::App for PokedexApp {
                 });
 
                 // Search bar estilo Vista
-                render_vista_search_bar(ui, &mut self.search_term);
-                ui.add_space(15.0);
+                let navigation = render_vista_search_bar(
+                    ui,
+                    &mut self.filter.search,
+                    &mut self.export.open,
+                    &self.history,
+                    &self.database,
+                    &self.i18n,
+                );
+                if let Some(navigation) = navigation {
+                    self.navigate(navigation);
+                }
+                ui.add_space(8.0);
+                render_vista_toolbar(
+                    ui,
+                    &mut self.view,
+                    &mut self.filter,
+                    &self.database,
+                    &self.collections,
+                    &mut self.collection_filter,
+                    &self.i18n,
+                );
+                self.sync_collection_filter();
+                ui.add_space(8.0);
 
-                // Main content - Split view con estilo Vista
-                ui.horizontal(|ui| {
-                    // Left panel - Pokemon List
-                    render_vista_pokemon_list_panel(ui, &self.database, &self.search_term, &mut self.selected_pokemon);
+                match self.view {
+                    View::Pokedex => {
+                        // Main content - Split view con estilo Vista
+                        ui.horizontal(|ui| {
+                            // Left panel - Pokemon List
+                            let clicked = render_vista_pokemon_list_panel(
+                                ui,
+                                &self.settings.sorted_list(&self.database, &self.filter, self.i18n.language()),
+                                self.selected_pokemon,
+                                &self.favorites,
+                                &mut self.sprites,
+                                self.scroll_to_selected,
+                                &mut self.settings,
+                                &self.i18n,
+                                self.database.names(),
+                            );
+                            self.scroll_to_selected = false;
+                            if let Some((id, form)) = clicked {
+                                self.select_entry(id, form);
+                            }
                     
-                    ui.add_space(15.0);
+                            ui.add_space(15.0);
                     
-                    // Right panel - Pokemon Details
-                    render_vista_pokemon_details_panel(ui, &self.database, self.selected_pokemon);
-                });
+                            // Right panel - Pokemon Details
+                            let similar = render_vista_pokemon_details_panel(
+                                ui,
+                                &self.database,
+                                self.selected_pokemon,
+                                &mut self.selected_form,
+                                &self.favorites,
+                                self.compare_pokemon,
+                                &mut self.sprites,
+                                &mut self.show_shiny,
+                                &mut self.rank_view,
+                                &mut self.similar,
+                                &self.i18n,
+                            );
+                            if let Some(id) = similar {
+                                self.select(id);
+                                self.scroll_to_selected = true;
+                            }
+                        });
+                    }
+                    View::Team => {
+                        ui.horizontal(|ui| {
+                            // En esta vista un clic en la lista añade el Pokémon al equipo activo
+                            let clicked = render_vista_pokemon_list_panel(
+                                ui,
+                                &self.settings.sorted_list(&self.database, &self.filter, self.i18n.language()),
+                                self.selected_pokemon,
+                                &self.favorites,
+                                &mut self.sprites,
+                                self.scroll_to_selected,
+                                &mut self.settings,
+                                &self.i18n,
+                                self.database.names(),
+                            );
+                            self.scroll_to_selected = false;
+                            if let Some((id, form)) = clicked {
+                                self.team_builder.add_species(&mut self.collections, &self.database, id, form.as_deref(), &self.i18n);
+                            }
+
+                            ui.add_space(15.0);
+
+                            render_vista_team_builder_panel(
+                                ui,
+                                &self.database,
+                                &mut self.collections,
+                                &mut self.team_builder,
+                                &mut self.sprites,
+                                &self.i18n,
+                            );
+                        });
+                    }
+                    View::Collections => {
+                        ui.horizontal(|ui| {
+                            // Un clic en la lista añade el Pokémon a la colección seleccionada
+                            let clicked = render_vista_pokemon_list_panel(
+                                ui,
+                                &self.settings.sorted_list(&self.database, &self.filter, self.i18n.language()),
+                                self.selected_pokemon,
+                                &self.favorites,
+                                &mut self.sprites,
+                                self.scroll_to_selected,
+                                &mut self.settings,
+                                &self.i18n,
+                                self.database.names(),
+                            );
+                            self.scroll_to_selected = false;
+                            if let Some((id, form)) = clicked {
+                                let form = form.as_deref();
+                                self.collections_view.add_species(&mut self.collections, &self.database, id, form, &self.i18n);
+                            }
+
+                            ui.add_space(15.0);
+
+                            render_vista_collections_panel(
+                                ui,
+                                &self.database,
+                                &mut self.collections,
+                                &mut self.collections_view,
+                                &mut self.sprites,
+                                &self.i18n,
+                            );
+                        });
+                    }
+                    View::Generator => {
+                        let show = render_vista_generator_panel(
+                            ui,
+                            &self.database,
+                            &mut self.collections,
+                            &mut self.generator,
+                            &mut self.sprites,
+                            &self.i18n,
+                        );
+                        if let Some(id) = show {
+                            self.select(id);
+                            self.view = View::Pokedex;
+                            self.scroll_to_selected = true;
+                        }
+                    }
+                    View::Quiz => {
+                        let show = render_vista_quiz_panel(
+                            ui,
+                            &self.database,
+                            &mut self.quiz,
+                            &mut self.sprites,
+                            &self.i18n,
+                        );
+                        if let Some(id) = show {
+                            self.select(id);
+                            self.view = View::Pokedex;
+                            self.scroll_to_selected = true;
+                        }
+                    }
+                    View::Battle => {
+                        render_vista_battle_panel(
+                            ui,
+                            &self.database,
+                            &self.collections,
+                            &mut self.battle,
+                            &self.i18n,
+                        );
+                    }
+                    View::Speed => {
+                        ui.horizontal(|ui| {
+                            let clicked = render_vista_pokemon_list_panel(
+                                ui,
+                                &self.settings.sorted_list(&self.database, &self.filter, self.i18n.language()),
+                                self.selected_pokemon,
+                                &self.favorites,
+                                &mut self.sprites,
+                                self.scroll_to_selected,
+                                &mut self.settings,
+                                &self.i18n,
+                                self.database.names(),
+                            );
+                            self.scroll_to_selected = false;
+
+                            ui.add_space(15.0);
+
+                            let selected = self
+                                .selected_pokemon
+                                .and_then(|id| self.database.get_pokemon_form(id, self.selected_form.as_deref()));
+                            // Un clic en las listas de velocidad también cambia el Pokémon comparado
+                            let compared = render_vista_speed_panel(
+                                ui,
+                                &self.database,
+                                selected,
+                                &mut self.speed,
+                                &self.i18n,
+                            );
+                            if let Some((id, form)) = clicked.or(compared.map(|id| (id, None))) {
+                                self.select_entry(id, form);
+                                self.scroll_to_selected = compared.is_some();
+                            }
+                        });
+                    }
+                    View::TypeChart => {
+                        let typing = render_vista_type_chart_panel(ui, &self.database, &mut self.type_chart, &self.i18n);
+                        if let Some((first, second)) = typing {
+                            self.filter.pokemon_type = Some(first);
+                            self.filter.second_type = second;
+                            self.view = View::Pokedex;
+                        }
+                    }
+                    View::Dashboard => {
+                        render_vista_dashboard_panel(
+                            ui,
+                            &self.database,
+                            &self.database.filter_entries(&self.filter),
+                            &mut self.dashboard,
+                            &self.i18n,
+                        );
+                    }
+                }
                 
                 // Barra de estado estilo Windows Vista
                 ui.add_space(8.0);
-                render_status_bar(ui, &self.database);
+                render_status_bar(ui, &self.database, &mut self.i18n, &mut self.show_settings);
             });
+
+        render_shortcuts_help(ctx, &self.keymap, &self.i18n, &mut self.show_shortcuts_help);
+        if self.show_settings {
+            render_settings_window(ctx, &mut self.settings, &mut self.i18n, &mut self.show_settings);
+        }
+        if self.export.open {
+            let selected = self
+                .selected_pokemon
+                .and_then(|id| self.database.get_pokemon_form(id, self.selected_form.as_deref()));
+            render_export_window(
+                ctx,
+                &mut self.export,
+                &self.settings.sorted_list(&self.database, &self.filter, self.i18n.language()),
+                selected,
+                self.database.names(),
+                &self.i18n,
+            );
+        }
     }
+
+    // Al cerrar se guardan la sesión y la ventana junto con las preferencias, y el historial y los favoritos pendientes
+    fn on_close_event(&mut self) -> bool {
+        self.capture_session();
+        self.settings.save();
+        self.history.save();
+        self.favorites.save();
+        true
+    }
+}
+
+// Estilo global para simular Windows Vista
+fn apply_vista_style(ctx: &egui::Context) {
+    let mut style = (*ctx.style()).clone();
+    
+    // Colores base de Windows Vista
+    let vista_window = egui::Color32::from_rgba_premultiplied(240, 248, 255, 180); // Ventana semi-transparente
+    
+    // Aplicar estilos de Windows Vista
+    style.visuals.window_fill = vista_window;
+    style.visuals.panel_fill = vista_window;
+    style.visuals.widgets.noninteractive.bg_fill = egui::Color32::from_rgba_premultiplied(240, 248, 255, 220);
+    style.visuals.widgets.inactive.bg_fill = egui::Color32::from_rgba_premultiplied(200, 220, 240, 200);
+    style.visuals.widgets.hovered.bg_fill = egui::Color32::from_rgba_premultiplied(210, 230, 250, 220);
+    style.visuals.widgets.active.bg_fill = egui::Color32::from_rgba_premultiplied(180, 210, 240, 230);
+    
+    // Sombras y bordes estilo Aero
+    style.visuals.window_shadow = egui::epaint::Shadow {
+        extrusion: 10.0,
+        color: egui::Color32::from_rgba_premultiplied(0, 0, 0, 40),
+    };
+    style.visuals.popup_shadow = egui::epaint::Shadow {
+        extrusion: 8.0,
+        color: egui::Color32::from_rgba_premultiplied(0, 0, 0, 40),
+    };
+    
+    // Bordes redondeados para todos los widgets
+    style.visuals.window_rounding = egui::Rounding::same(8.0);
+    style.visuals.menu_rounding = egui::Rounding::same(6.0);
+    style.visuals.widgets.noninteractive.rounding = egui::Rounding::same(4.0);
+    style.visuals.widgets.inactive.rounding = egui::Rounding::same(4.0);
+    style.visuals.widgets.hovered.rounding = egui::Rounding::same(4.0);
+    style.visuals.widgets.active.rounding = egui::Rounding::same(4.0);
+    
+    ctx.set_style(style);
 }
 
-fn render_title_bar(ui: &mut egui::Ui) {
+fn render_title_bar(ui: &mut egui::Ui, i18n: &I18n) {
     let vista_title_bar_bg = egui::Color32::from_rgb(75, 110, 170);
     let height = 24.0;
     
@@ -153,7 +747,7 @@ fn render_title_bar(ui: &mut egui::Ui) {
             
             // Título de la ventana
             ui.add(egui::widgets::Label::new(
-                egui::RichText::new("PokéDex pero en Rust")
+                egui::RichText::new(i18n.tr("app.title"))
                     .size(14.0)
                     .color(egui::Color32::WHITE)
                     .strong()
@@ -183,7 +777,23 @@ fn render_title_bar(ui: &mut egui::Ui) {
     });
 }
 
-fn render_vista_search_bar(ui: &mut egui::Ui, search_term: &mut String) {
+// Lo que se pide desde los botones de historial de la barra de búsqueda
+enum Navigation {
+    Back,
+    Forward,
+    Show(u16),
+    ClearHistory,
+}
+
+fn render_vista_search_bar(
+    ui: &mut egui::Ui,
+    search_term: &mut String,
+    export_open: &mut bool,
+    history: &History,
+    database: &Database,
+    i18n: &I18n,
+) -> Option<Navigation> {
+    let mut navigation = None;
     ui.vertical_centered(|ui| {
         let search_bar_frame = egui::Frame::none()
             .fill(egui::Color32::from_rgba_premultiplied(240, 248, 255, 200))
@@ -197,28 +807,180 @@ fn render_vista_search_bar(ui: &mut egui::Ui, search_term: &mut String) {
         search_bar_frame.show(ui, |ui| {
             ui.set_width(ui.available_width() * 0.7);
             ui.horizontal(|ui| {
+                if let Some(clicked) = render_history_buttons(ui, history, database, i18n) {
+                    navigation = Some(clicked);
+                }
                 ui.label(
                     egui::RichText::new("🔍")
                         .color(egui::Color32::from_rgb(70, 110, 160))
                 );
                 ui.add_sized(
-                    [ui.available_width() - 30.0, 24.0],
+                    [ui.available_width() - 110.0, 24.0],
                     egui::TextEdit::singleline(search_term)
-                        .hint_text("Buscar Pokémon...")
+                        .id(search_field_id())
+                        .hint_text(i18n.tr("search.hint"))
                         .text_color(egui::Color32::from_rgb(40, 70, 110))
                         .frame(false) // Sin borde adicional
                 );
+                // Exporta la lista filtrada
+                if ui.button(i18n.tr("export.button")).clicked() {
+                    *export_open = true;
+                }
             });
         });
     });
+    navigation
 }
 
-fn render_vista_pokemon_list_panel(
+// Atrás, adelante y el menú de vistos recientemente
+fn render_history_buttons(ui: &mut egui::Ui, history: &History, database: &Database, i18n: &I18n) -> Option<Navigation> {
+    let mut navigation = None;
+    if ui
+        .add_enabled(history.can_go_back(), egui::Button::new("◀").frame(false))
+        .on_hover_text(i18n.tr("history.back"))
+        .clicked()
+    {
+        navigation = Some(Navigation::Back);
+    }
+    if ui
+        .add_enabled(history.can_go_forward(), egui::Button::new("▶").frame(false))
+        .on_hover_text(i18n.tr("history.forward"))
+        .clicked()
+    {
+        navigation = Some(Navigation::Forward);
+    }
+    ui.menu_button("🕘", |ui| {
+        ui.label(
+            egui::RichText::new(i18n.tr("history.recent"))
+                .color(egui::Color32::from_rgb(40, 80, 120))
+                .strong()
+        );
+        if history.recent().is_empty() {
+            ui.label(egui::RichText::new(i18n.tr("history.empty")).color(egui::Color32::from_rgb(120, 140, 160)));
+            return;
+        }
+        for &id in history.recent() {
+            let Some(pokemon) = database.get_pokemon(id) else {
+                continue;
+            };
+            let name = format!("#{:03} {}", id, i18n.pokemon_name(pokemon, database.names()));
+            if ui.button(name).clicked() {
+                navigation = Some(Navigation::Show(id));
+                ui.close_menu();
+            }
+        }
+        ui.separator();
+        if ui.button(i18n.tr("history.clear")).clicked() {
+            navigation = Some(Navigation::ClearHistory);
+            ui.close_menu();
+        }
+    })
+    .response
+    .on_hover_text(i18n.tr("history.recent"));
+    navigation
+}
+
+// Pestañas de vista y filtros por tipo y generación
+fn render_vista_toolbar(
     ui: &mut egui::Ui,
+    view: &mut View,
+    filter: &mut PokemonFilter,
     database: &Database,
-    search_term: &str,
-    selected_pokemon: &mut Option<u16>,
+    collections: &CollectionStore,
+    collection_filter: &mut Option<String>,
+    i18n: &I18n,
 ) {
+    ui.horizontal(|ui| {
+        for tab in View::ALL {
+            let text = egui::RichText::new(i18n.tr(tab.key()))
+                .size(16.0)
+                .color(egui::Color32::from_rgb(30, 60, 110))
+                .strong();
+            if ui.selectable_label(*view == tab, text).clicked() {
+                *view = tab;
+            }
+        }
+
+        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+            // Mostrar solo los Pokémon de una colección
+            if !collections.all().is_empty() {
+                let collection_text = collection_filter
+                    .clone()
+                    .unwrap_or_else(|| i18n.tr("filter.all_collections").to_string());
+                egui::ComboBox::from_id_source("collection_filter")
+                    .selected_text(collection_text)
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(collection_filter, None, i18n.tr("filter.all_collections"));
+                        for collection in collections.all() {
+                            ui.selectable_value(collection_filter, Some(collection.name.clone()), &collection.name);
+                        }
+                    });
+            }
+
+            let generation_text = match filter.generation {
+                Some(generation) => i18n.tr_args("filter.generation", &[("number", &generation)]),
+                None => i18n.tr("filter.all_generations").to_string(),
+            };
+            egui::ComboBox::from_id_source("generation_filter")
+                .selected_text(generation_text)
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut filter.generation, None, i18n.tr("filter.all_generations"));
+                    for generation in database.generations() {
+                        let label = i18n.tr_args("filter.generation", &[("number", &generation)]);
+                        ui.selectable_value(&mut filter.generation, Some(generation), label);
+                    }
+                });
+
+            // El segundo tipo solo tiene sentido con el primero elegido (de derecha a izquierda: queda a su lado)
+            if filter.pokemon_type.is_some() {
+                let second_text = match filter.second_type {
+                    Some(pokemon_type) => i18n.type_name(pokemon_type, database.names()),
+                    None => i18n.tr("filter.any_second_type").to_string(),
+                };
+                egui::ComboBox::from_id_source("second_type_filter")
+                    .selected_text(second_text)
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut filter.second_type, None, i18n.tr("filter.any_second_type"));
+                        for pokemon_type in PokemonType::ALL.into_iter().filter(|&t| Some(t) != filter.pokemon_type) {
+                            let label = i18n.type_name(pokemon_type, database.names());
+                            ui.selectable_value(&mut filter.second_type, Some(pokemon_type), label);
+                        }
+                    });
+            }
+
+            let type_text = match filter.pokemon_type {
+                Some(pokemon_type) => i18n.type_name(pokemon_type, database.names()),
+                None => i18n.tr("filter.all_types").to_string(),
+            };
+            egui::ComboBox::from_id_source("type_filter")
+                .selected_text(type_text)
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut filter.pokemon_type, None, i18n.tr("filter.all_types"));
+                    for pokemon_type in PokemonType::ALL {
+                        let label = i18n.type_name(pokemon_type, database.names());
+                        ui.selectable_value(&mut filter.pokemon_type, Some(pokemon_type), label);
+                    }
+                });
+            if filter.pokemon_type.is_none() || filter.second_type == filter.pokemon_type {
+                filter.second_type = None;
+            }
+        });
+    });
+}
+
+#[allow(clippy::too_many_arguments)]
+fn render_vista_pokemon_list_panel(
+    ui: &mut egui::Ui,
+    pokemons: &[&Pokemon],
+    selected_pokemon: Option<u16>,
+    favorites: &Favorites,
+    sprites: &mut SpriteCache,
+    scroll_to_selected: bool,
+    settings: &mut Settings,
+    i18n: &I18n,
+    names: &LocalizedNames,
+) -> Option<(u16, Option<String>)> {
+    let mut clicked = None;
     let vista_panel_frame = egui::Frame::none()
         .fill(egui::Color32::from_rgba_premultiplied(240, 248, 255, 200))
         .rounding(egui::Rounding::same(8.0))
@@ -229,7 +991,7 @@ fn render_vista_pokemon_list_panel(
         });
         
     vista_panel_frame.show(ui, |ui| {
-        ui.set_width(400.0);
+        ui.set_width(settings.list_width);
         ui.set_height(450.0);
         ui.vertical(|ui| {
             // Título del panel con degradado
@@ -238,14 +1000,20 @@ fn render_vista_pokemon_list_panel(
                 .rounding(egui::Rounding::same(6.0))
                 .inner_margin(egui::style::Margin::symmetric(8.0, 6.0));
                 
-            title_frame.show(ui, |ui| {
+            title_frame.show(ui, |ui| ui.horizontal(|ui| {
                 ui.heading(
-                    egui::RichText::new("Pokémon")
+                    egui::RichText::new(i18n.tr("list.title"))
                         .size(24.0)
                         .color(egui::Color32::from_rgb(40, 110, 80))
                         .strong()
                 );
-            });
+                // Orden de la lista, alineado a la derecha
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if render_sort_selector(ui, "list_sort", settings, i18n) {
+                        settings.save();
+                    }
+                });
+            }));
             
             ui.add_space(4.0);
             
@@ -253,59 +1021,70 @@ fn render_vista_pokemon_list_panel(
             egui::ScrollArea::vertical()
                 .max_height(380.0)
                 .show(ui, |ui| {
-                    let mut found_any = false;
-                    
-                    for pokemon in database.get_all_pokemon() {
-                        if pokemon.name.to_lowercase().contains(&search_term.to_lowercase()) {
-                            found_any = true;
-                            let is_selected = Some(pokemon.id) == *selected_pokemon;
-                            
-                            // Estilo del botón según Windows Vista
-                            let button_frame = if is_selected {
-                                egui::Frame::none()
-                                    .fill(egui::Color32::from_rgb(210, 230, 250))
-                                    .rounding(egui::Rounding::same(4.0))
-                                    .inner_margin(egui::style::Margin::same(2.0))
-                                    .stroke(egui::Stroke::new(1.0, egui::Color32::from_rgb(120, 170, 220)))
-                            } else {
-                                egui::Frame::none()
-                                    .fill(egui::Color32::TRANSPARENT)
-                                    .rounding(egui::Rounding::same(4.0))
-                                    .inner_margin(egui::style::Margin::same(2.0))
-                                    .stroke(egui::Stroke::new(1.0, egui::Color32::TRANSPARENT))
-                            };
-                            
-                            button_frame.show(ui, |ui| {
-                                let response = ui.add_sized(
-                                    [ui.available_width() - 8.0, 36.0],
-                                    egui::Button::new(
-                                        egui::RichText::new(format!("#{:03} - {}", pokemon.id, pokemon.name))
-                                            .color(if is_selected {
-                                                egui::Color32::from_rgb(30, 90, 150)
-                                            } else {
-                                                egui::Color32::from_rgb(60, 80, 100)
-                                            })
-                                            .size(16.0)
-                                    )
-                                    .fill(egui::Color32::TRANSPARENT)
-                                    .frame(false)
-                                );
-                                
-                                if response.clicked() {
-                                    *selected_pokemon = Some(pokemon.id);
-                                }
-                            });
+                    for pokemon in pokemons {
+                        let is_selected = Some(pokemon.id) == selected_pokemon;
+                        let favorite_mark = if favorites.contains(pokemon.id) { " ★" } else { "" };
+                        
+                        // Estilo del botón según Windows Vista
+                        let button_frame = if is_selected {
+                            egui::Frame::none()
+                                .fill(egui::Color32::from_rgb(210, 230, 250))
+                                .rounding(egui::Rounding::same(4.0))
+                                .inner_margin(egui::style::Margin::same(2.0))
+                                .stroke(egui::Stroke::new(1.0, egui::Color32::from_rgb(120, 170, 220)))
+                        } else {
+                            egui::Frame::none()
+                                .fill(egui::Color32::TRANSPARENT)
+                                .rounding(egui::Rounding::same(4.0))
+                                .inner_margin(egui::style::Margin::same(2.0))
+                                .stroke(egui::Stroke::new(1.0, egui::Color32::TRANSPARENT))
+                        };
+                        
+                        button_frame.show(ui, |ui| ui.horizontal(|ui| {
+                            // Icono pequeño (solo se carga si la fila está a la vista)
+                            let (icon_rect, _) = ui.allocate_exact_size(egui::vec2(32.0, 32.0), egui::Sense::hover());
+                            if ui.is_rect_visible(icon_rect) {
+                                let texture = sprites.get(ui.ctx(), pokemon, false);
+                                paint_sprite(ui, icon_rect, texture.as_ref());
+                            }
+
+                            let response = ui.add_sized(
+                                [ui.available_width() - 8.0, 36.0],
+                                egui::Button::new(
+                                    egui::RichText::new(format!(
+                                        "#{:03} - {}{}",
+                                        pokemon.id,
+                                        i18n.pokemon_name(pokemon, names),
+                                        favorite_mark
+                                    ))
+                                        .color(if is_selected {
+                                            egui::Color32::from_rgb(30, 90, 150)
+                                        } else {
+                                            egui::Color32::from_rgb(60, 80, 100)
+                                        })
+                                        .size(16.0)
+                                )
+                                .fill(egui::Color32::TRANSPARENT)
+                                .frame(false)
+                            );
                             
-                            ui.add_space(2.0);
-                        }
+                            if response.clicked() {
+                                clicked = Some((pokemon.id, pokemon.form.clone()));
+                            }
+                            if is_selected && scroll_to_selected {
+                                response.scroll_to_me(Some(egui::Align::Center));
+                            }
+                        }));
+                        
+                        ui.add_space(2.0);
                     }
                     
                     // Mensaje cuando no hay resultados
-                    if !found_any {
+                    if pokemons.is_empty() {
                         ui.vertical_centered(|ui| {
                             ui.add_space(100.0);
                             ui.label(
-                                egui::RichText::new("No se encontraron Pokémon")
+                                egui::RichText::new(i18n.tr("list.empty"))
                                     .size(18.0)
                                     .color(egui::Color32::from_rgb(120, 140, 160))
                             );
@@ -314,12 +1093,24 @@ fn render_vista_pokemon_list_panel(
                 });
         });
     });
+
+    clicked
 }
+#[allow(clippy::too_many_arguments)]
 fn render_vista_pokemon_details_panel(
     ui: &mut egui::Ui,
     database: &Database,
     selected_pokemon: Option<u16>,
-) {
+    selected_form: &mut Option<String>,
+    favorites: &Favorites,
+    compare_pokemon: Option<u16>,
+    sprites: &mut SpriteCache,
+    show_shiny: &mut bool,
+    rank_view: &mut RankView,
+    similar: &mut SimilarState,
+    i18n: &I18n,
+) -> Option<u16> {
+    let mut clicked = None;
     let vista_panel_frame = egui::Frame::none()
         .fill(egui::Color32::from_rgba_premultiplied(240, 248, 255, 200)) // Fondo del recuadro
         .rounding(egui::Rounding::same(8.0)) // Bordes redondeados
@@ -334,39 +1125,86 @@ fn render_vista_pokemon_details_panel(
         ui.set_height(450.0); // Alto del recuadro
 
         if let Some(id) = selected_pokemon {
-            if let Some(pokemon) = database.get_pokemon(id) {
-                // Recuadro principal que contiene toda la información
-                ui.vertical(|ui| {
-                    // Nombre y número del Pokémon
-                    ui.heading(
-                        egui::RichText::new(&pokemon.name)
-                            .size(32.0)
-                            .color(egui::Color32::from_rgb(40, 110, 80))
-                            .strong()
-                    );
-                    ui.label(
-                        egui::RichText::new(format!("#{:03}", pokemon.id))
-                            .size(18.0)
-                            .color(egui::Color32::from_rgb(60, 130, 100))
-                    );
+            if let Some(pokemon) = database.get_pokemon_form(id, selected_form.as_deref()) {
+                // Pokémon contra el que se compara (si no es el mismo)
+                let compare = compare_pokemon
+                    .filter(|&other| other != id)
+                    .and_then(|other| database.get_pokemon(other));
+                let favorite_mark = if favorites.contains(id) { " ★" } else { "" };
+
+                // Recuadro principal que contiene toda la información (con scroll si no cabe)
+                egui::ScrollArea::vertical().id_source("details_scroll").show(ui, |ui| ui.vertical(|ui| {
+                    ui.horizontal(|ui| {
+                        // Sprite del Pokémon (shiny solo si existe)
+                        let has_shiny = sprites.has_shiny(pokemon);
+                        let texture = sprites.get(ui.ctx(), pokemon, *show_shiny && has_shiny);
+                        render_sprite(ui, texture.as_ref(), 96.0);
+
+                        ui.vertical(|ui| {
+                            // Nombre y número del Pokémon
+                            ui.heading(
+                                egui::RichText::new(format!("{}{}", i18n.pokemon_name(pokemon, database.names()), favorite_mark))
+                                    .size(32.0)
+                                    .color(egui::Color32::from_rgb(40, 110, 80))
+                                    .strong()
+                            );
+                            ui.horizontal(|ui| {
+                                ui.label(
+                                    egui::RichText::new(format!("#{:03}", pokemon.id))
+                                        .size(18.0)
+                                        .color(egui::Color32::from_rgb(60, 130, 100))
+                                );
+                                if has_shiny {
+                                    ui.add_space(10.0);
+                                    ui.checkbox(show_shiny, i18n.tr("details.shiny"));
+                                }
+                            });
+
+                            // Selector de formas (megas, formes, etc.)
+                            let forms = database.get_forms(id);
+                            if forms.len() > 1 {
+                                ui.horizontal_wrapped(|ui| {
+                                    for form in forms {
+                                        let label = form.form.as_deref().unwrap_or(&form.name);
+                                        let is_current = form.form == pokemon.form;
+                                        if ui.selectable_label(is_current, label).clicked() {
+                                            *selected_form = form.form.clone();
+                                        }
+                                    }
+                                });
+                            }
+                        });
+                    });
 
                     ui.add_space(12.0); // Espacio entre secciones
 
                     // Información Básica
-                    ui.label("Información Básica");
+                    ui.label(i18n.tr("details.basic_info"));
                     ui.horizontal(|ui| {
                         ui.vertical(|ui| {
-                            ui.label(format!("Altura: {:.1} m", pokemon.height as f32 / 10.0));
-                            ui.label(format!("Peso: {:.1} kg", pokemon.weight as f32 / 10.0));
+                            if let Some(height) = pokemon.formatted_height() {
+                                ui.label(i18n.tr_args("details.height", &[("value", &format!("{:.1}", height))]));
+                            }
+                            if let Some(weight) = pokemon.formatted_weight() {
+                                ui.label(i18n.tr_args("details.weight", &[("value", &format!("{:.1}", weight))]));
+                            }
+                            ui.label(i18n.tr_args("details.generation", &[("number", &pokemon.generation)]));
+                            if pokemon.legendary {
+                                ui.label(
+                                    egui::RichText::new(i18n.tr("details.legendary"))
+                                        .color(egui::Color32::from_rgb(200, 150, 30))
+                                        .strong()
+                                );
+                            }
                         });
 
                         ui.add_space(20.0); // Espacio entre columnas
 
                         ui.vertical(|ui| {
-                            ui.label("Tipos:");
+                            ui.label(i18n.tr("details.types"));
                             ui.horizontal(|ui| {
                                 for type_info in &pokemon.types {
-                                    render_vista_type_badge(ui, type_info);
+                                    render_vista_type_badge(ui, type_info, &i18n.type_name(*type_info, database.names()));
                                     ui.add_space(5.0);
                                 }
                             });
@@ -375,22 +1213,78 @@ fn render_vista_pokemon_details_panel(
 
                     ui.add_space(12.0); // Espacio entre secciones
 
-                    // Estadísticas
-                    ui.label("Estadísticas");
-                    render_vista_stat_bar(ui, "HP", pokemon.stats.hp);
-                    render_vista_stat_bar(ui, "Ataque", pokemon.stats.attack);
-                    render_vista_stat_bar(ui, "Defensa", pokemon.stats.defense);
-                    render_vista_stat_bar(ui, "Atq. Esp.", pokemon.stats.special_attack);
-                    render_vista_stat_bar(ui, "Def. Esp.", pokemon.stats.special_defense);
-                    render_vista_stat_bar(ui, "Velocidad", pokemon.stats.speed);
-                });
+                    // Estadísticas con selector del ámbito del ranking
+                    ui.horizontal(|ui| {
+                        ui.label(i18n.tr("details.stats"));
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            egui::ComboBox::from_id_source("rank_view")
+                                .selected_text(i18n.tr(rank_view.key()))
+                                .show_ui(ui, |ui| {
+                                    for view in RankView::ALL {
+                                        ui.selectable_value(rank_view, view, i18n.tr(view.key()));
+                                    }
+                                });
+                            ui.label(
+                                egui::RichText::new(i18n.tr("rank.title"))
+                                    .size(13.0)
+                                    .color(egui::Color32::from_rgb(70, 110, 160))
+                            );
+                        });
+                    });
+                    let rank_scope = rank_view.scope_for(pokemon);
+                    if let Some(other) = compare {
+                        ui.label(
+                            egui::RichText::new(i18n.tr_args(
+                                "details.comparing",
+                                &[
+                                    ("name", &i18n.pokemon_name(other, database.names())),
+                                    ("total", &pokemon.total_stats()),
+                                    ("other_total", &other.total_stats()),
+                                ],
+                            ))
+                            .size(13.0)
+                            .color(egui::Color32::from_rgb(70, 110, 160))
+                        );
+                    }
+                    for kind in StatKind::ALL {
+                        render_vista_stat_bar(
+                            ui,
+                            i18n.stat_name(kind),
+                            pokemon.stats.get(kind),
+                            compare.map(|other| other.stats.get(kind)),
+                            &metric_rankings(database, pokemon, Metric::Stat(kind), i18n),
+                            rank_scope,
+                            i18n,
+                        );
+                    }
+
+                    // Total con su ranking
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            egui::RichText::new(format!("{}: {}", i18n.tr("stat.total"), pokemon.total_stats()))
+                                .color(egui::Color32::from_rgb(40, 80, 120))
+                                .size(15.0)
+                                .strong()
+                        );
+                        ui.add_space(8.0);
+                        render_vista_rank_label(
+                            ui,
+                            &metric_rankings(database, pokemon, Metric::Total, i18n),
+                            rank_scope,
+                            i18n,
+                        );
+                    });
+
+                    ui.add_space(12.0); // Espacio entre secciones
+                    clicked = render_similar_section(ui, database, pokemon, similar, i18n);
+                }));
             }
         } else {
             // Mensaje si no hay ningún Pokémon seleccionado
             ui.vertical_centered(|ui| {
                 ui.add_space(100.0);
                 ui.label(
-                    egui::RichText::new("Selecciona un Pokémon")
+                    egui::RichText::new(i18n.tr("details.empty"))
                         .size(24.0)
                         .color(egui::Color32::from_rgb(120, 140, 160))
                 );
@@ -405,31 +1299,17 @@ fn render_vista_pokemon_details_panel(
             });
         }
     });
+    clicked
 }
 
-fn render_vista_type_badge(ui: &mut egui::Ui, pokemon_type: &PokemonType) {
-    // Obtener el color según el tipo de Pokémon
-    let type_color = match pokemon_type {
-        PokemonType::Normal => egui::Color32::from_rgb(168, 168, 120),
-        PokemonType::Fire => egui::Color32::from_rgb(240, 128, 48),
-        PokemonType::Water => egui::Color32::from_rgb(104, 144, 240),
-        PokemonType::Grass => egui::Color32::from_rgb(120, 200, 80),
-        PokemonType::Electric => egui::Color32::from_rgb(248, 208, 48),
-        PokemonType::Ice => egui::Color32::from_rgb(152, 216, 216),
-        PokemonType::Fighting => egui::Color32::from_rgb(192, 48, 40),
-        PokemonType::Poison => egui::Color32::from_rgb(160, 64, 160),
-        PokemonType::Ground => egui::Color32::from_rgb(224, 192, 104),
-        PokemonType::Flying => egui::Color32::from_rgb(168, 144, 240),
-        PokemonType::Psychic => egui::Color32::from_rgb(248, 88, 136),
-        PokemonType::Bug => egui::Color32::from_rgb(168, 184, 32),
-        PokemonType::Rock => egui::Color32::from_rgb(184, 160, 56),
-        PokemonType::Ghost => egui::Color32::from_rgb(112, 88, 152),
-        PokemonType::Dragon => egui::Color32::from_rgb(112, 56, 248),
-        PokemonType::Dark => egui::Color32::from_rgb(112, 88, 72),
-        PokemonType::Steel => egui::Color32::from_rgb(184, 184, 208),
-        PokemonType::Fairy => egui::Color32::from_rgb(238, 153, 172),
-        _ => egui::Color32::from_rgb(180, 180, 180), // Color por defecto
-    };
+// Color de cada tipo, compartido por las etiquetas y los gráficos
+fn type_color(pokemon_type: &PokemonType) -> egui::Color32 {
+    let (r, g, b) = pokemon_type.rgb();
+    egui::Color32::from_rgb(r, g, b)
+}
+
+fn render_vista_type_badge(ui: &mut egui::Ui, pokemon_type: &PokemonType, label: &str) {
+    let type_color = type_color(pokemon_type);
 
     // Estilos Vista para la etiqueta de tipo
     let badge_frame = egui::Frame::none()
@@ -444,7 +1324,7 @@ fn render_vista_type_badge(ui: &mut egui::Ui, pokemon_type: &PokemonType) {
     badge_frame.show(ui, |ui| {
         ui.add(
             egui::Label::new(
-                egui::RichText::new(format!("{}", pokemon_type))
+                egui::RichText::new(label)
                     .color(egui::Color32::WHITE)
                     .strong()
                     .size(14.0)
@@ -453,7 +1333,72 @@ fn render_vista_type_badge(ui: &mut egui::Ui, pokemon_type: &PokemonType) {
     });
 }
 
-fn render_vista_stat_bar(ui: &mut egui::Ui, name: &str, value: u8) {
+// Rankings de una métrica en cada ámbito al que pertenece el Pokémon, con su etiqueta
+fn metric_rankings(
+    database: &Database,
+    pokemon: &Pokemon,
+    metric: Metric,
+    i18n: &I18n,
+) -> Vec<(RankScope, String, Ranking)> {
+    StatRankings::scopes_of(pokemon)
+        .into_iter()
+        .filter_map(|scope| {
+            let ranking = database.rankings().rank(pokemon, metric, scope)?;
+            let label = match scope {
+                RankScope::All => i18n.tr("rank.all").to_string(),
+                RankScope::Type(t) => i18n.type_name(t, database.names()),
+                RankScope::Generation(g) => i18n.tr_args("filter.generation", &[("number", &g)]),
+            };
+            Some((scope, label, ranking))
+        })
+        .collect()
+}
+
+// "#12 · P95" para el ámbito elegido y el detalle de todos los ámbitos en el tooltip
+fn render_vista_rank_label(
+    ui: &mut egui::Ui,
+    rankings: &[(RankScope, String, Ranking)],
+    scope: RankScope,
+    i18n: &I18n,
+) {
+    let Some((_, _, shown)) = rankings.iter().find(|(s, _, _)| *s == scope) else {
+        return;
+    };
+    let tooltip = rankings
+        .iter()
+        .map(|(_, label, ranking)| {
+            i18n.tr_args(
+                "rank.tooltip_line",
+                &[
+                    ("scope", label),
+                    ("rank", &ranking.rank),
+                    ("total", &ranking.total),
+                    ("percentile", &format!("{:.1}", ranking.percentile)),
+                ],
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    ui.label(
+        egui::RichText::new(i18n.tr_args(
+            "rank.short",
+            &[("rank", &shown.rank), ("percentile", &format!("{:.0}", shown.percentile))],
+        ))
+        .color(egui::Color32::from_rgb(110, 130, 160))
+        .size(12.0)
+    )
+    .on_hover_text(tooltip);
+}
+
+fn render_vista_stat_bar(
+    ui: &mut egui::Ui,
+    name: &str,
+    value: u8,
+    compare_value: Option<u8>,
+    rankings: &[(RankScope, String, Ranking)],
+    rank_scope: RankScope,
+    i18n: &I18n,
+) {
     ui.horizontal(|ui| {
         ui.set_min_width(80.0);
         ui.label(
@@ -473,7 +1418,7 @@ fn render_vista_stat_bar(ui: &mut egui::Ui, name: &str, value: u8) {
             .inner_margin(egui::style::Margin::same(2.0));
             
         bar_frame.show(ui, |ui| {
-            ui.allocate_space(egui::Vec2::new(ui.available_width() - 40.0, 20.0));
+            ui.allocate_space(egui::Vec2::new(ui.available_width() - 110.0, 20.0));
             
             // Calcular el color de la barra basado en el valor
             let bar_color = egui::Color32::from_rgb(
@@ -517,12 +1462,29 @@ fn render_vista_stat_bar(ui: &mut egui::Ui, name: &str, value: u8) {
                 .color(egui::Color32::from_rgb(40, 80, 120))
                 .size(15.0)
         );
+
+        // Diferencia con el Pokémon comparado
+        if let Some(other) = compare_value {
+            let diff = value as i16 - other as i16;
+            let diff_color = match diff.cmp(&0) {
+                std::cmp::Ordering::Greater => egui::Color32::from_rgb(40, 140, 60),
+                std::cmp::Ordering::Less => egui::Color32::from_rgb(190, 60, 50),
+                std::cmp::Ordering::Equal => egui::Color32::from_rgb(120, 140, 160),
+            };
+            ui.label(
+                egui::RichText::new(format!("{:+}", diff))
+                    .color(diff_color)
+                    .size(13.0)
+            );
+        }
+
+        render_vista_rank_label(ui, rankings, rank_scope, i18n);
     });
     
     ui.add_space(4.0);
 }
 
-fn render_status_bar(ui: &mut egui::Ui, database: &Database) {
+fn render_status_bar(ui: &mut egui::Ui, database: &Database, i18n: &mut I18n, settings_open: &mut bool) {
     let vista_status_bar_bg = egui::Color32::from_rgb(65, 100, 160);
     
     let status_bar_frame = egui::Frame::none()
@@ -537,17 +1499,49 @@ fn render_status_bar(ui: &mut egui::Ui, database: &Database) {
     status_bar_frame.show(ui, |ui| {
         ui.horizontal(|ui| {
             ui.label(
-                egui::RichText::new(format!("Pokémon cargados: {}", database.get_all_pokemon().len()))
+                egui::RichText::new(i18n.tr_args(
+                    "status.loaded",
+                    &[("count", &database.get_all_pokemon().len())],
+                ))
                     .size(14.0)
                     .color(egui::Color32::WHITE)
             );
             
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                 ui.label(
-                    egui::RichText::new("© 2024 PokéDex Rust")
+                    egui::RichText::new(i18n.tr("status.copyright"))
                         .size(14.0)
                         .color(egui::Color32::WHITE)
                 );
+                ui.add_space(12.0);
+                if ui.button("⚙").on_hover_text(i18n.tr("settings.open")).clicked() {
+                    *settings_open = !*settings_open;
+                }
+                ui.add_space(12.0);
+
+                // Selector de idioma
+                let mut language = i18n.language();
+                egui::ComboBox::from_id_source("language_selector")
+                    .selected_text(language.native_name())
+                    .show_ui(ui, |ui| {
+                        for lang in Language::ALL {
+                            ui.selectable_value(&mut language, lang, lang.native_name());
+                        }
+                    });
+                if language != i18n.language() {
+                    i18n.set_language(language);
+                }
+                ui.label(
+                    egui::RichText::new(i18n.tr("app.language"))
+                        .size(14.0)
+                        .color(egui::Color32::WHITE)
+                );
+                ui.add_space(12.0);
+                ui.label(
+                    egui::RichText::new(i18n.tr("status.shortcuts_hint"))
+                        .size(14.0)
+                        .color(egui::Color32::from_rgb(200, 220, 245))
+                );
             });
         });
     });
@@ -0,0 +1,465 @@
+use super::render_vista_type_badge;
+use super::sprites::{render_silhouette, render_sprite, SpriteCache};
+use crate::database::Database;
+use crate::i18n::I18n;
+use crate::pokemon::{PokemonType, StatKind};
+use crate::quiz::{Choice, Difficulty, HighScoreStore, Prompt, Question, Quiz, QuizError, QuizKind, QuizSettings, ROUND_LENGTH};
+use crate::rng::Rng;
+use eframe::egui;
+
+// Opciones por fila
+const CHOICES_PER_ROW: usize = 2;
+
+pub struct QuizState {
+    settings: QuizSettings,
+    // Semilla escrita por el usuario; vacía = aleatoria
+    seed_text: String,
+    quiz: Option<Quiz>,
+    // Puntos ganados con la última respuesta
+    gained: Option<u32>,
+    // Puesto en la tabla de récords de la ronda recién terminada
+    record_position: Option<usize>,
+    high_scores: HighScoreStore,
+    error: Option<String>,
+}
+
+impl QuizState {
+    pub fn load() -> Self {
+        Self {
+            settings: QuizSettings::default(),
+            seed_text: String::new(),
+            quiz: None,
+            gained: None,
+            record_position: None,
+            high_scores: HighScoreStore::load(),
+            error: None,
+        }
+    }
+
+    fn start(&mut self, database: &Database, i18n: &I18n) {
+        let seed = self.seed_text.trim().parse().unwrap_or_else(|_| Rng::random_seed());
+        self.gained = None;
+        self.record_position = None;
+        match Quiz::new(database, self.settings.clone(), seed) {
+            Ok(quiz) => {
+                self.quiz = Some(quiz);
+                self.error = None;
+            }
+            Err(e) => {
+                self.quiz = None;
+                self.error = Some(error_message(&e, i18n));
+            }
+        }
+    }
+
+    fn answer(&mut self, choice: usize) {
+        let Some(quiz) = &mut self.quiz else {
+            return;
+        };
+        if let Some(gained) = quiz.answer(choice) {
+            self.gained = Some(gained);
+            if quiz.is_finished() {
+                self.record_position = self.high_scores.record(quiz);
+            }
+        }
+    }
+
+    fn next(&mut self, database: &Database, i18n: &I18n) {
+        let Some(quiz) = &mut self.quiz else {
+            return;
+        };
+        self.gained = None;
+        if let Err(e) = quiz.next_question(database) {
+            self.error = Some(error_message(&e, i18n));
+            self.quiz = None;
+        }
+    }
+}
+
+fn error_message(error: &QuizError, i18n: &I18n) -> String {
+    match error {
+        QuizError::NoKinds => i18n.tr("quiz.no_kinds").to_string(),
+        QuizError::NotEnoughCandidates => i18n.tr("quiz.not_enough").to_string(),
+    }
+}
+
+fn types_label(types: &[PokemonType], database: &Database, i18n: &I18n) -> String {
+    types
+        .iter()
+        .map(|&t| i18n.type_name(t, database.names()))
+        .collect::<Vec<_>>()
+        .join(" / ")
+}
+
+fn choice_label(choice: &Choice, stat: Option<StatKind>, revealed: bool, database: &Database, i18n: &I18n) -> String {
+    match choice {
+        Choice::Pokemon(key) => {
+            let Some(pokemon) = key.resolve(database) else {
+                return "?".to_string();
+            };
+            let name = i18n.pokemon_name(pokemon, database.names());
+            // En "¿cuál tiene más...?" se enseñan los valores al responder
+            match stat {
+                Some(kind) if revealed => format!("{} ({})", name, pokemon.stats.get(kind)),
+                _ => name,
+            }
+        }
+        Choice::Types(types) => types_label(types, database, i18n),
+    }
+}
+
+// Devuelve el Pokémon que el usuario quiere ver en la Pokédex
+pub fn render_vista_quiz_panel(
+    ui: &mut egui::Ui,
+    database: &Database,
+    state: &mut QuizState,
+    sprites: &mut SpriteCache,
+    i18n: &I18n,
+) -> Option<u16> {
+    let mut show = None;
+    let vista_panel_frame = egui::Frame::none()
+        .fill(egui::Color32::from_rgba_premultiplied(240, 248, 255, 200))
+        .rounding(egui::Rounding::same(8.0))
+        .stroke(egui::Stroke::new(1.0, egui::Color32::from_rgb(180, 200, 220)))
+        .inner_margin(egui::style::Margin::same(8.0))
+        .shadow(egui::epaint::Shadow {
+            extrusion: 6.0,
+            color: egui::Color32::from_rgba_premultiplied(0, 0, 0, 40),
+        });
+
+    vista_panel_frame.show(ui, |ui| {
+        ui.set_width(ui.available_width());
+        ui.set_height(450.0);
+
+        ui.horizontal_top(|ui| {
+            ui.vertical(|ui| {
+                ui.set_width(ui.available_width() - 260.0);
+                ui.heading(
+                    egui::RichText::new(i18n.tr("quiz.title"))
+                        .size(24.0)
+                        .color(egui::Color32::from_rgb(40, 110, 80))
+                        .strong()
+                );
+                if state.quiz.is_some() {
+                    show = render_round(ui, database, state, sprites, i18n);
+                } else {
+                    render_settings(ui, database, state, i18n);
+                }
+                if let Some(error) = &state.error {
+                    ui.label(egui::RichText::new(error).color(egui::Color32::from_rgb(200, 60, 50)));
+                }
+            });
+
+            ui.separator();
+            ui.vertical(|ui| {
+                render_high_scores(ui, state, i18n);
+            });
+        });
+    });
+    show
+}
+
+fn render_settings(ui: &mut egui::Ui, database: &Database, state: &mut QuizState, i18n: &I18n) {
+    ui.horizontal(|ui| {
+        ui.label(egui::RichText::new(i18n.tr("quiz.difficulty")).color(egui::Color32::from_rgb(40, 80, 120)));
+        let mut difficulty = state.settings.difficulty;
+        egui::ComboBox::from_id_source("quiz_difficulty")
+            .selected_text(i18n.tr(difficulty.key()))
+            .show_ui(ui, |ui| {
+                for option in Difficulty::ALL {
+                    ui.selectable_value(&mut difficulty, option, i18n.tr(option.key()));
+                }
+            });
+        // Cada dificultad trae sus generaciones, que luego se pueden ajustar
+        if difficulty != state.settings.difficulty {
+            state.settings.difficulty = difficulty;
+            state.settings.generations = difficulty.generations();
+        }
+        ui.label(
+            egui::RichText::new(i18n.tr_args(
+                "quiz.difficulty_hint",
+                &[("choices", &difficulty.choices()), ("points", &difficulty.points())],
+            ))
+            .size(12.0)
+            .color(egui::Color32::from_rgb(120, 140, 160))
+        );
+    });
+
+    ui.horizontal_wrapped(|ui| {
+        ui.label(egui::RichText::new(i18n.tr("generator.generations")).color(egui::Color32::from_rgb(40, 80, 120)));
+        for generation in database.generations() {
+            let mut allowed = state.settings.generations.contains(&generation);
+            let label = i18n.tr_args("filter.generation", &[("number", &generation)]);
+            if ui.checkbox(&mut allowed, label).changed() {
+                if allowed {
+                    state.settings.generations.insert(generation);
+                } else {
+                    state.settings.generations.remove(&generation);
+                }
+            }
+        }
+    });
+
+    ui.label(egui::RichText::new(i18n.tr("quiz.kinds")).color(egui::Color32::from_rgb(40, 80, 120)));
+    for kind in QuizKind::ALL {
+        let mut enabled = state.settings.kinds.contains(&kind);
+        if ui.checkbox(&mut enabled, i18n.tr(kind.key())).changed() {
+            if enabled {
+                state.settings.kinds.insert(kind);
+            } else {
+                state.settings.kinds.remove(&kind);
+            }
+        }
+    }
+
+    ui.horizontal(|ui| {
+        ui.label(egui::RichText::new(i18n.tr("generator.seed")).color(egui::Color32::from_rgb(40, 80, 120)));
+        ui.add(
+            egui::TextEdit::singleline(&mut state.seed_text)
+                .hint_text(i18n.tr("generator.seed_hint"))
+                .desired_width(140.0)
+        );
+    });
+
+    ui.add_space(6.0);
+    if ui.button(i18n.tr("quiz.start")).clicked() {
+        state.start(database, i18n);
+    }
+}
+
+fn render_round(
+    ui: &mut egui::Ui,
+    database: &Database,
+    state: &mut QuizState,
+    sprites: &mut SpriteCache,
+    i18n: &I18n,
+) -> Option<u16> {
+    let mut show = None;
+    let Some(quiz) = &state.quiz else {
+        return None;
+    };
+    let score = quiz.score().clone();
+    let finished = quiz.is_finished();
+    let seed = quiz.seed();
+
+    ui.horizontal(|ui| {
+        ui.label(
+            egui::RichText::new(i18n.tr_args("quiz.progress", &[("number", &quiz.number()), ("total", &ROUND_LENGTH)]))
+                .color(egui::Color32::from_rgb(40, 80, 120))
+                .strong()
+        );
+        ui.label(i18n.tr_args("quiz.points", &[("points", &score.points)]));
+        ui.label(i18n.tr_args(
+            "quiz.streak",
+            &[("streak", &score.streak), ("multiplier", &score.multiplier())],
+        ));
+        ui.label(
+            egui::RichText::new(i18n.tr_args("generator.seed_used", &[("seed", &seed)]))
+                .size(12.0)
+                .color(egui::Color32::from_rgb(120, 140, 160))
+        );
+    });
+    ui.add_space(6.0);
+
+    let mut clicked = None;
+    if let Some(question) = quiz.question() {
+        let revealed = quiz.chosen().is_some();
+        render_prompt(ui, database, question, revealed, sprites, i18n);
+        ui.add_space(6.0);
+        clicked = render_choices(ui, database, question, quiz.chosen(), i18n);
+
+        if revealed {
+            ui.add_space(4.0);
+            ui.horizontal(|ui| {
+                if quiz.chosen() == Some(question.answer) {
+                    let gained = state.gained.unwrap_or(0);
+                    ui.label(
+                        egui::RichText::new(i18n.tr_args("quiz.correct", &[("points", &gained)]))
+                            .color(egui::Color32::from_rgb(40, 140, 70))
+                            .strong()
+                    );
+                } else {
+                    let answer = choice_label(&question.choices[question.answer], None, true, database, i18n);
+                    ui.label(
+                        egui::RichText::new(i18n.tr_args("quiz.wrong", &[("answer", &answer)]))
+                            .color(egui::Color32::from_rgb(200, 60, 50))
+                            .strong()
+                    );
+                }
+                if let Some(key) = question.subject() {
+                    if ui.small_button(i18n.tr("generator.show")).clicked() {
+                        show = Some(key.id);
+                    }
+                }
+            });
+        }
+    }
+    if let Some(choice) = clicked {
+        state.answer(choice);
+    }
+
+    ui.add_space(6.0);
+    if finished {
+        ui.label(
+            egui::RichText::new(i18n.tr_args(
+                "quiz.finished",
+                &[
+                    ("correct", &score.correct),
+                    ("total", &ROUND_LENGTH),
+                    ("points", &score.points),
+                    ("streak", &score.best_streak),
+                ],
+            ))
+            .size(16.0)
+            .color(egui::Color32::from_rgb(40, 110, 80))
+            .strong()
+        );
+        if let Some(position) = state.record_position {
+            ui.label(
+                egui::RichText::new(i18n.tr_args("quiz.new_record", &[("position", &(position + 1))]))
+                    .color(egui::Color32::from_rgb(40, 140, 70))
+            );
+        }
+        ui.horizontal(|ui| {
+            if ui.button(i18n.tr("quiz.again")).clicked() {
+                state.start(database, i18n);
+            }
+            if ui.button(i18n.tr("quiz.settings")).clicked() {
+                state.quiz = None;
+            }
+        });
+    } else {
+        let answered = state.quiz.as_ref().is_some_and(|q| q.chosen().is_some());
+        ui.horizontal(|ui| {
+            if ui.add_enabled(answered, egui::Button::new(i18n.tr("quiz.next"))).clicked() {
+                state.next(database, i18n);
+            }
+            if ui.button(i18n.tr("quiz.quit")).clicked() {
+                state.quiz = None;
+            }
+        });
+    }
+    show
+}
+
+fn render_prompt(
+    ui: &mut egui::Ui,
+    database: &Database,
+    question: &Question,
+    revealed: bool,
+    sprites: &mut SpriteCache,
+    i18n: &I18n,
+) {
+    let subject = question.subject().and_then(|key| key.resolve(database));
+    let name = subject.map(|p| i18n.pokemon_name(p, database.names())).unwrap_or_default();
+    let text = match &question.prompt {
+        Prompt::Stats(_) => i18n.tr("quiz.prompt.stats").to_string(),
+        Prompt::Types(_) => i18n.tr_args("quiz.prompt.types", &[("name", &name)]),
+        Prompt::HigherStat(kind) => i18n.tr_args("quiz.prompt.higher_stat", &[("stat", &i18n.stat_name(*kind))]),
+        Prompt::Weakness(_) => i18n.tr_args("quiz.prompt.weakness", &[("name", &name)]),
+    };
+    ui.label(
+        egui::RichText::new(text)
+            .size(18.0)
+            .color(egui::Color32::from_rgb(40, 80, 120))
+            .strong()
+    );
+
+    let Some(pokemon) = subject else {
+        return;
+    };
+    ui.horizontal(|ui| {
+        let texture = sprites.get(ui.ctx(), pokemon, false);
+        // Antes de responder solo se ve la silueta
+        if matches!(question.prompt, Prompt::Stats(_)) && !revealed {
+            render_silhouette(ui, texture.as_ref(), 96.0);
+        } else {
+            render_sprite(ui, texture.as_ref(), 96.0);
+        }
+        match question.prompt {
+            Prompt::Stats(_) => {
+                egui::Grid::new("quiz_stats_grid").spacing([16.0, 2.0]).show(ui, |ui| {
+                    for kind in StatKind::ALL {
+                        ui.label(egui::RichText::new(i18n.stat_name(kind)).color(egui::Color32::from_rgb(120, 140, 160)));
+                        ui.label(egui::RichText::new(pokemon.stats.get(kind).to_string()).strong());
+                        ui.end_row();
+                    }
+                    ui.label(egui::RichText::new(i18n.tr("stat.total")).color(egui::Color32::from_rgb(120, 140, 160)));
+                    ui.label(egui::RichText::new(pokemon.total_stats().to_string()).strong());
+                    ui.end_row();
+                });
+            }
+            // Para adivinar la debilidad se dan los tipos del Pokémon
+            Prompt::Weakness(_) => {
+                for pokemon_type in &pokemon.types {
+                    render_vista_type_badge(ui, pokemon_type, &i18n.type_name(*pokemon_type, database.names()));
+                }
+            }
+            _ => {}
+        }
+    });
+}
+
+// Devuelve la opción pulsada; tras responder se marcan en verde la correcta y en rojo la elegida si falló
+fn render_choices(
+    ui: &mut egui::Ui,
+    database: &Database,
+    question: &Question,
+    chosen: Option<usize>,
+    i18n: &I18n,
+) -> Option<usize> {
+    let mut clicked = None;
+    let stat = match question.prompt {
+        Prompt::HigherStat(kind) => Some(kind),
+        _ => None,
+    };
+    egui::Grid::new("quiz_choices_grid").spacing([8.0, 8.0]).show(ui, |ui| {
+        for (i, choice) in question.choices.iter().enumerate() {
+            let label = choice_label(choice, stat, chosen.is_some(), database, i18n);
+            let mut button = egui::Button::new(egui::RichText::new(label).size(15.0)).min_size(egui::vec2(220.0, 32.0));
+            if chosen.is_some() && i == question.answer {
+                button = button.fill(egui::Color32::from_rgb(40, 140, 70));
+            } else if chosen == Some(i) {
+                button = button.fill(egui::Color32::from_rgb(200, 60, 50));
+            }
+            if ui.add(button).clicked() && chosen.is_none() {
+                clicked = Some(i);
+            }
+            if (i + 1) % CHOICES_PER_ROW == 0 {
+                ui.end_row();
+            }
+        }
+    });
+    clicked
+}
+
+fn render_high_scores(ui: &mut egui::Ui, state: &mut QuizState, i18n: &I18n) {
+    let difficulty = state.quiz.as_ref().map_or(state.settings.difficulty, |q| q.settings().difficulty);
+    ui.label(
+        egui::RichText::new(i18n.tr_args("quiz.high_scores", &[("difficulty", &i18n.tr(difficulty.key()))]))
+            .size(18.0)
+            .color(egui::Color32::from_rgb(40, 80, 120))
+            .strong()
+    );
+    let scores = state.high_scores.for_difficulty(difficulty);
+    if scores.is_empty() {
+        ui.label(egui::RichText::new(i18n.tr("quiz.no_scores")).color(egui::Color32::from_rgb(120, 140, 160)));
+        return;
+    }
+    egui::Grid::new("quiz_high_scores_grid").striped(true).spacing([10.0, 4.0]).show(ui, |ui| {
+        for (i, score) in scores.iter().enumerate() {
+            ui.label(format!("{}.", i + 1));
+            ui.label(egui::RichText::new(score.points.to_string()).strong());
+            ui.label(i18n.tr_args(
+                "quiz.score_detail",
+                &[("correct", &score.correct), ("total", &ROUND_LENGTH), ("streak", &score.best_streak)],
+            ));
+            ui.label(egui::RichText::new(&score.date).size(12.0).color(egui::Color32::from_rgb(120, 140, 160)));
+            ui.end_row();
+        }
+    });
+    ui.add_space(4.0);
+    if ui.small_button(i18n.tr("quiz.clear_scores")).clicked() {
+        state.high_scores.clear(difficulty);
+    }
+}
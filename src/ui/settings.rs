@@ -0,0 +1,303 @@
+use super::View;
+use crate::database::{Database, PokemonFilter};
+use crate::i18n::{I18n, Language};
+use crate::pokemon::{Pokemon, PokemonType, StatKind};
+use crate::storage;
+use eframe::egui;
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::path::PathBuf;
+
+const SETTINGS_FILE: &str = "settings.json";
+const DEFAULT_WINDOW_SIZE: egui::Vec2 = egui::vec2(900.0, 720.0);
+const ZOOM_RANGE: std::ops::RangeInclusive<f32> = 0.75..=2.0;
+const LIST_WIDTH_RANGE: std::ops::RangeInclusive<f32> = 300.0..=600.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Theme {
+    // Aspecto translúcido tipo Windows Vista
+    Vista,
+    // Estilo claro por defecto de egui, sin transparencias
+    Classic,
+}
+
+impl Theme {
+    const ALL: [Theme; 2] = [Theme::Vista, Theme::Classic];
+
+    fn key(&self) -> &'static str {
+        match self {
+            Theme::Vista => "settings.theme.vista",
+            Theme::Classic => "settings.theme.classic",
+        }
+    }
+}
+
+// Orden de la lista de Pokémon; las estadísticas van de mayor a menor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ListSort {
+    Id,
+    Name,
+    Total,
+    Stat(StatKind),
+}
+
+impl ListSort {
+    const ALL: [ListSort; 9] = [
+        ListSort::Id,
+        ListSort::Name,
+        ListSort::Total,
+        ListSort::Stat(StatKind::Hp),
+        ListSort::Stat(StatKind::Attack),
+        ListSort::Stat(StatKind::Defense),
+        ListSort::Stat(StatKind::SpecialAttack),
+        ListSort::Stat(StatKind::SpecialDefense),
+        ListSort::Stat(StatKind::Speed),
+    ];
+
+    fn label(&self, i18n: &I18n) -> String {
+        match self {
+            ListSort::Id => i18n.tr("sort.id").to_string(),
+            ListSort::Name => i18n.tr("sort.name").to_string(),
+            ListSort::Total => i18n.tr("sort.total").to_string(),
+            ListSort::Stat(kind) => i18n.tr(kind.key()).to_string(),
+        }
+    }
+}
+
+// Lo último que se estaba viendo, para recuperarlo al volver a abrir
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Session {
+    pub(super) view: View,
+    pub(super) search: String,
+    pub(super) pokemon_type: Option<PokemonType>,
+    pub(super) second_type: Option<PokemonType>,
+    pub(super) generation: Option<u8>,
+    pub(super) collection: Option<String>,
+    pub(super) selected: Option<u16>,
+    pub(super) selected_form: Option<String>,
+}
+
+// Tamaño y posición en puntos lógicos del sistema (sin el zoom de la aplicación)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WindowState {
+    pub size: Option<[f32; 2]>,
+    pub position: Option<[f32; 2]>,
+    pub maximized: bool,
+}
+
+impl WindowState {
+    pub fn initial_size(&self) -> egui::Vec2 {
+        self.size.map_or(DEFAULT_WINDOW_SIZE, |[w, h]| egui::vec2(w, h))
+    }
+
+    pub fn initial_position(&self) -> Option<egui::Pos2> {
+        self.position.map(|[x, y]| egui::pos2(x, y))
+    }
+
+    // Con la ventana maximizada o minimizada se conserva el último tamaño normal
+    pub fn capture(&mut self, info: &eframe::WindowInfo, scale: f32) {
+        self.maximized = info.maximized;
+        if info.maximized || info.minimized || info.fullscreen {
+            return;
+        }
+        if info.size.x > 0.0 && info.size.y > 0.0 {
+            self.size = Some([info.size.x * scale, info.size.y * scale]);
+        }
+        if let Some(position) = info.position {
+            self.position = Some([position.x * scale, position.y * scale]);
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub language: Language,
+    pub theme: Theme,
+    pub zoom: f32,
+    pub list_width: f32,
+    pub sort: ListSort,
+    pub reverse_sort: bool,
+    pub restore_session: bool,
+    pub session: Session,
+    pub window: WindowState,
+    #[serde(skip)]
+    path: Option<PathBuf>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            language: Language::Spanish,
+            theme: Theme::Vista,
+            zoom: 1.0,
+            list_width: 400.0,
+            sort: ListSort::Id,
+            reverse_sort: false,
+            restore_session: true,
+            session: Session::default(),
+            window: WindowState::default(),
+            path: None,
+        }
+    }
+}
+
+impl Settings {
+    pub fn path() -> Option<PathBuf> {
+        storage::config_file(SETTINGS_FILE)
+    }
+
+    // Si el archivo está dañado se avisa y se usan los valores por defecto
+    pub fn load() -> Self {
+        let Some(path) = Self::path() else {
+            return Self::default();
+        };
+        let mut settings = match storage::load_json::<Settings>(&path) {
+            Ok(settings) => settings.unwrap_or_default(),
+            Err(e) => {
+                eprintln!("Error al leer {}: {}", path.display(), e);
+                Self::default()
+            }
+        };
+        // Por si el archivo se editó a mano
+        settings.zoom = settings.zoom.clamp(*ZOOM_RANGE.start(), *ZOOM_RANGE.end());
+        settings.list_width = settings.list_width.clamp(*LIST_WIDTH_RANGE.start(), *LIST_WIDTH_RANGE.end());
+        settings.path = Some(path);
+        settings
+    }
+
+    pub fn save(&self) {
+        let Some(path) = &self.path else {
+            return;
+        };
+        if let Err(e) = storage::save_json(path, self) {
+            eprintln!("No se pudo guardar {}: {}", path.display(), e);
+        }
+    }
+
+    // Vuelve a las preferencias por defecto; la sesión y la ventana no se tocan
+    pub fn reset(&mut self) {
+        *self = Self {
+            session: std::mem::take(&mut self.session),
+            window: self.window,
+            path: self.path.take(),
+            ..Self::default()
+        };
+    }
+
+    // Especies que cumplen el filtro en el orden elegido; los empates conservan el orden de la Pokédex
+    pub fn sorted_list<'a>(&self, database: &'a Database, filter: &PokemonFilter, language: Language) -> Vec<&'a Pokemon> {
+        let mut list = database.query(filter);
+        match self.sort {
+            ListSort::Id => {}
+            ListSort::Name => list.sort_by_cached_key(|p| {
+                database
+                    .names()
+                    .species_name(p.id, language.code())
+                    .unwrap_or(&p.name)
+                    .to_lowercase()
+            }),
+            ListSort::Total => list.sort_by_key(|p| Reverse(p.total_stats())),
+            ListSort::Stat(kind) => list.sort_by_key(|p| Reverse(p.stats.get(kind))),
+        }
+        if self.reverse_sort {
+            list.reverse();
+        }
+        list
+    }
+}
+
+// Selector de orden para la cabecera de la lista; devuelve true si ha cambiado algo
+pub fn render_sort_selector(ui: &mut egui::Ui, id: &str, settings: &mut Settings, i18n: &I18n) -> bool {
+    let (sort, reverse) = (settings.sort, settings.reverse_sort);
+    egui::ComboBox::from_id_source(id)
+        .selected_text(settings.sort.label(i18n))
+        .show_ui(ui, |ui| {
+            for option in ListSort::ALL {
+                ui.selectable_value(&mut settings.sort, option, option.label(i18n));
+            }
+        });
+    let arrow = if settings.reverse_sort { "⬆" } else { "⬇" };
+    if ui.selectable_label(settings.reverse_sort, arrow).on_hover_text(i18n.tr("sort.reverse")).clicked() {
+        settings.reverse_sort = !settings.reverse_sort;
+    }
+    (sort, reverse) != (settings.sort, settings.reverse_sort)
+}
+
+pub fn render_settings_window(ctx: &egui::Context, settings: &mut Settings, i18n: &mut I18n, open: &mut bool) {
+    // El idioma también se cambia desde la barra de estado
+    settings.language = i18n.language();
+    let before = settings.clone();
+    egui::Window::new(i18n.tr("settings.title"))
+        .open(open)
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .show(ctx, |ui| {
+            let section = |text: &str| egui::RichText::new(text).color(egui::Color32::from_rgb(40, 80, 120));
+            egui::Grid::new("settings_grid")
+                .num_columns(2)
+                .spacing([24.0, 8.0])
+                .show(ui, |ui| {
+                    ui.label(section(i18n.tr("app.language")));
+                    egui::ComboBox::from_id_source("settings_language")
+                        .selected_text(settings.language.native_name())
+                        .show_ui(ui, |ui| {
+                            for language in Language::ALL {
+                                ui.selectable_value(&mut settings.language, language, language.native_name());
+                            }
+                        });
+                    ui.end_row();
+
+                    ui.label(section(i18n.tr("settings.theme")));
+                    ui.horizontal(|ui| {
+                        for theme in Theme::ALL {
+                            ui.selectable_value(&mut settings.theme, theme, i18n.tr(theme.key()));
+                        }
+                    });
+                    ui.end_row();
+
+                    ui.label(section(i18n.tr("settings.zoom")));
+                    ui.add(
+                        egui::Slider::new(&mut settings.zoom, ZOOM_RANGE)
+                            .step_by(0.05)
+                            .custom_formatter(|value, _| format!("{:.0}%", value * 100.0))
+                    );
+                    ui.end_row();
+
+                    ui.label(section(i18n.tr("settings.list_width")));
+                    ui.add(egui::Slider::new(&mut settings.list_width, LIST_WIDTH_RANGE).step_by(10.0).suffix(" px"));
+                    ui.end_row();
+
+                    ui.label(section(i18n.tr("settings.sort")));
+                    ui.horizontal(|ui| render_sort_selector(ui, "settings_sort", settings, i18n));
+                    ui.end_row();
+
+                    ui.label(section(i18n.tr("settings.session")));
+                    ui.checkbox(&mut settings.restore_session, i18n.tr("settings.restore_session"));
+                    ui.end_row();
+                });
+
+            ui.add_space(8.0);
+            if ui.button(i18n.tr("settings.reset")).clicked() {
+                settings.reset();
+            }
+            if let Some(path) = Settings::path() {
+                ui.add_space(8.0);
+                ui.label(
+                    egui::RichText::new(i18n.tr_args("settings.path", &[("path", &path.display())]))
+                        .size(12.0)
+                        .color(egui::Color32::from_rgb(120, 140, 160))
+                );
+            }
+        });
+
+    if settings.language != i18n.language() {
+        i18n.set_language(settings.language);
+    }
+    if *settings != before {
+        settings.save();
+    }
+}
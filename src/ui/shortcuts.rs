@@ -0,0 +1,307 @@
+use crate::i18n::I18n;
+use crate::storage;
+use eframe::egui;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+const KEYMAP_FILE: &str = "keymap.json";
+
+// Acciones que se pueden disparar con el teclado
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Action {
+    SelectNext,
+    SelectPrevious,
+    PageDown,
+    PageUp,
+    FocusSearch,
+    ClearSearch,
+    ToggleFavorite,
+    ToggleCompare,
+    HistoryBack,
+    HistoryForward,
+    ToggleHelp,
+}
+
+impl Action {
+    pub const ALL: [Action; 11] = [
+        Action::SelectNext,
+        Action::SelectPrevious,
+        Action::PageDown,
+        Action::PageUp,
+        Action::FocusSearch,
+        Action::ClearSearch,
+        Action::ToggleFavorite,
+        Action::ToggleCompare,
+        Action::HistoryBack,
+        Action::HistoryForward,
+        Action::ToggleHelp,
+    ];
+
+    // Clave del texto traducido en los catálogos de i18n
+    pub fn description_key(&self) -> &'static str {
+        match self {
+            Action::SelectNext => "shortcuts.select_next",
+            Action::SelectPrevious => "shortcuts.select_previous",
+            Action::PageDown => "shortcuts.page_down",
+            Action::PageUp => "shortcuts.page_up",
+            Action::FocusSearch => "shortcuts.focus_search",
+            Action::ClearSearch => "shortcuts.clear_search",
+            Action::ToggleFavorite => "shortcuts.toggle_favorite",
+            Action::ToggleCompare => "shortcuts.toggle_compare",
+            Action::HistoryBack => "shortcuts.history_back",
+            Action::HistoryForward => "shortcuts.history_forward",
+            Action::ToggleHelp => "shortcuts.toggle_help",
+        }
+    }
+
+    fn default_bindings(&self) -> &'static [&'static str] {
+        match self {
+            Action::SelectNext => &["Down"],
+            Action::SelectPrevious => &["Up"],
+            Action::PageDown => &["PageDown"],
+            Action::PageUp => &["PageUp"],
+            Action::FocusSearch => &["Ctrl+F", "/"],
+            Action::ClearSearch => &["Escape"],
+            Action::ToggleFavorite => &["F"],
+            Action::ToggleCompare => &["C"],
+            Action::HistoryBack => &["Alt+Left"],
+            Action::HistoryForward => &["Alt+Right"],
+            Action::ToggleHelp => &["F1"],
+        }
+    }
+}
+
+// Teclas que se pueden usar en un atajo (egui no trae un parser de nombres)
+const BINDABLE_KEYS: [egui::Key; 59] = [
+    egui::Key::ArrowDown, egui::Key::ArrowLeft, egui::Key::ArrowRight, egui::Key::ArrowUp,
+    egui::Key::Escape, egui::Key::Tab, egui::Key::Backspace, egui::Key::Enter, egui::Key::Space,
+    egui::Key::Insert, egui::Key::Delete, egui::Key::Home, egui::Key::End,
+    egui::Key::PageUp, egui::Key::PageDown,
+    egui::Key::Num0, egui::Key::Num1, egui::Key::Num2, egui::Key::Num3, egui::Key::Num4,
+    egui::Key::Num5, egui::Key::Num6, egui::Key::Num7, egui::Key::Num8, egui::Key::Num9,
+    egui::Key::A, egui::Key::B, egui::Key::C, egui::Key::D, egui::Key::E, egui::Key::F,
+    egui::Key::G, egui::Key::H, egui::Key::I, egui::Key::J, egui::Key::K, egui::Key::L,
+    egui::Key::M, egui::Key::N, egui::Key::O, egui::Key::P, egui::Key::Q, egui::Key::R,
+    egui::Key::S, egui::Key::T, egui::Key::U, egui::Key::V, egui::Key::W, egui::Key::X,
+    egui::Key::Y, egui::Key::Z,
+    egui::Key::F1, egui::Key::F2, egui::Key::F3, egui::Key::F4, egui::Key::F5,
+    egui::Key::F6, egui::Key::F7, egui::Key::F8,
+];
+
+// Un atajo puede ser una tecla con modificadores o un carácter escrito (como "/")
+#[derive(Debug, Clone, PartialEq)]
+pub enum Binding {
+    Key(egui::KeyboardShortcut),
+    Char(char),
+}
+
+impl Binding {
+    pub fn parse(text: &str) -> Option<Binding> {
+        let text = text.trim();
+        let mut chars = text.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            if !c.is_ascii_alphanumeric() {
+                return Some(Binding::Char(c));
+            }
+        }
+
+        let mut modifiers = egui::Modifiers::NONE;
+        let mut parts: Vec<&str> = text.split('+').map(str::trim).collect();
+        let key_name = parts.pop()?;
+        for part in parts {
+            match part.to_lowercase().as_str() {
+                "ctrl" | "cmd" => modifiers = modifiers | egui::Modifiers::COMMAND,
+                "alt" => modifiers = modifiers | egui::Modifiers::ALT,
+                "shift" => modifiers = modifiers | egui::Modifiers::SHIFT,
+                _ => return None,
+            }
+        }
+
+        let key = BINDABLE_KEYS
+            .iter()
+            .find(|key| key.name().eq_ignore_ascii_case(key_name))?;
+        Some(Binding::Key(egui::KeyboardShortcut::new(modifiers, *key)))
+    }
+
+    pub fn label(&self, ctx: &egui::Context) -> String {
+        match self {
+            Binding::Key(shortcut) => ctx.format_shortcut(shortcut),
+            Binding::Char(c) => c.to_string(),
+        }
+    }
+
+    // Atajos que chocan con la escritura en el buscador
+    fn conflicts_with_typing(&self) -> bool {
+        match self {
+            Binding::Char(_) => true,
+            Binding::Key(shortcut) => {
+                !shortcut.modifiers.command
+                    && !shortcut.modifiers.alt
+                    && !matches!(
+                        shortcut.key,
+                        egui::Key::ArrowUp
+                            | egui::Key::ArrowDown
+                            | egui::Key::PageUp
+                            | egui::Key::PageDown
+                            | egui::Key::Escape
+                            | egui::Key::F1
+                            | egui::Key::F2
+                            | egui::Key::F3
+                            | egui::Key::F4
+                            | egui::Key::F5
+                            | egui::Key::F6
+                            | egui::Key::F7
+                            | egui::Key::F8
+                    )
+            }
+        }
+    }
+
+    fn consume(&self, input: &mut egui::InputState) -> bool {
+        match self {
+            Binding::Key(shortcut) => input.consume_shortcut(shortcut),
+            Binding::Char(c) => {
+                let text = c.to_string();
+                let mut found = false;
+                input.events.retain(|event| {
+                    let is_match = matches!(event, egui::Event::Text(t) if *t == text);
+                    found |= is_match;
+                    !is_match
+                });
+                found
+            }
+        }
+    }
+}
+
+// Formato del archivo keymap.json: acción -> lista de atajos, p. ej. "FocusSearch": ["Ctrl+F", "/"]
+type KeymapFile = BTreeMap<Action, Vec<String>>;
+
+pub struct Keymap {
+    bindings: BTreeMap<Action, Vec<Binding>>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let bindings = Action::ALL
+            .iter()
+            .map(|action| {
+                let parsed = action
+                    .default_bindings()
+                    .iter()
+                    .filter_map(|text| Binding::parse(text))
+                    .collect();
+                (*action, parsed)
+            })
+            .collect();
+        Self { bindings }
+    }
+}
+
+impl Keymap {
+    pub fn path() -> Option<PathBuf> {
+        storage::config_file(KEYMAP_FILE)
+    }
+
+    // Carga los atajos del usuario; si no hay archivo se escribe uno con los valores por defecto
+    pub fn load() -> Self {
+        let mut keymap = Keymap::default();
+        let Some(path) = Self::path() else {
+            return keymap;
+        };
+
+        match storage::load_json::<KeymapFile>(&path) {
+            Ok(Some(file)) => {
+                for (action, texts) in file {
+                    let parsed: Vec<Binding> = texts
+                        .iter()
+                        .filter_map(|text| {
+                            let binding = Binding::parse(text);
+                            if binding.is_none() {
+                                eprintln!("Atajo no reconocido para {:?}: {}", action, text);
+                            }
+                            binding
+                        })
+                        .collect();
+                    keymap.bindings.insert(action, parsed);
+                }
+            }
+            Ok(None) => {
+                if let Err(e) = storage::save_json(&path, &Self::default_file()) {
+                    eprintln!("No se pudo guardar {}: {}", path.display(), e);
+                }
+            }
+            Err(e) => eprintln!("Error al leer {}: {}", path.display(), e),
+        }
+        keymap
+    }
+
+    fn default_file() -> KeymapFile {
+        Action::ALL
+            .iter()
+            .map(|action| {
+                let texts = action.default_bindings().iter().map(|s| s.to_string()).collect();
+                (*action, texts)
+            })
+            .collect()
+    }
+
+    pub fn bindings(&self, action: Action) -> &[Binding] {
+        self.bindings.get(&action).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    // Devuelve las acciones disparadas en este frame, consumiendo sus teclas
+    pub fn pressed_actions(&self, ctx: &egui::Context) -> Vec<Action> {
+        let typing = ctx.wants_keyboard_input();
+        ctx.input_mut(|input| {
+            Action::ALL
+                .iter()
+                .filter(|action| {
+                    self.bindings(**action).iter().any(|binding| {
+                        !(typing && binding.conflicts_with_typing()) && binding.consume(input)
+                    })
+                })
+                .copied()
+                .collect()
+        })
+    }
+}
+
+pub fn render_shortcuts_help(ctx: &egui::Context, keymap: &Keymap, i18n: &I18n, open: &mut bool) {
+    egui::Window::new(i18n.tr("shortcuts.title"))
+        .open(open)
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .show(ctx, |ui| {
+            egui::Grid::new("shortcuts_help_grid")
+                .num_columns(2)
+                .spacing([24.0, 6.0])
+                .striped(true)
+                .show(ui, |ui| {
+                    for action in Action::ALL {
+                        ui.label(
+                            egui::RichText::new(i18n.tr(action.description_key()))
+                                .color(egui::Color32::from_rgb(40, 80, 120))
+                        );
+                        let keys: Vec<String> = keymap
+                            .bindings(action)
+                            .iter()
+                            .map(|binding| binding.label(ctx))
+                            .collect();
+                        ui.label(egui::RichText::new(keys.join("  /  ")).strong());
+                        ui.end_row();
+                    }
+                });
+
+            if let Some(path) = Keymap::path() {
+                ui.add_space(8.0);
+                ui.label(
+                    egui::RichText::new(i18n.tr_args("shortcuts.config_path", &[("path", &path.display())]))
+                        .size(12.0)
+                        .color(egui::Color32::from_rgb(120, 140, 160))
+                );
+            }
+        });
+}
@@ -0,0 +1,106 @@
+use super::render_vista_type_badge;
+use crate::database::Database;
+use crate::i18n::I18n;
+use crate::pokemon::{Pokemon, PokemonType, StatKind};
+use crate::similarity::{self, Distance, SimilarityOptions, TypeConstraint};
+use eframe::egui;
+
+const MAX_K: usize = 20;
+const MAX_WEIGHT: f32 = 3.0;
+
+#[derive(Default)]
+pub struct SimilarState {
+    options: SimilarityOptions,
+}
+
+fn constraint_label(constraint: TypeConstraint, database: &Database, i18n: &I18n) -> String {
+    match constraint {
+        TypeConstraint::Any => i18n.tr("similar.types.any").to_string(),
+        TypeConstraint::SharesType => i18n.tr("similar.types.shared").to_string(),
+        TypeConstraint::DifferentTypes => i18n.tr("similar.types.different").to_string(),
+        TypeConstraint::HasType(pokemon_type) => i18n.type_name(pokemon_type, database.names()),
+    }
+}
+
+// Sección "Similares" del panel de detalles; devuelve el Pokémon en el que se hace clic
+pub fn render_similar_section(
+    ui: &mut egui::Ui,
+    database: &Database,
+    pokemon: &Pokemon,
+    state: &mut SimilarState,
+    i18n: &I18n,
+) -> Option<u16> {
+    let mut clicked = None;
+    let options = &mut state.options;
+    egui::CollapsingHeader::new(i18n.tr("similar.title"))
+        .id_source("similar_section")
+        .default_open(true)
+        .show(ui, |ui| {
+            ui.horizontal_wrapped(|ui| {
+                egui::ComboBox::from_id_source("similar_distance")
+                    .selected_text(i18n.tr(options.distance.key()))
+                    .show_ui(ui, |ui| {
+                        for distance in Distance::ALL {
+                            ui.selectable_value(&mut options.distance, distance, i18n.tr(distance.key()));
+                        }
+                    });
+                egui::ComboBox::from_id_source("similar_types")
+                    .selected_text(constraint_label(options.types, database, i18n))
+                    .show_ui(ui, |ui| {
+                        let constraints = [TypeConstraint::Any, TypeConstraint::SharesType, TypeConstraint::DifferentTypes]
+                            .into_iter()
+                            .chain(PokemonType::ALL.into_iter().map(TypeConstraint::HasType));
+                        for constraint in constraints {
+                            let label = constraint_label(constraint, database, i18n);
+                            ui.selectable_value(&mut options.types, constraint, label);
+                        }
+                    });
+                ui.label(i18n.tr("similar.count"));
+                ui.add(egui::DragValue::new(&mut options.k).clamp_range(1..=MAX_K));
+            });
+
+            ui.collapsing(i18n.tr("similar.weights"), |ui| {
+                for kind in StatKind::ALL {
+                    let mut weight = options.weight(kind);
+                    ui.horizontal(|ui| {
+                        ui.add_sized([70.0, 18.0], egui::Label::new(i18n.stat_name(kind)));
+                        if ui.add(egui::Slider::new(&mut weight, 0.0..=MAX_WEIGHT).step_by(0.25)).changed() {
+                            options.set_weight(kind, weight);
+                        }
+                    });
+                }
+                if ui.small_button(i18n.tr("similar.reset_weights")).clicked() {
+                    options.weights = SimilarityOptions::default().weights;
+                }
+            });
+
+            let neighbors = similarity::find_similar(database, pokemon, options);
+            if neighbors.is_empty() {
+                ui.label(
+                    egui::RichText::new(i18n.tr("similar.none"))
+                        .color(egui::Color32::from_rgb(120, 140, 160))
+                );
+            }
+            for neighbor in neighbors {
+                ui.horizontal(|ui| {
+                    let name = format!("#{:03} {}", neighbor.pokemon.id, i18n.pokemon_name(neighbor.pokemon, database.names()));
+                    if ui.link(egui::RichText::new(name).color(egui::Color32::from_rgb(40, 110, 80))).clicked() {
+                        clicked = Some(neighbor.pokemon.id);
+                    }
+                    for pokemon_type in &neighbor.pokemon.types {
+                        render_vista_type_badge(ui, pokemon_type, &i18n.type_name(*pokemon_type, database.names()));
+                    }
+                    let score = match options.distance {
+                        Distance::Cosine => format!("{:.1}%", neighbor.score * 100.0),
+                        Distance::Euclidean => format!("{:.1}", neighbor.score),
+                    };
+                    ui.label(
+                        egui::RichText::new(score)
+                            .size(13.0)
+                            .color(egui::Color32::from_rgb(120, 140, 160))
+                    );
+                });
+            }
+        });
+    clicked
+}
@@ -0,0 +1,179 @@
+use crate::database::Database;
+use crate::i18n::I18n;
+use crate::pokemon::Pokemon;
+use crate::speed::{self, Modifier, Spread, SpeedEntry, MAX_STAGE, MIN_STAGE};
+use eframe::egui;
+
+const ROW_HEIGHT: f32 = 18.0;
+
+pub struct SpeedState {
+    level: u8,
+    spread: Spread,
+    modifier: Modifier,
+    // Inversión y modificadores que se suponen en los rivales
+    rival_spread: Spread,
+    rival_modifier: Modifier,
+}
+
+impl Default for SpeedState {
+    fn default() -> Self {
+        Self {
+            level: 50,
+            spread: Spread::MaxPositive,
+            modifier: Modifier::default(),
+            rival_spread: Spread::MaxNeutral,
+            rival_modifier: Modifier::default(),
+        }
+    }
+}
+
+// Devuelve el Pokémon de las listas en el que se ha hecho clic
+pub fn render_vista_speed_panel(
+    ui: &mut egui::Ui,
+    database: &Database,
+    selected: Option<&Pokemon>,
+    state: &mut SpeedState,
+    i18n: &I18n,
+) -> Option<u16> {
+    let mut clicked = None;
+    let vista_panel_frame = egui::Frame::none()
+        .fill(egui::Color32::from_rgba_premultiplied(240, 248, 255, 200))
+        .rounding(egui::Rounding::same(8.0))
+        .stroke(egui::Stroke::new(1.0, egui::Color32::from_rgb(180, 200, 220)))
+        .inner_margin(egui::style::Margin::same(8.0))
+        .shadow(egui::epaint::Shadow {
+            extrusion: 6.0,
+            color: egui::Color32::from_rgba_premultiplied(0, 0, 0, 40),
+        });
+
+    vista_panel_frame.show(ui, |ui| {
+        ui.set_width(ui.available_width());
+        ui.set_height(450.0);
+
+        ui.heading(
+            egui::RichText::new(i18n.tr("speed.title"))
+                .size(24.0)
+                .color(egui::Color32::from_rgb(40, 110, 80))
+                .strong()
+        );
+        ui.horizontal(|ui| {
+            ui.label(
+                egui::RichText::new(i18n.tr("speed.level"))
+                    .color(egui::Color32::from_rgb(40, 80, 120))
+            );
+            ui.add(egui::DragValue::new(&mut state.level).clamp_range(1..=100));
+        });
+        render_settings(ui, "speed_subject", i18n.tr("speed.subject"), &mut state.spread, &mut state.modifier, i18n);
+        render_settings(ui, "speed_rivals", i18n.tr("speed.rivals"), &mut state.rival_spread, &mut state.rival_modifier, i18n);
+
+        let Some(pokemon) = selected else {
+            ui.label(
+                egui::RichText::new(i18n.tr("speed.no_selection"))
+                    .color(egui::Color32::from_rgb(120, 140, 160))
+            );
+            return;
+        };
+
+        let speed = speed::speed_at(pokemon, state.level, state.spread, state.modifier);
+        let tiers = speed::speed_tiers(database, state.level, state.rival_spread, state.rival_modifier);
+        let comparison = speed::compare(pokemon, speed, &tiers);
+
+        ui.separator();
+        ui.horizontal_wrapped(|ui| {
+            ui.label(
+                egui::RichText::new(format!(
+                    "{}: {}",
+                    i18n.pokemon_name(pokemon, database.names()),
+                    comparison.speed
+                ))
+                .size(18.0)
+                .color(egui::Color32::from_rgb(40, 80, 120))
+                .strong()
+            );
+            // Velocidad con cada inversión, sin modificadores
+            for spread in Spread::ALL {
+                let value = speed::speed_at(pokemon, state.level, spread, Modifier::default());
+                ui.label(
+                    egui::RichText::new(format!("{} {}", i18n.tr(spread.key()), value))
+                        .color(egui::Color32::from_rgb(120, 140, 160))
+                );
+            }
+        });
+        ui.label(i18n.tr_args(
+            "speed.position",
+            &[
+                ("rank", &(comparison.underspeeds.len() + 1)),
+                ("total", &tiers.len()),
+                ("percent", &format!("{:.1}", comparison.percentile())),
+            ],
+        ));
+
+        ui.columns(3, |columns| {
+            let lists = [
+                ("speed.underspeeds", &comparison.underspeeds, egui::Color32::from_rgb(200, 60, 50)),
+                ("speed.ties", &comparison.ties, egui::Color32::from_rgb(200, 140, 30)),
+                ("speed.outspeeds", &comparison.outspeeds, egui::Color32::from_rgb(40, 140, 70)),
+            ];
+            for (column, (key, entries, color)) in columns.iter_mut().zip(lists) {
+                if let Some(id) = render_tier_list(column, database, key, entries, color, i18n) {
+                    clicked = Some(id);
+                }
+            }
+        });
+    });
+    clicked
+}
+
+fn render_settings(
+    ui: &mut egui::Ui,
+    id: &str,
+    label: &str,
+    spread: &mut Spread,
+    modifier: &mut Modifier,
+    i18n: &I18n,
+) {
+    ui.horizontal(|ui| {
+        ui.label(
+            egui::RichText::new(label)
+                .color(egui::Color32::from_rgb(40, 80, 120))
+        );
+        egui::ComboBox::from_id_source(id)
+            .selected_text(i18n.tr(spread.key()))
+            .show_ui(ui, |ui| {
+                for option in Spread::ALL {
+                    ui.selectable_value(spread, option, i18n.tr(option.key()));
+                }
+            });
+        ui.label(i18n.tr("speed.stage"));
+        ui.add(egui::Slider::new(&mut modifier.stage, MIN_STAGE..=MAX_STAGE));
+        ui.checkbox(&mut modifier.scarf, i18n.tr("speed.scarf"));
+    });
+}
+
+fn render_tier_list(
+    ui: &mut egui::Ui,
+    database: &Database,
+    key: &str,
+    entries: &[SpeedEntry],
+    color: egui::Color32,
+    i18n: &I18n,
+) -> Option<u16> {
+    let mut clicked = None;
+    ui.label(
+        egui::RichText::new(i18n.tr_args(key, &[("count", &entries.len())]))
+            .color(color)
+            .strong()
+    );
+    egui::ScrollArea::vertical()
+        .id_source(key)
+        .auto_shrink([false, false])
+        .show_rows(ui, ROW_HEIGHT, entries.len(), |ui, range| {
+            for entry in &entries[range] {
+                let name = i18n.pokemon_name(entry.pokemon, database.names());
+                if ui.link(format!("{} · {}", entry.speed, name)).clicked() {
+                    clicked = Some(entry.pokemon.id);
+                }
+            }
+        });
+    clicked
+}
@@ -0,0 +1,108 @@
+use crate::pokemon::Pokemon;
+use crate::sprites::{find_sprite, sprite_stem, sprites_dir};
+use eframe::egui;
+use std::collections::HashMap;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+
+// Caché de sprites locales (ver crate::sprites para los nombres de archivo)
+pub struct SpriteCache {
+    dir: PathBuf,
+    textures: HashMap<PathBuf, Option<egui::TextureHandle>>,
+    paths: HashMap<(String, bool), Option<PathBuf>>,
+}
+
+impl SpriteCache {
+    pub fn new(dir: PathBuf) -> Self {
+        Self {
+            dir,
+            textures: HashMap::new(),
+            paths: HashMap::new(),
+        }
+    }
+
+    pub fn from_env() -> Self {
+        Self::new(sprites_dir())
+    }
+
+    pub fn has_shiny(&mut self, pokemon: &Pokemon) -> bool {
+        self.find_path(pokemon, true).is_some()
+    }
+
+    // Textura del sprite; None si no existe o no se pudo cargar
+    pub fn get(&mut self, ctx: &egui::Context, pokemon: &Pokemon, shiny: bool) -> Option<egui::TextureHandle> {
+        let path = self.find_path(pokemon, shiny)?;
+        self.textures
+            .entry(path.clone())
+            .or_insert_with(|| match load_image(&path) {
+                Ok(image) => Some(ctx.load_texture(
+                    path.to_string_lossy(),
+                    image,
+                    egui::TextureOptions::NEAREST,
+                )),
+                Err(e) => {
+                    eprintln!("No se pudo cargar el sprite {}: {}", path.display(), e);
+                    None
+                }
+            })
+            .clone()
+    }
+
+    fn find_path(&mut self, pokemon: &Pokemon, shiny: bool) -> Option<PathBuf> {
+        let key = (sprite_stem(pokemon), shiny);
+        if let Some(cached) = self.paths.get(&key) {
+            return cached.clone();
+        }
+
+        let found = find_sprite(&self.dir, pokemon, shiny);
+        self.paths.insert(key, found.clone());
+        found
+    }
+}
+
+// PNG o GIF (de los GIF animados se usa el primer cuadro)
+fn load_image(path: &Path) -> Result<egui::ColorImage, Box<dyn Error>> {
+    let image = image::open(path)?.to_rgba8();
+    let size = [image.width() as usize, image.height() as usize];
+    Ok(egui::ColorImage::from_rgba_unmultiplied(size, image.as_raw()))
+}
+
+// Dibuja el sprite o un recuadro con "?" cuando no hay imagen
+pub fn render_sprite(ui: &mut egui::Ui, texture: Option<&egui::TextureHandle>, size: f32) {
+    let (rect, _) = ui.allocate_exact_size(egui::vec2(size, size), egui::Sense::hover());
+    paint_sprite(ui, rect, texture);
+}
+
+// La silueta es el sprite teñido de negro (el tinte multiplica el color y respeta la transparencia)
+pub fn render_silhouette(ui: &mut egui::Ui, texture: Option<&egui::TextureHandle>, size: f32) {
+    let (rect, _) = ui.allocate_exact_size(egui::vec2(size, size), egui::Sense::hover());
+    paint_tinted(ui, rect, texture, egui::Color32::BLACK);
+}
+
+pub fn paint_sprite(ui: &egui::Ui, rect: egui::Rect, texture: Option<&egui::TextureHandle>) {
+    paint_tinted(ui, rect, texture, egui::Color32::WHITE);
+}
+
+fn paint_tinted(ui: &egui::Ui, rect: egui::Rect, texture: Option<&egui::TextureHandle>, tint: egui::Color32) {
+    match texture {
+        Some(texture) => {
+            let uv = egui::Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(1.0, 1.0));
+            ui.painter().image(texture.id(), rect, uv, tint);
+        }
+        None => {
+            ui.painter().rect(
+                rect.shrink(1.0),
+                egui::Rounding::same(rect.height() * 0.2),
+                egui::Color32::from_rgb(225, 235, 245),
+                egui::Stroke::new(1.0, egui::Color32::from_rgb(180, 200, 220)),
+            );
+            ui.painter().text(
+                rect.center(),
+                egui::Align2::CENTER_CENTER,
+                "?",
+                egui::FontId::proportional(rect.height() * 0.5),
+                egui::Color32::from_rgb(140, 160, 180),
+            );
+        }
+    }
+}
@@ -0,0 +1,525 @@
+use super::sprites::{render_sprite, SpriteCache};
+use super::{render_vista_type_badge, type_color};
+use crate::analytics::Metric;
+use crate::collections::{Collection, CollectionKind, CollectionStore};
+use crate::database::Database;
+use crate::i18n::I18n;
+use crate::pokemon::PokemonType;
+use crate::showdown::{self, Diagnostic, Severity};
+use crate::team::{self, TeamMember, MAX_TEAM_SIZE};
+use eframe::egui;
+
+const MEMBER_SPRITE: f32 = 48.0;
+
+#[derive(Default)]
+pub struct TeamBuilderState {
+    // Índice en el almacén de colecciones del equipo que se está editando
+    active: Option<usize>,
+    rename_text: String,
+    rename_source: Option<usize>,
+    // Ventana de importación en formato Showdown
+    import_open: bool,
+    import_text: String,
+    import_diagnostics: Vec<Diagnostic>,
+    // Mensaje breve tras copiar al portapapeles
+    notice: Option<String>,
+}
+
+impl TeamBuilderState {
+    // El equipo activo; si ya no existe se toma el primero que haya
+    fn active_index(&mut self, store: &CollectionStore) -> Option<usize> {
+        let valid = self
+            .active
+            .filter(|&i| store.get(i).is_some_and(|c| c.kind == CollectionKind::Team));
+        self.active = valid.or_else(|| store.indices_of(CollectionKind::Team).first().copied());
+        self.active
+    }
+
+    fn new_team(&mut self, store: &mut CollectionStore, i18n: &I18n) -> usize {
+        let number = store.indices_of(CollectionKind::Team).len() + 1;
+        let name = i18n.tr_args("team.default_name", &[("number", &number)]);
+        let index = store.create(&name, CollectionKind::Team);
+        self.active = Some(index);
+        index
+    }
+
+    // Añade la forma que se ve en la lista al equipo activo (se ignora si está completo)
+    pub fn add_species(&mut self, store: &mut CollectionStore, database: &Database, id: u16, form: Option<&str>, i18n: &I18n) {
+        let index = match self.active_index(store) {
+            Some(index) => index,
+            None => self.new_team(store, i18n),
+        };
+        if let (Some(pokemon), Some(team)) = (database.get_pokemon_form(id, form), store.get_mut(index)) {
+            let _ = team.add(TeamMember::from_pokemon(pokemon));
+        }
+        store.save();
+    }
+
+    // Crea un equipo nuevo con los Pokémon reconocidos; los avisos quedan visibles en la ventana
+    fn import(&mut self, store: &mut CollectionStore, database: &Database, i18n: &I18n) {
+        let import = showdown::parse_team(&self.import_text, database);
+        self.import_diagnostics = import.diagnostics;
+        if import.members.is_empty() {
+            return;
+        }
+        let index = self.new_team(store, i18n);
+        if let Some(name) = import.name {
+            store.rename(index, &name);
+        }
+        if let Some(team) = store.get_mut(index) {
+            team.members = import.members;
+        }
+        store.save();
+        if self.import_diagnostics.is_empty() {
+            self.import_open = false;
+            self.import_text.clear();
+        }
+    }
+}
+
+// Multiplicador en formato corto: ×2, ×½, ×0...
+pub fn format_multiplier(multiplier: f32) -> String {
+    let text = if multiplier == 0.25 {
+        "¼".to_string()
+    } else if multiplier == 0.5 {
+        "½".to_string()
+    } else {
+        multiplier.to_string()
+    };
+    format!("×{}", text)
+}
+
+pub fn multiplier_color(multiplier: f32) -> egui::Color32 {
+    if multiplier > 1.0 {
+        egui::Color32::from_rgb(200, 60, 50)
+    } else if multiplier == 0.0 {
+        egui::Color32::from_rgb(90, 90, 110)
+    } else if multiplier < 1.0 {
+        egui::Color32::from_rgb(40, 140, 70)
+    } else {
+        egui::Color32::from_rgb(160, 170, 180)
+    }
+}
+
+pub fn render_vista_team_builder_panel(
+    ui: &mut egui::Ui,
+    database: &Database,
+    store: &mut CollectionStore,
+    state: &mut TeamBuilderState,
+    sprites: &mut SpriteCache,
+    i18n: &I18n,
+) {
+    let vista_panel_frame = egui::Frame::none()
+        .fill(egui::Color32::from_rgba_premultiplied(240, 248, 255, 200))
+        .rounding(egui::Rounding::same(8.0))
+        .stroke(egui::Stroke::new(1.0, egui::Color32::from_rgb(180, 200, 220)))
+        .inner_margin(egui::style::Margin::same(8.0))
+        .shadow(egui::epaint::Shadow {
+            extrusion: 6.0,
+            color: egui::Color32::from_rgba_premultiplied(0, 0, 0, 40),
+        });
+
+    vista_panel_frame.show(ui, |ui| {
+        ui.set_width(ui.available_width());
+        ui.set_height(450.0);
+
+        ui.heading(
+            egui::RichText::new(i18n.tr("team.title"))
+                .size(24.0)
+                .color(egui::Color32::from_rgb(40, 110, 80))
+                .strong()
+        );
+        let active = state.active_index(store);
+        render_team_selector(ui, database, store, state, active, i18n);
+        render_import_window(ui.ctx(), database, store, state, i18n);
+        let Some(index) = active else {
+            ui.label(
+                egui::RichText::new(i18n.tr("team.empty"))
+                    .color(egui::Color32::from_rgb(120, 140, 160))
+            );
+            return;
+        };
+
+        egui::ScrollArea::vertical().id_source("team_scroll").show(ui, |ui| {
+            let Some(team) = store.get_mut(index) else {
+                return;
+            };
+            if render_members(ui, database, team, sprites, i18n) {
+                store.save();
+            }
+
+            let members = store.get(index).map(|team| team.resolve(database)).unwrap_or_default();
+            if members.is_empty() {
+                return;
+            }
+            let analysis = team::analyze(&members);
+
+            // Debilidades compartidas
+            section_title(ui, i18n.tr("team.risks"));
+            let risks = analysis.risks();
+            if risks.is_empty() {
+                ui.label(
+                    egui::RichText::new(i18n.tr("team.no_risks"))
+                        .color(egui::Color32::from_rgb(40, 140, 70))
+                );
+            }
+            for risk in risks {
+                let defense = analysis.defense.iter().find(|d| d.attacking == risk);
+                let weak = defense.map_or(0, |d| d.weak);
+                let type_name = i18n.type_name(risk, database.names());
+                ui.label(
+                    egui::RichText::new(i18n.tr_args(
+                        "team.risk_line",
+                        &[("count", &weak), ("size", &analysis.size), ("type", &type_name)],
+                    ))
+                    .color(egui::Color32::from_rgb(200, 60, 50))
+                    .strong()
+                );
+            }
+
+            // Tabla defensiva: multiplicador de cada miembro contra cada tipo atacante
+            section_title(ui, i18n.tr("team.defense"));
+            egui::Grid::new("team_defense_grid")
+                .num_columns(analysis.size + 4)
+                .spacing([6.0, 3.0])
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.label("");
+                    for pokemon in &members {
+                        ui.label(egui::RichText::new(format!("#{}", pokemon.id)).size(11.0))
+                            .on_hover_text(i18n.pokemon_name(pokemon, database.names()));
+                    }
+                    for key in ["team.weak", "team.resist", "team.immune"] {
+                        ui.label(egui::RichText::new(i18n.tr(key)).size(11.0).strong());
+                    }
+                    ui.end_row();
+
+                    for defense in &analysis.defense {
+                        render_type_chip(ui, defense.attacking, &i18n.type_name(defense.attacking, database.names()), true);
+                        for &multiplier in &defense.multipliers {
+                            ui.label(
+                                egui::RichText::new(format_multiplier(multiplier))
+                                    .color(multiplier_color(multiplier))
+                            );
+                        }
+                        let weak_text = egui::RichText::new(defense.weak.to_string());
+                        ui.label(if defense.is_risk() {
+                            weak_text.color(egui::Color32::from_rgb(200, 60, 50)).strong()
+                        } else {
+                            weak_text
+                        });
+                        ui.label(defense.resist.to_string());
+                        ui.label(defense.immune.to_string());
+                        ui.end_row();
+                    }
+                });
+
+            // Cobertura ofensiva con los tipos de los propios miembros
+            section_title(ui, i18n.tr("team.offense"));
+            ui.horizontal_wrapped(|ui| {
+                for coverage in &analysis.offense {
+                    let name = i18n.type_name(coverage.defending, database.names());
+                    let sources: Vec<String> = coverage
+                        .sources
+                        .iter()
+                        .map(|&t| i18n.type_name(t, database.names()))
+                        .collect();
+                    render_type_chip(ui, coverage.defending, &name, coverage.best > 1.0)
+                        .on_hover_text(i18n.tr_args(
+                            "team.offense_tooltip",
+                            &[("multiplier", &format_multiplier(coverage.best)), ("types", &sources.join(", "))],
+                        ));
+                }
+            });
+
+            let uncovered: Vec<String> = analysis
+                .uncovered()
+                .into_iter()
+                .map(|t| i18n.type_name(t, database.names()))
+                .collect();
+            if !uncovered.is_empty() {
+                ui.label(
+                    egui::RichText::new(i18n.tr_args("team.uncovered", &[("types", &uncovered.join(", "))]))
+                        .size(12.0)
+                        .color(egui::Color32::from_rgb(120, 140, 160))
+                );
+            }
+
+            // Totales y medias de estadísticas
+            section_title(ui, i18n.tr("team.stats"));
+            egui::Grid::new("team_stats_grid")
+                .num_columns(3)
+                .spacing([24.0, 4.0])
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.label("");
+                    ui.label(egui::RichText::new(i18n.tr("team.total")).strong());
+                    ui.label(egui::RichText::new(i18n.tr("team.average")).strong());
+                    ui.end_row();
+                    for metric in Metric::ALL {
+                        ui.label(
+                            egui::RichText::new(i18n.tr(metric.key()))
+                                .color(egui::Color32::from_rgb(40, 80, 120))
+                        );
+                        ui.label(analysis.stat_totals.get(&metric).copied().unwrap_or(0).to_string());
+                        ui.label(format!("{:.1}", analysis.stat_averages.get(&metric).copied().unwrap_or(0.0)));
+                        ui.end_row();
+                    }
+                });
+        });
+    });
+}
+
+fn render_team_selector(
+    ui: &mut egui::Ui,
+    database: &Database,
+    store: &mut CollectionStore,
+    state: &mut TeamBuilderState,
+    active: Option<usize>,
+    i18n: &I18n,
+) {
+    let active_name = active.and_then(|i| store.get(i)).map(|team| team.name.clone());
+    if state.rename_source != active {
+        state.rename_text = active_name.clone().unwrap_or_default();
+        state.rename_source = active;
+    }
+
+    ui.horizontal(|ui| {
+        egui::ComboBox::from_id_source("team_selector")
+            .selected_text(active_name.unwrap_or_default())
+            .show_ui(ui, |ui| {
+                for index in store.indices_of(CollectionKind::Team) {
+                    if let Some(team) = store.get(index) {
+                        ui.selectable_value(&mut state.active, Some(index), &team.name);
+                    }
+                }
+            });
+        if ui.button(i18n.tr("team.new")).clicked() {
+            state.new_team(store, i18n);
+            store.save();
+        }
+        if let Some(index) = active {
+            if ui.button(i18n.tr("team.delete")).clicked() {
+                store.delete(index);
+                store.save();
+                state.active = None;
+            }
+        }
+    });
+
+    let Some(index) = active else {
+        return;
+    };
+    ui.horizontal(|ui| {
+        ui.label(
+            egui::RichText::new(i18n.tr("team.rename"))
+                .color(egui::Color32::from_rgb(40, 80, 120))
+        );
+        let response = ui.text_edit_singleline(&mut state.rename_text);
+        if response.lost_focus() {
+            store.rename(index, &state.rename_text);
+            store.save();
+            state.rename_source = None;
+        }
+    });
+    ui.horizontal(|ui| {
+        if ui.button(i18n.tr("showdown.import")).clicked() {
+            state.import_open = true;
+        }
+        let members = store.get(index).map(|team| team.members.as_slice()).unwrap_or_default();
+        let export = ui.add_enabled(!members.is_empty(), egui::Button::new(i18n.tr("showdown.export")));
+        if export.clicked() {
+            let text = showdown::export_team(members, database);
+            ui.output_mut(|output| output.copied_text = text);
+            state.notice = Some(i18n.tr("showdown.copied").to_string());
+        }
+        if let Some(notice) = &state.notice {
+            ui.label(
+                egui::RichText::new(notice)
+                    .size(12.0)
+                    .color(egui::Color32::from_rgb(40, 140, 70))
+            );
+        }
+    });
+}
+
+fn render_members(
+    ui: &mut egui::Ui,
+    database: &Database,
+    team: &mut Collection,
+    sprites: &mut SpriteCache,
+    i18n: &I18n,
+) -> bool {
+    section_title(
+        ui,
+        &i18n.tr_args("team.members", &[("count", &team.members.len()), ("max", &MAX_TEAM_SIZE)]),
+    );
+    if team.members.is_empty() {
+        ui.label(
+            egui::RichText::new(i18n.tr("team.empty"))
+                .color(egui::Color32::from_rgb(120, 140, 160))
+        );
+        return false;
+    }
+
+    // Devuelve si hubo cambios para guardar las colecciones
+    let mut changed = false;
+    let mut remove = None;
+    for (index, member) in team.members.iter_mut().enumerate() {
+        let Some(pokemon) = database.get_pokemon_form(member.id, member.form.as_deref()) else {
+            continue;
+        };
+        ui.horizontal(|ui| {
+            let texture = sprites.get(ui.ctx(), pokemon, false);
+            render_sprite(ui, texture.as_ref(), MEMBER_SPRITE);
+            ui.vertical(|ui| {
+                ui.label(
+                    egui::RichText::new(i18n.pokemon_name(pokemon, database.names()))
+                        .size(16.0)
+                        .color(egui::Color32::from_rgb(40, 110, 80))
+                        .strong()
+                );
+                ui.horizontal(|ui| {
+                    for pokemon_type in &pokemon.types {
+                        render_vista_type_badge(ui, pokemon_type, &i18n.type_name(*pokemon_type, database.names()));
+                    }
+                });
+                // Resumen del set importado: objeto, habilidad y naturaleza
+                let set = &member.set;
+                let summary: Vec<String> = [
+                    set.item.clone(),
+                    set.ability.clone(),
+                    set.nature.map(|nature| match (nature.increased(), nature.decreased()) {
+                        (Some(up), Some(down)) => {
+                            format!("{} (+{} −{})", nature, i18n.stat_name(up), i18n.stat_name(down))
+                        }
+                        _ => nature.to_string(),
+                    }),
+                ]
+                .into_iter()
+                .flatten()
+                .collect();
+                if !summary.is_empty() {
+                    ui.label(
+                        egui::RichText::new(summary.join(" · "))
+                            .size(12.0)
+                            .color(egui::Color32::from_rgb(120, 140, 160))
+                    )
+                    .on_hover_text(set.moves.join("\n"));
+                }
+            });
+
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.button("✖").on_hover_text(i18n.tr("team.remove")).clicked() {
+                    remove = Some(index);
+                }
+                // Selector de forma si la especie tiene varias (megas, formes...)
+                let forms = database.get_forms(member.id);
+                if forms.len() > 1 {
+                    egui::ComboBox::from_id_source(("team_member_form", index))
+                        .selected_text(i18n.pokemon_name(pokemon, database.names()))
+                        .show_ui(ui, |ui| {
+                            for form in forms {
+                                changed |= ui
+                                    .selectable_value(
+                                        &mut member.form,
+                                        form.form.clone(),
+                                        i18n.pokemon_name(form, database.names()),
+                                    )
+                                    .changed();
+                            }
+                        });
+                }
+            });
+        });
+    }
+    if let Some(index) = remove {
+        team.remove(index);
+        changed = true;
+    }
+    if team.is_full() {
+        ui.label(
+            egui::RichText::new(i18n.tr("team.full"))
+                .size(12.0)
+                .color(egui::Color32::from_rgb(120, 140, 160))
+        );
+    }
+    changed
+}
+
+// Etiqueta de tipo compacta; apagada en gris si no está activa
+fn render_type_chip(ui: &mut egui::Ui, pokemon_type: PokemonType, label: &str, active: bool) -> egui::Response {
+    let fill = if active {
+        type_color(&pokemon_type)
+    } else {
+        egui::Color32::from_rgb(190, 200, 210)
+    };
+    egui::Frame::none()
+        .fill(fill)
+        .rounding(egui::Rounding::same(8.0))
+        .inner_margin(egui::style::Margin::symmetric(6.0, 2.0))
+        .show(ui, |ui| {
+            ui.label(egui::RichText::new(label).color(egui::Color32::WHITE).size(12.0).strong());
+        })
+        .response
+}
+
+fn section_title(ui: &mut egui::Ui, text: &str) {
+    ui.add_space(8.0);
+    ui.label(
+        egui::RichText::new(text)
+            .size(18.0)
+            .color(egui::Color32::from_rgb(40, 80, 120))
+            .strong()
+    );
+}
+
+fn render_import_window(
+    ctx: &egui::Context,
+    database: &Database,
+    store: &mut CollectionStore,
+    state: &mut TeamBuilderState,
+    i18n: &I18n,
+) {
+    let mut open = state.import_open;
+    egui::Window::new(i18n.tr("showdown.import_title"))
+        .open(&mut open)
+        .collapsible(false)
+        .default_width(420.0)
+        .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .show(ctx, |ui| {
+            ui.label(
+                egui::RichText::new(i18n.tr("showdown.import_hint"))
+                    .color(egui::Color32::from_rgb(40, 80, 120))
+            );
+            egui::ScrollArea::vertical()
+                .id_source("showdown_import_text")
+                .max_height(260.0)
+                .show(ui, |ui| {
+                    ui.add(
+                        egui::TextEdit::multiline(&mut state.import_text)
+                            .code_editor()
+                            .desired_rows(12)
+                            .desired_width(f32::INFINITY)
+                    );
+                });
+
+            if ui.button(i18n.tr("showdown.import")).clicked() {
+                state.import(store, database, i18n);
+            }
+
+            // Avisos de la última importación, con su número de línea
+            for diagnostic in &state.import_diagnostics {
+                let color = match diagnostic.severity() {
+                    Severity::Error => egui::Color32::from_rgb(200, 60, 50),
+                    Severity::Warning => egui::Color32::from_rgb(190, 120, 30),
+                };
+                let text = i18n.tr_args(
+                    diagnostic.key(),
+                    &[("line", &diagnostic.line), ("value", &diagnostic.value())],
+                );
+                ui.label(egui::RichText::new(text).size(12.0).color(color));
+            }
+        });
+    state.import_open &= open;
+}
@@ -0,0 +1,297 @@
+use super::team_builder::{format_multiplier, multiplier_color};
+use super::type_color;
+use crate::database::{Database, PokemonFilter};
+use crate::i18n::I18n;
+use crate::pokemon::PokemonType;
+use eframe::egui;
+
+// Tamaño de cada casilla de la tabla completa y ancho de los nombres de las filas
+const CELL: f32 = 21.0;
+const LABEL_WIDTH: f32 = 80.0;
+// Filas por columna en el modo de doble tipo
+const DUAL_ROWS: usize = 6;
+const DUAL_CELL: egui::Vec2 = egui::vec2(150.0, 26.0);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ChartMode {
+    // Atacante × defensor, un tipo contra otro
+    Full,
+    // Todos los tipos atacando a una combinación de dos tipos
+    Dual,
+}
+
+impl ChartMode {
+    const ALL: [ChartMode; 2] = [ChartMode::Full, ChartMode::Dual];
+
+    fn key(&self) -> &'static str {
+        match self {
+            ChartMode::Full => "chart.mode.full",
+            ChartMode::Dual => "chart.mode.dual",
+        }
+    }
+}
+
+pub struct TypeChartState {
+    mode: ChartMode,
+    first: PokemonType,
+    second: Option<PokemonType>,
+}
+
+impl Default for TypeChartState {
+    fn default() -> Self {
+        Self {
+            mode: ChartMode::Full,
+            first: PokemonType::Fire,
+            second: Some(PokemonType::Flying),
+        }
+    }
+}
+
+// Tinte para la fila y la columna bajo el puntero
+fn highlight() -> egui::Color32 {
+    egui::Color32::from_rgba_unmultiplied(70, 110, 160, 60)
+}
+
+fn neutral_fill() -> egui::Color32 {
+    egui::Color32::from_rgb(235, 240, 245)
+}
+
+// Abreviatura de tres letras para las cabeceras de columna
+fn short_name(name: &str) -> String {
+    name.chars().take(3).collect()
+}
+
+fn paint_type_label(painter: &egui::Painter, rect: egui::Rect, pokemon_type: PokemonType, text: &str, highlighted: bool) {
+    painter.rect_filled(rect, egui::Rounding::same(4.0), type_color(&pokemon_type));
+    if highlighted {
+        painter.rect_stroke(rect, egui::Rounding::same(4.0), egui::Stroke::new(2.0, egui::Color32::from_rgb(40, 80, 120)));
+    }
+    painter.text(
+        rect.center(),
+        egui::Align2::CENTER_CENTER,
+        text,
+        egui::FontId::proportional(11.0),
+        egui::Color32::WHITE,
+    );
+}
+
+fn paint_multiplier(painter: &egui::Painter, rect: egui::Rect, multiplier: f32, size: f32) {
+    // Las casillas neutras van en gris claro y sin texto para que destaquen las demás
+    if multiplier == 1.0 {
+        painter.rect_filled(rect, egui::Rounding::same(2.0), neutral_fill());
+        return;
+    }
+    painter.rect_filled(rect, egui::Rounding::same(2.0), multiplier_color(multiplier));
+    painter.text(
+        rect.center(),
+        egui::Align2::CENTER_CENTER,
+        format_multiplier(multiplier).trim_start_matches('×'),
+        egui::FontId::proportional(size),
+        egui::Color32::WHITE,
+    );
+}
+
+// Devuelve la combinación de tipos por la que filtrar la lista
+pub fn render_vista_type_chart_panel(
+    ui: &mut egui::Ui,
+    database: &Database,
+    state: &mut TypeChartState,
+    i18n: &I18n,
+) -> Option<(PokemonType, Option<PokemonType>)> {
+    let mut filter = None;
+    let vista_panel_frame = egui::Frame::none()
+        .fill(egui::Color32::from_rgba_premultiplied(240, 248, 255, 200))
+        .rounding(egui::Rounding::same(8.0))
+        .stroke(egui::Stroke::new(1.0, egui::Color32::from_rgb(180, 200, 220)))
+        .inner_margin(egui::style::Margin::same(8.0))
+        .shadow(egui::epaint::Shadow {
+            extrusion: 6.0,
+            color: egui::Color32::from_rgba_premultiplied(0, 0, 0, 40),
+        });
+
+    vista_panel_frame.show(ui, |ui| {
+        ui.set_width(ui.available_width());
+        ui.set_height(450.0);
+
+        ui.horizontal(|ui| {
+            ui.heading(
+                egui::RichText::new(i18n.tr("chart.title"))
+                    .size(24.0)
+                    .color(egui::Color32::from_rgb(40, 110, 80))
+                    .strong()
+            );
+            ui.add_space(12.0);
+            for mode in ChartMode::ALL {
+                ui.selectable_value(&mut state.mode, mode, i18n.tr(mode.key()));
+            }
+            ui.add_space(12.0);
+            ui.label(
+                egui::RichText::new(i18n.tr("chart.click_hint"))
+                    .size(12.0)
+                    .color(egui::Color32::from_rgb(120, 140, 160))
+            );
+        });
+        ui.add_space(4.0);
+
+        filter = match state.mode {
+            ChartMode::Full => render_full_chart(ui, database, i18n).map(|defender| (defender, None)),
+            ChartMode::Dual => render_dual_chart(ui, database, state, i18n),
+        };
+    });
+    filter
+}
+
+// Tabla completa: filas = tipo atacante, columnas = tipo defensor; un clic filtra por el defensor
+fn render_full_chart(ui: &mut egui::Ui, database: &Database, i18n: &I18n) -> Option<PokemonType> {
+    let types = PokemonType::ALL;
+    let count = types.len();
+    let names: Vec<String> = types.iter().map(|&t| i18n.type_name(t, database.names())).collect();
+
+    let size = egui::vec2(LABEL_WIDTH + CELL * count as f32, CELL * (count + 1) as f32);
+    let (rect, response) = ui.allocate_exact_size(size, egui::Sense::click());
+    let origin = rect.min + egui::vec2(LABEL_WIDTH, CELL);
+    // Casilla bajo el puntero: (fila del atacante, columna del defensor)
+    let hovered = response.hover_pos().and_then(|pos| {
+        let offset = pos - origin;
+        let (row, col) = ((offset.y / CELL).floor(), (offset.x / CELL).floor());
+        (row >= 0.0 && col >= 0.0 && (row as usize) < count && (col as usize) < count).then_some((row as usize, col as usize))
+    });
+    let cell_rect = |row: usize, col: usize| {
+        egui::Rect::from_min_size(origin + egui::vec2(col as f32 * CELL, row as f32 * CELL), egui::vec2(CELL, CELL)).shrink(1.0)
+    };
+
+    let painter = ui.painter_at(rect);
+    for (col, &defender) in types.iter().enumerate() {
+        let header = egui::Rect::from_min_size(
+            egui::pos2(origin.x + col as f32 * CELL, rect.min.y),
+            egui::vec2(CELL, CELL),
+        );
+        let highlighted = hovered.is_some_and(|(_, c)| c == col);
+        paint_type_label(&painter, header.shrink(1.0), defender, &short_name(&names[col]), highlighted);
+    }
+    for (row, &attacker) in types.iter().enumerate() {
+        let label = egui::Rect::from_min_size(
+            egui::pos2(rect.min.x, origin.y + row as f32 * CELL),
+            egui::vec2(LABEL_WIDTH - 2.0, CELL),
+        );
+        let highlighted = hovered.is_some_and(|(r, _)| r == row);
+        paint_type_label(&painter, label.shrink(1.0), attacker, &names[row], highlighted);
+        for (col, &defender) in types.iter().enumerate() {
+            paint_multiplier(&painter, cell_rect(row, col), attacker.effectiveness(defender), 11.0);
+        }
+    }
+
+    let (row, col) = hovered?;
+    for i in 0..count {
+        painter.rect_filled(cell_rect(row, i), egui::Rounding::same(2.0), highlight());
+        if i != row {
+            painter.rect_filled(cell_rect(i, col), egui::Rounding::same(2.0), highlight());
+        }
+    }
+    painter.rect_stroke(
+        cell_rect(row, col),
+        egui::Rounding::same(2.0),
+        egui::Stroke::new(2.0, egui::Color32::from_rgb(40, 80, 120)),
+    );
+
+    let multiplier = types[row].effectiveness(types[col]);
+    let tooltip = i18n.tr_args(
+        "chart.cell",
+        &[("attacker", &names[row]), ("defender", &names[col]), ("multiplier", &format_multiplier(multiplier))],
+    );
+    let response = response.on_hover_text(tooltip);
+    response.clicked().then_some(types[col])
+}
+
+fn type_combo(ui: &mut egui::Ui, id: &str, selected: &mut Option<PokemonType>, none_label: Option<&str>, database: &Database, i18n: &I18n) {
+    let text = match selected {
+        Some(pokemon_type) => i18n.type_name(*pokemon_type, database.names()),
+        None => none_label.unwrap_or_default().to_string(),
+    };
+    egui::ComboBox::from_id_source(id).selected_text(text).show_ui(ui, |ui| {
+        if let Some(label) = none_label {
+            ui.selectable_value(selected, None, label);
+        }
+        for pokemon_type in PokemonType::ALL {
+            ui.selectable_value(selected, Some(pokemon_type), i18n.type_name(pokemon_type, database.names()));
+        }
+    });
+}
+
+// Un defensor de uno o dos tipos contra cada tipo atacante; un clic filtra por esa combinación
+fn render_dual_chart(
+    ui: &mut egui::Ui,
+    database: &Database,
+    state: &mut TypeChartState,
+    i18n: &I18n,
+) -> Option<(PokemonType, Option<PokemonType>)> {
+    ui.horizontal(|ui| {
+        ui.label(egui::RichText::new(i18n.tr("chart.defender")).color(egui::Color32::from_rgb(40, 80, 120)));
+        let mut first = Some(state.first);
+        type_combo(ui, "chart_first_type", &mut first, None, database, i18n);
+        state.first = first.unwrap_or(state.first);
+        type_combo(ui, "chart_second_type", &mut state.second, Some(i18n.tr("chart.no_second_type")), database, i18n);
+        if state.second == Some(state.first) {
+            state.second = None;
+        }
+    });
+
+    let defender: Vec<PokemonType> = std::iter::once(state.first).chain(state.second).collect();
+    let filter = PokemonFilter {
+        pokemon_type: Some(state.first),
+        second_type: state.second,
+        ..PokemonFilter::default()
+    };
+    ui.label(
+        egui::RichText::new(i18n.tr_args("chart.matching", &[("count", &database.query(&filter).len())]))
+            .color(egui::Color32::from_rgb(120, 140, 160))
+    );
+    ui.add_space(4.0);
+
+    let types = PokemonType::ALL;
+    let columns = types.len().div_ceil(DUAL_ROWS);
+    let size = egui::vec2(DUAL_CELL.x * columns as f32, DUAL_CELL.y * DUAL_ROWS as f32);
+    let (rect, response) = ui.allocate_exact_size(size, egui::Sense::click());
+    let cell_rect = |i: usize| {
+        let (col, row) = (i / DUAL_ROWS, i % DUAL_ROWS);
+        egui::Rect::from_min_size(
+            rect.min + egui::vec2(col as f32 * DUAL_CELL.x, row as f32 * DUAL_CELL.y),
+            DUAL_CELL,
+        )
+        .shrink2(egui::vec2(4.0, 2.0))
+    };
+    let hovered = response
+        .hover_pos()
+        .and_then(|pos| (0..types.len()).find(|&i| cell_rect(i).contains(pos)));
+
+    let painter = ui.painter_at(rect);
+    for (i, &attacker) in types.iter().enumerate() {
+        let cell = cell_rect(i);
+        let split = cell.left() + cell.width() * 0.65;
+        let label = egui::Rect::from_min_max(cell.min, egui::pos2(split, cell.bottom()));
+        let value = egui::Rect::from_min_max(egui::pos2(split, cell.top()), cell.max);
+        let highlighted = hovered == Some(i);
+        paint_type_label(&painter, label, attacker, &i18n.type_name(attacker, database.names()), highlighted);
+        paint_multiplier(&painter, value.shrink2(egui::vec2(2.0, 0.0)), attacker.effectiveness_against(&defender), 13.0);
+        if highlighted {
+            painter.rect_filled(cell, egui::Rounding::same(4.0), highlight());
+        }
+    }
+
+    let i = hovered?;
+    let defender_name = defender
+        .iter()
+        .map(|&t| i18n.type_name(t, database.names()))
+        .collect::<Vec<_>>()
+        .join(" / ");
+    let tooltip = i18n.tr_args(
+        "chart.cell",
+        &[
+            ("attacker", &i18n.type_name(types[i], database.names())),
+            ("defender", &defender_name),
+            ("multiplier", &format_multiplier(types[i].effectiveness_against(&defender))),
+        ],
+    );
+    let response = response.on_hover_text(tooltip);
+    response.clicked().then_some((state.first, state.second))
+}
@@ -0,0 +1,64 @@
+#![cfg(feature = "cli")]
+
+use serde_json::Value;
+use std::process::Command;
+
+// Ejecuta el binario real con --json y devuelve la salida ya parseada
+fn run_json(args: &[&str]) -> Value {
+    let output = Command::new(env!("CARGO_BIN_EXE_pokedex"))
+        .arg("--json")
+        .args(args)
+        .output()
+        .expect("no se pudo ejecutar pokedex");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    serde_json::from_slice(&output.stdout).expect("salida JSON")
+}
+
+fn has_type(pokemon: &Value, pokemon_type: &str) -> bool {
+    pokemon["types"].as_array().unwrap().iter().any(|t| t == pokemon_type)
+}
+
+#[test]
+fn list_rows_match_the_type_filter() {
+    let dragons = run_json(&["list", "--type", "dragon"]);
+    let dragons = dragons.as_array().unwrap();
+    assert!(!dragons.is_empty());
+    assert!(dragons.iter().all(|p| has_type(p, "Dragon")));
+    // Charizard sale por su mega X, no por la forma base
+    let charizard = dragons.iter().find(|p| p["id"] == 6).expect("Charizard");
+    assert_eq!(charizard["form"], "Mega Charizard X");
+}
+
+#[test]
+fn sort_uses_the_stats_of_the_matching_form() {
+    let fire = run_json(&["list", "--type", "fire", "--sort", "attack"]);
+    let fire = fire.as_array().unwrap();
+    assert!(fire.iter().all(|p| has_type(p, "Fire")));
+    let attacks: Vec<u64> = fire.iter().map(|p| p["stats"]["attack"].as_u64().unwrap()).collect();
+    assert!(attacks.windows(2).all(|pair| pair[0] >= pair[1]));
+    assert_eq!(fire[0]["form"], "Primal Groudon");
+    assert_eq!(attacks[0], 180);
+}
+
+#[test]
+fn battle_prints_repeatable_win_rates() {
+    let args = ["battle", "charizard,pikachu", "blastoise,gengar", "--runs", "20", "--seed", "5"];
+    let summary = run_json(&args);
+    assert_eq!(summary["runs"], 20);
+    assert_eq!(summary["seed"], 5);
+    let total = ["player_wins", "opponent_wins", "draws"].iter().map(|k| summary[k].as_u64().unwrap()).sum::<u64>();
+    assert_eq!(total, 20);
+    assert_eq!(run_json(&args), summary);
+}
+
+#[test]
+fn battle_log_follows_the_language() {
+    let args = ["battle", "charizard", "blastoise", "--runs", "1", "--seed", "3", "--log"];
+    let spanish = run_json(&args);
+    let english = run_json(&[&args[..], &["--lang", "en"]].concat());
+    let (spanish, english) = (spanish["log"].as_array().unwrap(), english["log"].as_array().unwrap());
+    assert_eq!(spanish.len(), english.len());
+    assert_eq!(spanish[0], "¡Adelante, Charizard!");
+    assert_eq!(english[0], "Go, Charizard!");
+    assert!(english.last().unwrap().as_str().unwrap().ends_with("wins the battle!"));
+}
@@ -0,0 +1,157 @@
+#![cfg(feature = "cli")]
+
+use serde_json::{json, Value};
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+
+// El ejecutable real en modo `rpc`, hablando por tuberías
+struct RpcProcess {
+    child: Child,
+    stdin: Option<ChildStdin>,
+    stdout: BufReader<ChildStdout>,
+}
+
+impl RpcProcess {
+    fn spawn() -> Self {
+        Self::spawn_with(Command::new(env!("CARGO_BIN_EXE_pokedex")))
+    }
+
+    fn spawn_with(mut command: Command) -> Self {
+        let mut child = command
+            .arg("rpc")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("no se pudo arrancar pokedex rpc");
+        let stdin = child.stdin.take();
+        let stdout = BufReader::new(child.stdout.take().expect("stdout"));
+        Self { child, stdin, stdout }
+    }
+
+    fn send(&mut self, line: &str) {
+        let stdin = self.stdin.as_mut().expect("stdin abierto");
+        writeln!(stdin, "{}", line).unwrap();
+        stdin.flush().unwrap();
+    }
+
+    fn receive(&mut self) -> Value {
+        let mut line = String::new();
+        self.stdout.read_line(&mut line).unwrap();
+        serde_json::from_str(&line).expect("respuesta JSON")
+    }
+
+    fn call(&mut self, method: &str, params: Value) -> Value {
+        self.send(&json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params }).to_string());
+        self.receive()
+    }
+
+    // Cierra la entrada y devuelve lo que quedara por leer
+    fn finish(self) -> (bool, String) {
+        let (success, lines) = self.finish_lines();
+        (success, lines.concat())
+    }
+
+    fn finish_lines(mut self) -> (bool, Vec<String>) {
+        drop(self.stdin.take());
+        let lines = (&mut self.stdout).lines().map(|line| line.unwrap()).collect();
+        (self.child.wait().unwrap().success(), lines)
+    }
+}
+
+#[test]
+fn lookup_and_search() {
+    let mut rpc = RpcProcess::spawn();
+    let response = rpc.call("lookup", json!({ "query": "pikachu" }));
+    assert_eq!(response["jsonrpc"], "2.0");
+    assert_eq!(response["id"], 1);
+    assert_eq!(response["result"]["id"], 25);
+
+    let mega = rpc.call("lookup", json!({ "query": "6", "form": "Mega Charizard X" }));
+    assert_eq!(mega["result"]["types"], json!(["Fire", "Dragon"]));
+
+    let search = rpc.call("search", json!({ "query": "saur", "type": "grass" }));
+    let names: Vec<&str> = search["result"].as_array().unwrap().iter().map(|p| p["name"].as_str().unwrap()).collect();
+    assert_eq!(names, ["Bulbasaur", "Ivysaur", "Venusaur"]);
+}
+
+#[test]
+fn matchups_stats_and_team() {
+    let mut rpc = RpcProcess::spawn();
+    let matchups = rpc.call("matchups", json!({ "types": ["fire", "flying"] }));
+    assert_eq!(matchups["result"]["defense"]["Rock"], 4.0);
+    assert_eq!(matchups["result"]["immunities"], json!(["Ground"]));
+
+    let stats = rpc.call(
+        "calculate_stats",
+        json!({ "pokemon": "garchomp", "level": 50, "nature": "jolly", "evs": { "attack": 252, "speed": 252 } }),
+    );
+    assert_eq!(stats["result"]["stats"]["speed"], 169);
+    assert_eq!(stats["result"]["stats"]["hp"], 183);
+
+    let team = rpc.call("analyze_team", json!({ "members": ["charizard", "venusaur", { "query": "6", "form": "Mega Charizard X" }] }));
+    assert_eq!(team["result"]["risks"], json!(["Rock"]));
+    assert_eq!(team["result"]["stat_totals"]["total"], 1693);
+}
+
+#[test]
+fn batches_and_notifications() {
+    let mut rpc = RpcProcess::spawn();
+    rpc.send(
+        &json!([
+            { "jsonrpc": "2.0", "id": "a", "method": "lookup", "params": { "query": "1" } },
+            { "jsonrpc": "2.0", "method": "lookup", "params": { "query": "2" } },
+            { "jsonrpc": "2.0", "id": "b", "method": "nope" },
+        ])
+        .to_string(),
+    );
+    let batch = rpc.receive();
+    let responses = batch.as_array().unwrap();
+    assert_eq!(responses.len(), 2);
+    assert_eq!(responses[0]["id"], "a");
+    assert_eq!(responses[0]["result"]["name"], "Bulbasaur");
+    assert_eq!(responses[1]["error"]["code"], -32601);
+
+    // Una notificación suelta no produce respuesta
+    rpc.send(r#"{"jsonrpc": "2.0", "method": "lookup", "params": {"query": "3"}}"#);
+    let (success, rest) = rpc.finish();
+    assert!(success);
+    assert_eq!(rest, "");
+}
+
+#[test]
+fn error_codes() {
+    let mut rpc = RpcProcess::spawn();
+    rpc.send("{no es json");
+    assert_eq!(rpc.receive()["error"]["code"], -32700);
+    rpc.send("[]");
+    assert_eq!(rpc.receive()["error"]["code"], -32600);
+    rpc.send(r#"{"id": 1, "method": "lookup"}"#);
+    assert_eq!(rpc.receive()["error"]["code"], -32600);
+
+    assert_eq!(rpc.call("lookup", json!({ "query": "missingno" }))["error"]["code"], -32001);
+    assert_eq!(rpc.call("lookup", json!({}))["error"]["code"], -32602);
+    assert_eq!(rpc.call("matchups", json!({ "types": ["plasma"] }))["error"]["code"], -32602);
+    let evs = json!({ "hp": 252, "attack": 252, "speed": 252 });
+    assert_eq!(rpc.call("calculate_stats", json!({ "pokemon": "1", "evs": evs }))["error"]["code"], -32602);
+}
+
+#[test]
+fn stdout_is_only_json_with_localized_names() {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_pokedex"));
+    command.env("POKEDEX_NAMES_FILE", concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/names.json"))
+        .stderr(Stdio::null());
+    let mut rpc = RpcProcess::spawn_with(command);
+    rpc.send(&json!({ "jsonrpc": "2.0", "id": 1, "method": "search", "params": { "query": "ピカチュウ" } }).to_string());
+    rpc.send(&json!({ "jsonrpc": "2.0", "id": 2, "method": "lookup", "params": { "query": "25" } }).to_string());
+
+    // Cada línea de la salida tiene que ser una respuesta JSON-RPC completa
+    let (success, lines) = rpc.finish_lines();
+    assert!(success);
+    assert_eq!(lines.len(), 2);
+    let responses: Vec<Value> = lines
+        .iter()
+        .map(|line| serde_json::from_str(line).unwrap_or_else(|e| panic!("línea no JSON {:?}: {}", line, e)))
+        .collect();
+    assert_eq!(responses[0]["result"][0]["id"], 25);
+    assert_eq!(responses[1]["result"]["name"], "Pikachu");
+}
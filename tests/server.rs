@@ -0,0 +1,211 @@
+#![cfg(feature = "server")]
+
+use pokedex::server::{ApiServer, MAX_PER_PAGE};
+use pokedex::{Database, PokemonType};
+use serde_json::Value;
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::sync::OnceLock;
+use std::thread;
+
+// Un único servidor en un puerto libre compartido por todas las pruebas
+fn server() -> SocketAddr {
+    static ADDRESS: OnceLock<SocketAddr> = OnceLock::new();
+    *ADDRESS.get_or_init(|| {
+        let server = ApiServer::bind("127.0.0.1:0").expect("no se pudo abrir el puerto");
+        let address = server.local_addr().expect("dirección IP");
+        thread::spawn(move || {
+            let mut database = Database::new();
+            database.load_from_csv().expect("CSV");
+            server.serve(&database);
+        });
+        address
+    })
+}
+
+fn request(method: &str, path: &str) -> (u16, Value) {
+    let mut stream = TcpStream::connect(server()).expect("conexión");
+    write!(stream, "{} {} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n", method, path).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+
+    let (head, body) = response.split_once("\r\n\r\n").expect("respuesta HTTP");
+    let status = head.split_whitespace().nth(1).and_then(|s| s.parse().ok()).expect("código de estado");
+    (status, serde_json::from_str(body).expect("cuerpo JSON"))
+}
+
+fn get(path: &str) -> (u16, Value) {
+    request("GET", path)
+}
+
+#[test]
+fn lists_with_pagination() {
+    let (status, body) = get("/pokemon?page=2&per_page=10");
+    assert_eq!(status, 200);
+    assert_eq!(body["page"], 2);
+    assert_eq!(body["per_page"], 10);
+    assert_eq!(body["total"], 721);
+    let items = body["items"].as_array().unwrap();
+    assert_eq!(items.len(), 10);
+    assert_eq!(items[0]["id"], 11);
+
+    let (_, with_forms) = get("/pokemon?forms=true&per_page=1");
+    assert_eq!(with_forms["total"], 800);
+}
+
+#[test]
+fn huge_page_is_a_bad_request() {
+    let (status, body) = get(&format!("/pokemon?page={}&per_page={}", usize::MAX, MAX_PER_PAGE));
+    assert_eq!(status, 400);
+    assert!(body["error"].is_string());
+    // El servidor sigue atendiendo
+    let (status, body) = get("/pokemon?page=1000&per_page=10");
+    assert_eq!(status, 200);
+    assert!(body["items"].as_array().unwrap().is_empty());
+}
+
+#[test]
+fn fetches_by_id_name_and_form() {
+    let (status, body) = get("/pokemon/25");
+    assert_eq!(status, 200);
+    assert_eq!(body["name"], "Pikachu");
+    assert_eq!(body["stats"]["speed"], 90);
+
+    let (_, by_name) = get("/pokemon/charizard");
+    assert_eq!(by_name["id"], 6);
+    assert_eq!(by_name["form"], Value::Null);
+
+    let (status, mega) = get("/pokemon/6?form=Mega%20Charizard%20X");
+    assert_eq!(status, 200);
+    assert_eq!(mega["types"], serde_json::json!(["Fire", "Dragon"]));
+
+    let (_, forms) = get("/pokemon/charizard/forms");
+    assert_eq!(forms.as_array().unwrap().len(), 3);
+}
+
+#[test]
+fn search_uses_the_ui_filter() {
+    let (status, body) = get("/search?q=saur&type=grass");
+    assert_eq!(status, 200);
+    let names: Vec<&str> = body["items"].as_array().unwrap().iter().map(|p| p["name"].as_str().unwrap()).collect();
+    assert_eq!(names, ["Bulbasaur", "Ivysaur", "Venusaur"]);
+
+    let (_, generation) = get("/search?type=fire&generation=1&per_page=100");
+    assert_eq!(generation["total"], 12);
+}
+
+#[test]
+fn search_by_type_only_returns_that_type() {
+    for pokemon_type in PokemonType::ALL {
+        let name = format!("{:?}", pokemon_type);
+        let (status, body) = get(&format!("/search?type={}&per_page={}", name.to_lowercase(), MAX_PER_PAGE));
+        assert_eq!(status, 200);
+        let items = body["items"].as_array().unwrap();
+        assert_eq!(body["total"], items.len());
+        for item in items {
+            assert!(
+                item["types"].as_array().unwrap().iter().any(|t| t == name.as_str()),
+                "{} no es de tipo {}: {}",
+                item["name"],
+                name,
+                item["types"]
+            );
+        }
+    }
+}
+
+#[test]
+fn type_matchups() {
+    let (status, fire) = get("/types/fire");
+    assert_eq!(status, 200);
+    assert_eq!(fire["offense"]["Grass"], 2.0);
+    assert_eq!(fire["defense"]["Water"], 2.0);
+
+    let (_, combo) = get("/matchups?types=fire,flying");
+    assert_eq!(combo["defense"]["Rock"], 4.0);
+    assert_eq!(combo["defense"]["Ground"], 0.0);
+}
+
+#[test]
+fn stats_aggregates() {
+    let (status, body) = get("/stats?forms=true");
+    assert_eq!(status, 200);
+    assert_eq!(body["count"], 800);
+    assert_eq!(body["metrics"]["speed"]["max"], 180);
+
+    let (_, by_type) = get("/stats?by=type&forms=true");
+    assert!(by_type["metrics"]["total"]["Dragon"]["mean"].as_f64().unwrap() > 500.0);
+}
+
+#[test]
+fn stats_count_matches_search_total() {
+    for query in ["type=dragon", "type=fire&generation=1", "q=char", "q=char&forms=true", "forms=true"] {
+        let (_, search) = get(&format!("/search?{}", query));
+        let (_, stats) = get(&format!("/stats?{}", query));
+        assert_eq!(stats["count"], search["total"], "{}", query);
+    }
+    assert_eq!(get("/stats").1["count"], 721);
+}
+
+#[test]
+fn errors_are_json() {
+    let (status, body) = get("/pokemon/missingno");
+    assert_eq!(status, 404);
+    assert!(body["error"].is_string());
+
+    assert_eq!(get("/search?type=plasma").0, 400);
+    assert_eq!(get("/nowhere").0, 404);
+    assert_eq!(request("POST", "/pokemon").0, 405);
+}
+
+#[cfg(feature = "graphql")]
+#[test]
+fn graphql_queries() {
+    let query = "%7Bpokemon(name%3A%22charizard%22)%7Bid%20types%20forms%7BdisplayName%7D%7D%7D";
+    let (status, body) = get(&format!("/graphql?query={}", query));
+    assert_eq!(status, 200);
+    assert_eq!(body["data"]["pokemon"]["id"], 6);
+    assert_eq!(body["data"]["pokemon"]["types"], serde_json::json!(["FIRE", "FLYING"]));
+    assert_eq!(body["data"]["pokemon"]["forms"].as_array().unwrap().len(), 3);
+
+    assert_eq!(get("/graphql").0, 400);
+}
+
+#[cfg(feature = "graphql")]
+fn graphql(query: &str) -> Value {
+    let encoded: String = query
+        .bytes()
+        .map(|b| if b.is_ascii_alphanumeric() { (b as char).to_string() } else { format!("%{:02X}", b) })
+        .collect();
+    let (status, body) = get(&format!("/graphql?query={}", encoded));
+    assert_eq!(status, 200);
+    assert!(body["errors"].is_null(), "{}", body["errors"]);
+    body["data"].clone()
+}
+
+#[cfg(feature = "graphql")]
+#[test]
+fn graphql_filters_use_the_matching_form() {
+    let data = graphql("{ pokemons(filter: {type: FIRE}, sort: {field: ATTACK, descending: true}, limit: 500) { types stats { attack } } count(filter: {type: FIRE}) }");
+    let pokemons = data["pokemons"].as_array().unwrap();
+    assert_eq!(data["count"], pokemons.len());
+    assert!(pokemons.iter().all(|p| p["types"].as_array().unwrap().iter().any(|t| t == "FIRE")));
+    assert_eq!(pokemons[0]["stats"]["attack"], 180);
+
+    let data = graphql("{ all: count(filter: {type: DRAGON}, forms: true) species: count(filter: {type: DRAGON}) pokemons(filter: {type: DRAGON}, forms: true, limit: 500) { id } }");
+    assert_eq!(data["all"], data["pokemons"].as_array().unwrap().len());
+    assert!(data["all"].as_u64() > data["species"].as_u64());
+}
+
+#[cfg(feature = "graphql")]
+#[test]
+fn graphql_evolutions() {
+    let data = graphql("{ pokemon(name: \"eevee\") { preEvolution { name } evolutions { name } } ivysaur: pokemon(name: \"ivysaur\") { preEvolution { name } evolutionChain { name } } }");
+    assert!(data["pokemon"]["preEvolution"].is_null());
+    assert_eq!(data["pokemon"]["evolutions"].as_array().unwrap().len(), 8);
+    assert_eq!(data["ivysaur"]["preEvolution"]["name"], "Bulbasaur");
+    assert_eq!(
+        data["ivysaur"]["evolutionChain"],
+        serde_json::json!([{ "name": "Bulbasaur" }, { "name": "Ivysaur" }, { "name": "Venusaur" }])
+    );
+}